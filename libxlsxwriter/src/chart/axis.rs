@@ -0,0 +1,446 @@
+use super::constants::{ChartAxisDisplayUnits, ChartAxisLabelAlignment};
+use super::structs::*;
+use crate::{convert_bool, convert_str, Workbook, XlsxError};
+use std::os::raw::c_char;
+
+/// Struct to represent an Excel chart axis.
+///
+/// This struct is returned by the `Chart.x_axis()` and `Chart.y_axis()` functions. It is used in functions that modify a chart axis but the members of the struct aren't modified directly.
+///
+/// ### Note
+/// libxlsxwriter doesn't support secondary axes, so there are no `x2_axis()` or `y2_axis()` accessors.
+///
+/// libxlsxwriter also doesn't support choosing between the text axis and the date axis types of a category axis, so there is no `set_axis_type()` function.
+/// Category axes are written with the automatic type: Excel uses a date axis when the categories are formatted as dates and a text axis otherwise.
+/// To display numeric categories as text, write them to the worksheet as strings.
+pub struct ChartAxis<'a> {
+    pub(crate) _workbook: &'a Workbook,
+    pub(crate) chart_axis: *mut libxlsxwriter_sys::lxw_chart_axis,
+}
+
+impl<'a> ChartAxis<'a> {
+    /// Set the title of the axis. The title can have several lines separated by `\n`:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_name-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.x_axis().set_name("Quarter")?;
+    /// chart.y_axis().set_name("Revenue\n(millions)")?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The lines of the title are kept in the file and Excel displays them as separate lines. Windows line endings (`\r\n`) are written as `\n`.
+    pub fn set_name(&mut self, name: &str) -> Result<(), XlsxError> {
+        let name_vec = convert_str(&name.replace("\r\n", "\n"))?;
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_name(
+                self.chart_axis,
+                name_vec.as_ptr() as *const c_char,
+            );
+        }
+        self._workbook.const_str.borrow_mut().push(name_vec);
+        Ok(())
+    }
+
+    /// Set the font properties of the title of the axis.
+    pub fn set_name_font(&mut self, font: &ChartFont) -> Result<(), XlsxError> {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_name_font(
+                self.chart_axis,
+                &mut font.to_c_struct()?.chart_font,
+            );
+        }
+        Ok(())
+    }
+
+    /// Set the font properties of the numbers of the axis.
+    pub fn set_num_font(&mut self, font: &ChartFont) -> Result<(), XlsxError> {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_num_font(
+                self.chart_axis,
+                &mut font.to_c_struct()?.chart_font,
+            );
+        }
+        Ok(())
+    }
+
+    /// Set the position where the y axis will cross the x axis.
+    ///
+    /// The value is the category number for a category axis or the value for a value axis:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_crossing-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Column);
+    /// # chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.x_axis().set_crossing(3.0);
+    /// chart.y_axis().set_crossing(8.0);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_crossing(&mut self, value: f64) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_crossing(self.chart_axis, value);
+        }
+    }
+
+    /// Set the position where the other axis will cross this axis to the maximum value.
+    pub fn set_crossing_max(&mut self) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_crossing_max(self.chart_axis);
+        }
+    }
+
+    /// Set the position where the other axis will cross this axis to the minimum value.
+    pub fn set_crossing_min(&mut self) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_crossing_min(self.chart_axis);
+        }
+    }
+
+    /// Set the number format of the axis labels. It can be used on category axes as well as value axes:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_num_format-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    /// // Years such as 2020 are displayed without a thousands separator.
+    /// chart.x_axis().set_num_format("0")?;
+    /// chart.y_axis().set_num_format("$#,##0")?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (2016 + i).into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i * 1000 + 500).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// By default an axis uses the number format of the cells of its categories or values.
+    /// Setting a number format replaces it, so numeric categories written with a thousands separator format can be displayed as plain integers.
+    pub fn set_num_format(&mut self, num_format: &str) -> Result<(), XlsxError> {
+        let num_format_vec = convert_str(num_format)?;
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_num_format(
+                self.chart_axis,
+                num_format_vec.as_ptr() as *const c_char,
+            );
+        }
+        self._workbook.const_str.borrow_mut().push(num_format_vec);
+        Ok(())
+    }
+
+    /// Link the number format of the axis labels to the number format of the source cells, or keep the format set with
+    /// [ChartAxis.set_num_format()](struct.ChartAxis.html#method.set_num_format):
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_num_format_source_linked-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 1000 + 500).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// let mut y_axis = chart.y_axis();
+    /// y_axis.set_num_format("$#,##0")?;
+    /// // Display the values like the cells of the series again.
+    /// y_axis.set_num_format_source_linked(true);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The axes are linked to the source by default, with the `General` format or the date format of a date axis. Setting a custom number format
+    /// unlinks the axis. Relinking it replaces the custom format with the default format of the axis.
+    ///
+    /// libxlsxwriter links the default format to the source and unlinks any other format, so `false` is the state after setting a custom format
+    /// and doesn't change the axis: an axis using its default format can't be unlinked.
+    pub fn set_num_format_source_linked(&mut self, linked: bool) {
+        if linked {
+            unsafe {
+                libxlsxwriter_sys::chart_axis_set_num_format(
+                    self.chart_axis,
+                    (*self.chart_axis).default_num_format,
+                );
+            }
+        }
+    }
+
+    /// Reverse the order of the axis, for example to display a value axis from the maximum at the bottom to the minimum at the top:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_reverse-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 20).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// let mut y_axis = chart.y_axis();
+    /// y_axis.set_reverse();
+    /// y_axis.set_min(0.0);
+    /// y_axis.set_max(100.0);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Reversing an axis and setting its bounds are independent and can be done in any order. The minimum and the maximum keep their meaning on a reversed axis:
+    /// with the settings above the axis goes from 0 at the top to 100 at the bottom.
+    ///
+    /// The other axis crosses a reversed axis at its minimum, which is at the other end of the axis. Use
+    /// [ChartAxis.set_crossing_max()](struct.ChartAxis.html#method.set_crossing_max) on the reversed axis to keep the other axis at the bottom or on the left.
+    pub fn set_reverse(&mut self) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_reverse(self.chart_axis);
+        }
+    }
+
+    /// Set the minimum value of a value axis. It is only applicable to value and date axes.
+    pub fn set_min(&mut self, min: f64) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_min(self.chart_axis, min);
+        }
+    }
+
+    /// Set the maximum value of a value axis. It is only applicable to value and date axes.
+    pub fn set_max(&mut self, max: f64) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_max(self.chart_axis, max);
+        }
+    }
+
+    /// Turn off the axis. The axis line, its tick marks and its labels aren't displayed.
+    ///
+    /// The gridlines of the axis are independent of it and are still displayed if they are visible.
+    /// See [ChartAxis.set_gridlines_only()](struct.ChartAxis.html#method.set_gridlines_only) for an example.
+    pub fn off(&mut self) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_off(self.chart_axis);
+        }
+    }
+
+    /// Set the line properties of the axis.
+    pub fn set_line(&mut self, line: &ChartLine) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_line(self.chart_axis, &mut line.value());
+        }
+    }
+
+    /// Turn the major gridlines of the axis on or off. Excel displays the major gridlines of the value axis by default.
+    pub fn set_major_gridlines_visible(&mut self, visible: bool) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_major_gridlines_set_visible(
+                self.chart_axis,
+                convert_bool(visible),
+            );
+        }
+    }
+
+    /// Turn the minor gridlines of the axis on or off. They are off by default.
+    pub fn set_minor_gridlines_visible(&mut self, visible: bool) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_minor_gridlines_set_visible(
+                self.chart_axis,
+                convert_bool(visible),
+            );
+        }
+    }
+
+    /// Set the line properties of the major gridlines of the axis. This also turns the major gridlines on.
+    pub fn set_major_gridlines_line(&mut self, line: &ChartLine) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_major_gridlines_set_line(
+                self.chart_axis,
+                &mut line.value(),
+            );
+        }
+    }
+
+    /// Set the line properties of the minor gridlines of the axis. This also turns the minor gridlines on.
+    pub fn set_minor_gridlines_line(&mut self, line: &ChartLine) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_minor_gridlines_set_line(
+                self.chart_axis,
+                &mut line.value(),
+            );
+        }
+    }
+
+    /// Hide the axis line, tick marks and labels while keeping the major gridlines, which is common in dashboards:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_gridlines_only-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.y_axis().set_gridlines_only();
+    /// chart.y_axis().set_major_gridlines_line(&ChartLine {
+    ///     color: FormatColor::Gray,
+    ///     width: 0.5,
+    ///     ..ChartLine::new()
+    /// });
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This is the same as calling [ChartAxis.off()](struct.ChartAxis.html#method.off) and turning the major gridlines on.
+    /// The order of the calls doesn't matter: turning the axis off doesn't hide its gridlines.
+    pub fn set_gridlines_only(&mut self) {
+        self.off();
+        self.set_major_gridlines_visible(true);
+    }
+
+    /// Set the alignment of the labels of a category axis. It is mostly useful for multi-level categories, where the labels of the outer level span several categories:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_label_align-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// // The years in column A and the quarters in column B are the two levels of the categories.
+    /// chart.add_series(Some("=Sheet1!$A$2:$B$9"), Some("=Sheet1!$C$2:$C$9"))?;
+    /// chart.x_axis().set_label_align(ChartAxisLabelAlignment::Left);
+    /// # worksheet.insert_chart(1, 4, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # worksheet.write_string(0, 0, "Year", None)?;
+    /// # worksheet.write_string(0, 1, "Quarter", None)?;
+    /// # worksheet.write_string(0, 2, "Sales", None)?;
+    /// # for i in 0..8 {
+    /// #     if i % 4 == 0 {
+    /// #         worksheet.write_string(i + 1, 0, &(2020 + i / 4).to_string(), None)?;
+    /// #     }
+    /// #     worksheet.write_string(i + 1, 1, &format!("Q{}", i % 4 + 1), None)?;
+    /// #     worksheet.write_number(i + 1, 2, (i * 10 + 5).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Multi-level categories are specified with a category range of several columns, or several rows for series in rows.
+    /// The first column is the outer level: write each of its labels only in the first row of its group, like "2020" above "Q1".
+    /// Excel displays the inner level next to the axis and the outer level below it.
+    ///
+    /// This function is only applicable to category axes. It is ignored for value axes.
+    pub fn set_label_align(&mut self, align: ChartAxisLabelAlignment) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_label_align(self.chart_axis, align.value());
+        }
+    }
+
+    /// Set the interval unit for a category axis. Only every Nth category label is displayed:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_interval_unit-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// chart.add_series(Some("=Sheet1!$A$1:$A$100"), Some("=Sheet1!$B$1:$B$100"))?;
+    /// chart.x_axis().set_interval_unit(10);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 0..100 {
+    /// #     worksheet.write_string(i, 0, &format!("Day {}", i + 1), None)?;
+    /// #     worksheet.write_number(i, 1, (i % 7).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This function is only applicable to category axes. It is ignored for value axes.
+    pub fn set_interval_unit(&mut self, unit: u16) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_interval_unit(self.chart_axis, unit);
+        }
+    }
+
+    /// Set the units in which the values of a value axis are displayed, for example to display 2500000 as 2.5 with a caption "Millions" next to the axis:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_display_units-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, ((i + 1) * 1_500_000).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// let mut y_axis = chart.y_axis();
+    /// y_axis.set_display_units(ChartAxisDisplayUnits::Millions);
+    /// y_axis.set_display_units_visible(false); // 1.5, 3, ... without the "Millions" caption
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This function is only applicable to value axes. It is ignored for category axes.
+    pub fn set_display_units(&mut self, units: ChartAxisDisplayUnits) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_display_units(self.chart_axis, units.value());
+        }
+    }
+
+    /// Show or hide the caption of the display units set with [ChartAxis.set_display_units()](struct.ChartAxis.html#method.set_display_units), such as "Millions".
+    /// The caption is shown by default.
+    ///
+    /// The caption is independent of the scaling: the values are still divided by the display units when the caption is hidden,
+    /// so the axis title or the chart title should then mention the units.
+    pub fn set_display_units_visible(&mut self, visible: bool) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_display_units_visible(
+                self.chart_axis,
+                convert_bool(visible),
+            );
+        }
+    }
+
+    /// Set the interval between the tick marks of a category axis.
+    ///
+    /// This function is only applicable to category axes. It is ignored for value axes.
+    pub fn set_interval_tick(&mut self, unit: u16) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_interval_tick(self.chart_axis, unit);
+        }
+    }
+}
@@ -1,3 +1,12 @@
+/// Available chart types.
+///
+/// ### Note
+/// libxlsxwriter doesn't support the "Pie of Pie" and "Bar of Pie" subtypes of pie charts, so there are no chart types or split controls for them.
+/// Use a `Pie` or `Doughnut` chart instead.
+///
+/// libxlsxwriter doesn't support bubble charts either, so there is no `Bubble` chart type and no function to set the bubble sizes of a series.
+/// A `Scatter` chart can show a third value with the size of the markers of its series, set with
+/// [ChartSeries.set_marker_size()](struct.ChartSeries.html#method.set_marker_size) for each series.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub enum ChartType {
     None,
@@ -12,10 +21,15 @@ pub enum ChartType {
     Doughnut,
     Line,
     Pie,
+    /// Scatter chart with markers only and no lines.
     Scatter,
+    /// Scatter chart with straight lines and no markers.
     ScatterStraight,
+    /// Scatter chart with straight lines and markers.
     ScatterStraightWithMarkers,
+    /// Scatter chart with smooth lines and no markers.
     ScatterSmooth,
+    /// Scatter chart with smooth lines and markers.
     ScatterSmoothWithMarkers,
     Radar,
     RadarWithMarkers,
@@ -416,3 +430,399 @@ impl ChartMarkerType {
         value as u8
     }
 }
+
+/// Position of the data labels of a chart series.
+///
+/// Not all positions are valid for all chart types. libxlsxwriter ignores a position that isn't supported by the chart type.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChartLabelPosition {
+    /// Series data label position: default position.
+    Default,
+    /// Series data label position: center.
+    Center,
+    /// Series data label position: right.
+    Right,
+    /// Series data label position: left.
+    Left,
+    /// Series data label position: above.
+    Above,
+    /// Series data label position: below.
+    Below,
+    /// Series data label position: inside base.
+    InsideBase,
+    /// Series data label position: inside end.
+    InsideEnd,
+    /// Series data label position: outside end.
+    OutsideEnd,
+    /// Series data label position: best fit.
+    BestFit,
+}
+
+impl ChartLabelPosition {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            ChartLabelPosition::Default => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_DEFAULT
+            }
+            ChartLabelPosition::Center => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_CENTER
+            }
+            ChartLabelPosition::Right => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_RIGHT
+            }
+            ChartLabelPosition::Left => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_LEFT
+            }
+            ChartLabelPosition::Above => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_ABOVE
+            }
+            ChartLabelPosition::Below => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_BELOW
+            }
+            ChartLabelPosition::InsideBase => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_INSIDE_BASE
+            }
+            ChartLabelPosition::InsideEnd => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_INSIDE_END
+            }
+            ChartLabelPosition::OutsideEnd => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_OUTSIDE_END
+            }
+            ChartLabelPosition::BestFit => {
+                libxlsxwriter_sys::lxw_chart_label_position_LXW_CHART_LABEL_POSITION_BEST_FIT
+            }
+        };
+        value as u8
+    }
+}
+
+/// Type of the trendline of a chart series.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChartTrendlineType {
+    /// Trendline type: Exponential.
+    Exponential,
+    /// Trendline type: Linear.
+    Linear,
+    /// Trendline type: Logarithmic.
+    Log,
+    /// Trendline type: Moving average. The value of the trendline is the period, 2 or more.
+    MovingAverage,
+    /// Trendline type: Polynomial. The value of the trendline is the order, 2 to 6.
+    Polynomial,
+    /// Trendline type: Power.
+    Power,
+}
+
+impl ChartTrendlineType {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            ChartTrendlineType::Exponential => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_EXP
+            }
+            ChartTrendlineType::Linear => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_LINEAR
+            }
+            ChartTrendlineType::Log => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_LOG
+            }
+            ChartTrendlineType::MovingAverage => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_AVERAGE
+            }
+            ChartTrendlineType::Polynomial => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_POLY
+            }
+            ChartTrendlineType::Power => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_POWER
+            }
+        };
+        value as u8
+    }
+}
+
+/// Type of the error bars of a chart series.
+///
+/// ### Note
+/// libxlsxwriter doesn't support the "Custom" error bars of Excel, whose plus and minus values are read from worksheet ranges, so
+/// there is no custom type or function to set the value ranges. The value of the supported types applies to every point of the series.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChartErrorBarType {
+    /// Error bar type: Standard error.
+    StdError,
+    /// Error bar type: Fixed value.
+    Fixed,
+    /// Error bar type: Percentage.
+    Percentage,
+    /// Error bar type: Standard deviation(s).
+    StdDev,
+}
+
+impl ChartErrorBarType {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            ChartErrorBarType::StdError => {
+                libxlsxwriter_sys::lxw_chart_error_bar_type_LXW_CHART_ERROR_BAR_TYPE_STD_ERROR
+            }
+            ChartErrorBarType::Fixed => {
+                libxlsxwriter_sys::lxw_chart_error_bar_type_LXW_CHART_ERROR_BAR_TYPE_FIXED
+            }
+            ChartErrorBarType::Percentage => {
+                libxlsxwriter_sys::lxw_chart_error_bar_type_LXW_CHART_ERROR_BAR_TYPE_PERCENTAGE
+            }
+            ChartErrorBarType::StdDev => {
+                libxlsxwriter_sys::lxw_chart_error_bar_type_LXW_CHART_ERROR_BAR_TYPE_STD_DEV
+            }
+        };
+        value as u8
+    }
+}
+
+/// Direction of the error bars of a chart series.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChartErrorBarDirection {
+    /// Error bar extends in both directions. The default.
+    Both,
+    /// Error bar extends in positive direction.
+    Plus,
+    /// Error bar extends in negative direction.
+    Minus,
+}
+
+impl ChartErrorBarDirection {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            ChartErrorBarDirection::Both => {
+                libxlsxwriter_sys::lxw_chart_error_bar_direction_LXW_CHART_ERROR_BAR_DIR_BOTH
+            }
+            ChartErrorBarDirection::Plus => {
+                libxlsxwriter_sys::lxw_chart_error_bar_direction_LXW_CHART_ERROR_BAR_DIR_PLUS
+            }
+            ChartErrorBarDirection::Minus => {
+                libxlsxwriter_sys::lxw_chart_error_bar_direction_LXW_CHART_ERROR_BAR_DIR_MINUS
+            }
+        };
+        value as u8
+    }
+}
+
+/// End cap style of the error bars of a chart series.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChartErrorBarCap {
+    /// Flat end cap. The default.
+    EndCap,
+    /// No end cap.
+    NoCap,
+}
+
+impl ChartErrorBarCap {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            ChartErrorBarCap::EndCap => {
+                libxlsxwriter_sys::lxw_chart_error_bar_cap_LXW_CHART_ERROR_BAR_END_CAP
+            }
+            ChartErrorBarCap::NoCap => {
+                libxlsxwriter_sys::lxw_chart_error_bar_cap_LXW_CHART_ERROR_BAR_NO_CAP
+            }
+        };
+        value as u8
+    }
+}
+
+/// Chart styles of the Excel "Chart Styles" gallery, used by [Chart.set_style()](struct.Chart.html#method.set_style).
+///
+/// The 48 styles are laid out in the gallery as 6 rows of 8 styles, and each variant is named after its row and column.
+/// The rows are `Simple` (styles 1 to 8), `Outline` (9 to 16), `Subtle` (17 to 24), `Moderate` (25 to 32), `Intense` (33 to 40) and `Dark` (41 to 48).
+/// In each row the column 1 is grayscale, the column 2 uses the colors of the theme and the columns 3 to 8 use a single theme accent color.
+///
+/// The Excel default is `Simple2`, the style 2.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ChartStyle {
+    Simple1 = 1,
+    Simple2 = 2,
+    Simple3 = 3,
+    Simple4 = 4,
+    Simple5 = 5,
+    Simple6 = 6,
+    Simple7 = 7,
+    Simple8 = 8,
+    Outline1 = 9,
+    Outline2 = 10,
+    Outline3 = 11,
+    Outline4 = 12,
+    Outline5 = 13,
+    Outline6 = 14,
+    Outline7 = 15,
+    Outline8 = 16,
+    Subtle1 = 17,
+    Subtle2 = 18,
+    Subtle3 = 19,
+    Subtle4 = 20,
+    Subtle5 = 21,
+    Subtle6 = 22,
+    Subtle7 = 23,
+    Subtle8 = 24,
+    Moderate1 = 25,
+    Moderate2 = 26,
+    Moderate3 = 27,
+    Moderate4 = 28,
+    Moderate5 = 29,
+    Moderate6 = 30,
+    Moderate7 = 31,
+    Moderate8 = 32,
+    Intense1 = 33,
+    Intense2 = 34,
+    Intense3 = 35,
+    Intense4 = 36,
+    Intense5 = 37,
+    Intense6 = 38,
+    Intense7 = 39,
+    Intense8 = 40,
+    Dark1 = 41,
+    Dark2 = 42,
+    Dark3 = 43,
+    Dark4 = 44,
+    Dark5 = 45,
+    Dark6 = 46,
+    Dark7 = 47,
+    Dark8 = 48,
+}
+
+impl ChartStyle {
+    /// Returns the Excel style number, from 1 to 48.
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Position of the legend of a chart, used by [Chart.set_legend_position()](struct.Chart.html#method.set_legend_position).
+///
+/// The Excel default is `Right`. The `Overlay` positions display the legend over the plot area instead of reserving space for it.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ChartLegendPosition {
+    /// Remove the legend from the chart.
+    None,
+    Right,
+    Left,
+    Top,
+    Bottom,
+    TopRight,
+    OverlayRight,
+    OverlayLeft,
+    OverlayTopRight,
+}
+
+impl ChartLegendPosition {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            ChartLegendPosition::None => {
+                libxlsxwriter_sys::lxw_chart_legend_position_LXW_CHART_LEGEND_NONE
+            }
+            ChartLegendPosition::Right => {
+                libxlsxwriter_sys::lxw_chart_legend_position_LXW_CHART_LEGEND_RIGHT
+            }
+            ChartLegendPosition::Left => {
+                libxlsxwriter_sys::lxw_chart_legend_position_LXW_CHART_LEGEND_LEFT
+            }
+            ChartLegendPosition::Top => {
+                libxlsxwriter_sys::lxw_chart_legend_position_LXW_CHART_LEGEND_TOP
+            }
+            ChartLegendPosition::Bottom => {
+                libxlsxwriter_sys::lxw_chart_legend_position_LXW_CHART_LEGEND_BOTTOM
+            }
+            ChartLegendPosition::TopRight => {
+                libxlsxwriter_sys::lxw_chart_legend_position_LXW_CHART_LEGEND_TOP_RIGHT
+            }
+            ChartLegendPosition::OverlayRight => {
+                libxlsxwriter_sys::lxw_chart_legend_position_LXW_CHART_LEGEND_OVERLAY_RIGHT
+            }
+            ChartLegendPosition::OverlayLeft => {
+                libxlsxwriter_sys::lxw_chart_legend_position_LXW_CHART_LEGEND_OVERLAY_LEFT
+            }
+            ChartLegendPosition::OverlayTopRight => {
+                libxlsxwriter_sys::lxw_chart_legend_position_LXW_CHART_LEGEND_OVERLAY_TOP_RIGHT
+            }
+        };
+        value as u8
+    }
+}
+
+/// Alignment of the labels of a category axis, used by [ChartAxis.set_label_align()](struct.ChartAxis.html#method.set_label_align).
+///
+/// The Excel default is `Center`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ChartAxisLabelAlignment {
+    Center,
+    Left,
+    Right,
+}
+
+impl ChartAxisLabelAlignment {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            ChartAxisLabelAlignment::Center => {
+                libxlsxwriter_sys::lxw_chart_axis_label_alignment_LXW_CHART_AXIS_LABEL_ALIGN_CENTER
+            }
+            ChartAxisLabelAlignment::Left => {
+                libxlsxwriter_sys::lxw_chart_axis_label_alignment_LXW_CHART_AXIS_LABEL_ALIGN_LEFT
+            }
+            ChartAxisLabelAlignment::Right => {
+                libxlsxwriter_sys::lxw_chart_axis_label_alignment_LXW_CHART_AXIS_LABEL_ALIGN_RIGHT
+            }
+        };
+        value as u8
+    }
+}
+
+/// Units in which the values of a value axis are displayed, used by [ChartAxis.set_display_units()](struct.ChartAxis.html#method.set_display_units).
+///
+/// The values are divided by the unit, for example 2500000 is displayed as 2.5 with `Millions`. The Excel default is `None`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ChartAxisDisplayUnits {
+    None,
+    Hundreds,
+    Thousands,
+    TenThousands,
+    HundredThousands,
+    Millions,
+    TenMillions,
+    HundredMillions,
+    Billions,
+    Trillions,
+}
+
+impl ChartAxisDisplayUnits {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            ChartAxisDisplayUnits::None => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_NONE
+            }
+            ChartAxisDisplayUnits::Hundreds => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_HUNDREDS
+            }
+            ChartAxisDisplayUnits::Thousands => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_THOUSANDS
+            }
+            ChartAxisDisplayUnits::TenThousands => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_TEN_THOUSANDS
+            }
+            ChartAxisDisplayUnits::HundredThousands => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_HUNDRED_THOUSANDS
+            }
+            ChartAxisDisplayUnits::Millions => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_MILLIONS
+            }
+            ChartAxisDisplayUnits::TenMillions => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_TEN_MILLIONS
+            }
+            ChartAxisDisplayUnits::HundredMillions => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_HUNDRED_MILLIONS
+            }
+            ChartAxisDisplayUnits::Billions => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_BILLIONS
+            }
+            ChartAxisDisplayUnits::Trillions => {
+                libxlsxwriter_sys::lxw_chart_axis_display_unit_LXW_CHART_AXIS_UNITS_TRILLIONS
+            }
+        };
+        value as u8
+    }
+}
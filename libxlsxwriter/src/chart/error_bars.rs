@@ -0,0 +1,70 @@
+use super::constants::*;
+use super::structs::*;
+use crate::Workbook;
+
+/// Struct to represent the X or Y error bars of a chart series.
+///
+/// This struct is returned by the `ChartSeries.x_error_bars()` and `ChartSeries.y_error_bars()` functions.
+/// The X and Y error bars of a series are independent, each one has its own type, value and style.
+pub struct ChartErrorBars<'a> {
+    pub(crate) _workbook: &'a Workbook,
+    pub(crate) error_bars: *mut libxlsxwriter_sys::lxw_series_error_bars,
+}
+
+impl<'a> ChartErrorBars<'a> {
+    /// Set the type and the value of the error bars:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_error_bars-set_type-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Line);
+    /// let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// series.y_error_bars().set_type(ChartErrorBarType::Percentage, 10.0);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The value is the fixed value, the percentage or the number of standard deviations. It is ignored for `ChartErrorBarType::StdError`.
+    pub fn set_type(&mut self, error_type: ChartErrorBarType, value: f64) {
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_error_bars(
+                self.error_bars,
+                error_type.value(),
+                value,
+            );
+        }
+    }
+
+    /// Set the direction of the error bars.
+    pub fn set_direction(&mut self, direction: ChartErrorBarDirection) {
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_error_bars_direction(
+                self.error_bars,
+                direction.value(),
+            );
+        }
+    }
+
+    /// Set the end cap style of the error bars.
+    pub fn set_endcap(&mut self, endcap: ChartErrorBarCap) {
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_error_bars_endcap(self.error_bars, endcap.value());
+        }
+    }
+
+    /// Set the line properties of the error bars.
+    pub fn set_line(&mut self, line: &ChartLine) {
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_error_bars_line(self.error_bars, &mut line.value());
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use super::constants::ChartLegendPosition;
+use super::structs::ChartFont;
+use super::Chart;
+use crate::XlsxError;
+
+/// Struct to represent the legend of an Excel chart.
+///
+/// This struct is returned by the `Chart.legend()` function to configure the legend:
+/// ```rust
+/// # use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-chart_legend-1.xlsx");
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// # for i in 0..5 {
+/// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+/// #     worksheet.write_number(i, 1, (i * 5).into(), None)?;
+/// # }
+/// let mut chart = workbook.add_chart(ChartType::Column);
+/// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+/// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+/// let mut legend = chart.legend();
+/// legend.set_position(ChartLegendPosition::Bottom);
+/// legend.set_font(&ChartFont::new().set_bold())?;
+/// # worksheet.insert_chart(1, 3, &chart)?;
+/// # workbook.close()?;
+/// # Ok(())
+/// # }
+/// ```
+/// The legend mutably borrows its chart, because the chart keeps the position and the overlay of the legend which are combined by libxlsxwriter.
+/// The chart can be used again, for example to insert it into a worksheet, once the legend is dropped.
+pub struct ChartLegend<'a, 'b> {
+    pub(crate) chart: &'b mut Chart<'a>,
+}
+
+impl<'a, 'b> ChartLegend<'a, 'b> {
+    /// Set the position of the legend, or remove it with `ChartLegendPosition::None`. The Excel default is `Right`.
+    ///
+    /// Selecting one of the `Overlay` positions also sets the overlay, like [ChartLegend.set_overlay()](struct.ChartLegend.html#method.set_overlay).
+    pub fn set_position(&mut self, position: ChartLegendPosition) {
+        let (position, overlay) = match position {
+            ChartLegendPosition::OverlayRight => (ChartLegendPosition::Right, true),
+            ChartLegendPosition::OverlayLeft => (ChartLegendPosition::Left, true),
+            ChartLegendPosition::OverlayTopRight => (ChartLegendPosition::TopRight, true),
+            position => (position, false),
+        };
+        self.chart.legend_position = position;
+        self.chart.legend_overlay = overlay;
+        self.apply_position();
+    }
+
+    /// Display the legend over the plot area instead of reserving space for it.
+    ///
+    /// Excel only supports the overlay for the `Right`, `Left` and `TopRight` positions, so it is ignored for the other positions.
+    /// The overlay is kept when the position is changed to one of these positions later.
+    pub fn set_overlay(&mut self, overlay: bool) {
+        self.chart.legend_overlay = overlay;
+        self.apply_position();
+    }
+
+    /// Set the font properties of the legend.
+    pub fn set_font(&mut self, font: &ChartFont) -> Result<(), XlsxError> {
+        unsafe {
+            libxlsxwriter_sys::chart_legend_set_font(
+                self.chart.chart,
+                &mut font.to_c_struct()?.chart_font,
+            );
+        }
+        Ok(())
+    }
+
+    /// Remove the entries of some series from the legend. See [Chart.delete_legend_series()](struct.Chart.html#method.delete_legend_series).
+    pub fn delete_series(&mut self, series: &[i16]) -> Result<(), XlsxError> {
+        self.chart.delete_legend_series(series)
+    }
+
+    fn apply_position(&mut self) {
+        let position = match (self.chart.legend_position, self.chart.legend_overlay) {
+            (ChartLegendPosition::Right, true) => ChartLegendPosition::OverlayRight,
+            (ChartLegendPosition::Left, true) => ChartLegendPosition::OverlayLeft,
+            (ChartLegendPosition::TopRight, true) => ChartLegendPosition::OverlayTopRight,
+            (position, _) => position,
+        };
+        unsafe {
+            libxlsxwriter_sys::chart_legend_set_position(self.chart.chart, position.value());
+        }
+    }
+}
@@ -1,11 +1,17 @@
+mod axis;
 mod constants;
+mod error_bars;
+mod legend;
 mod series;
 mod structs;
 
+pub use self::axis::*;
 pub use self::constants::*;
+pub use self::error_bars::*;
+pub use self::legend::*;
 pub use self::series::*;
 pub use self::structs::*;
-use super::{convert_str, Workbook};
+use super::{convert_str, error, Workbook, WorksheetCol, WorksheetRow, XlsxError};
 use std::os::raw::c_char;
 
 /// The Chart object represents an Excel chart. It provides functions for adding data series to the chart and for configuring the chart.
@@ -18,11 +24,12 @@ use std::os::raw::c_char;
 /// let mut worksheet = workbook.add_worksheet(None)?;
 /// write_worksheet(&mut worksheet)?; // write worksheet contents
 /// let mut chart = workbook.add_chart(ChartType::Column);
-/// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"));
-/// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"));
-/// chart.add_series(None, Some("=Sheet1!$C$1:$C$5"));
+/// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+/// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+/// chart.add_series(None, Some("=Sheet1!$C$1:$C$5"))?;
 /// worksheet.insert_chart(1, 3, &chart)?;
-/// workbook.close()
+/// workbook.close()?;
+/// Ok(())
 /// # }
 /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
 /// # for i in 0..5 {
@@ -43,11 +50,27 @@ use std::os::raw::c_char;
 /// Add one or more data series to the chart which refers to data in the workbook using Chart.add_series().
 /// Configure the chart with the other available functions shown below.
 /// Insert the chart into a worksheet using Worksheet.insert_chart().
+///
+/// ### Note
+/// The version of libxlsxwriter used by this crate doesn't support combining charts, so there is no `combine()` function:
+/// a column chart and a line chart can't be displayed in the same plot area. Insert them as two charts, for example side by side.
 pub struct Chart<'a> {
     pub(crate) _workbook: &'a Workbook,
     pub(crate) chart: *mut libxlsxwriter_sys::lxw_chart,
+    pub(crate) series: Vec<*mut libxlsxwriter_sys::lxw_chart_series>,
+    /// The width in pixels set with `set_size()` or `set_width()`, or `None` to derive it from the height.
+    pub(crate) width: Option<u32>,
+    /// The height in pixels set with `set_size()` or `set_height()`, or `None` to derive it from the width.
+    pub(crate) height: Option<u32>,
+    /// The position of the legend without its overlay, set with `set_legend_position()` or `legend()`.
+    pub(crate) legend_position: ChartLegendPosition,
+    /// Whether the legend is displayed over the plot area, set with `legend()`.
+    pub(crate) legend_overlay: bool,
 }
 
+/// The size of a chart inserted into a worksheet in pixels, when its size isn't set.
+pub(crate) const DEFAULT_CHART_SIZE: (u32, u32) = (480, 288);
+
 impl<'a> Chart<'a> {
     /// In Excel a chart **series** is a collection of information that defines which data is plotted such as the categories and values. It is also used to define the formatting for the data.
     ///
@@ -59,9 +82,10 @@ impl<'a> Chart<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"));
+    /// chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # for i in 0..5 {
@@ -81,9 +105,10 @@ impl<'a> Chart<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"));
+    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # for i in 0..5 {
@@ -103,11 +128,12 @@ impl<'a> Chart<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// let mut series = chart.add_series(None, None);
-    /// series.set_categories("Sheet1", 0, 0, 4, 0); // "=Sheet1!$A$1:$A$5"
-    /// series.set_values("Sheet1", 0, 1, 4, 1);     // "=Sheet1!$B$1:$B$5"
+    /// let mut series = chart.add_series(None, None)?;
+    /// series.set_categories("Sheet1", 0, 0, 4, 0)?; // "=Sheet1!$A$1:$A$5"
+    /// series.set_values("Sheet1", 0, 1, 4, 1)?;     // "=Sheet1!$B$1:$B$5"
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # for i in 0..5 {
@@ -117,7 +143,7 @@ impl<'a> Chart<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    /// As shown in the previous example the return value from Chart.add_series() is a `ChartSeries` struct. This can be used in other functions that configure a series.
+    /// As shown in the previous example the return value from Chart.add_series() is a `ChartSeries` struct wrapped in a `Result`. This can be used in other functions that configure a series.
     ///
     /// More than one series can be added to a chart. The series numbering and order in the Excel chart will be the same as the order in which they are added in libxlsxwriter:
     ///
@@ -128,11 +154,12 @@ impl<'a> Chart<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"));
-    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"));
-    /// chart.add_series(None, Some("=Sheet1!$C$1:$C$5"));
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    /// chart.add_series(None, Some("=Sheet1!$C$1:$C$5"))?;
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # for i in 0..5 {
@@ -151,9 +178,10 @@ impl<'a> Chart<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// chart.add_series(Some("=(Sheet1!$A$1:$A$5,Sheet1!$A$10:$A$18)"), Some("=(Sheet1!$B$1:$B$5,Sheet1!$B$10:$B$18)"));
+    /// chart.add_series(Some("=(Sheet1!$A$1:$A$5,Sheet1!$A$10:$A$18)"), Some("=(Sheet1!$B$1:$B$5,Sheet1!$B$10:$B$18)"))?;
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # for i in 0..20 {
@@ -163,15 +191,18 @@ impl<'a> Chart<'a> {
     /// # Ok(())
     /// # }
     /// ```
-
+    ///
+    /// An error is returned if libxlsxwriter cannot create the series, for example when a scatter chart series is added without categories.
+    ///
+    /// The series are plotted, stacked and listed in the legend in the order they are added.
+    /// libxlsxwriter can't reorder the series of a chart, so they must be added in the desired order.
     pub fn add_series(
         &mut self,
         categories: Option<&str>,
         values: Option<&str>,
-    ) -> ChartSeries<'a> {
-        let categories_vec = categories.map(convert_str);
-        let values_vec = values.map(convert_str);
-        let mut const_str = self._workbook.const_str.borrow_mut();
+    ) -> Result<ChartSeries<'a>, XlsxError> {
+        let categories_vec = categories.map(convert_str).transpose()?;
+        let values_vec = values.map(convert_str).transpose()?;
         let series = unsafe {
             libxlsxwriter_sys::chart_add_series(
                 self.chart,
@@ -185,15 +216,547 @@ impl<'a> Chart<'a> {
                     .unwrap_or(std::ptr::null()) as *const c_char,
             )
         };
+        if series.is_null() {
+            return Err(XlsxError::new(error::UNKNOWN_ERROR_CODE));
+        }
+        let mut const_str = self._workbook.const_str.borrow_mut();
         if let Some(x) = categories_vec {
             const_str.push(x);
         }
         if let Some(x) = values_vec {
             const_str.push(x);
         }
-        ChartSeries {
+        self.series.push(series);
+        Ok(ChartSeries {
             _workbook: self._workbook,
             chart_series: series,
+            labels_enabled: false,
+        })
+    }
+
+    /// Returns the number of series added to the chart with [Chart.add_series()](struct.Chart.html#method.add_series):
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-series_count-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    /// assert_eq!(chart.series_count(), 2);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    /// Set the same number format on the Y axis and on the data labels of all the series added so far, so that they stay in sync:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_number_format_all-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?.set_labels();
+    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?.set_labels();
+    /// chart.set_number_format_all("0.0%")?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, f64::from(i) / 10., None)?;
+    /// #     worksheet.write_number(i, 1, f64::from(i) / 20., None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This is the same as calling [ChartAxis.set_num_format()](struct.ChartAxis.html#method.set_num_format) on the Y axis and
+    /// [ChartSeries.set_labels_num_format()](struct.ChartSeries.html#method.set_labels_num_format) on every series.
+    /// Series added after this call keep the default label format, and the format only shows on series whose data labels are enabled.
+    pub fn set_number_format_all(&mut self, num_format: &str) -> Result<(), XlsxError> {
+        self.y_axis().set_num_format(num_format)?;
+        let num_format_vec = convert_str(num_format)?;
+        for series in &self.series {
+            unsafe {
+                libxlsxwriter_sys::chart_series_set_labels_num_format(
+                    *series,
+                    num_format_vec.as_ptr() as *const c_char,
+                );
+            }
+        }
+        self._workbook.const_str.borrow_mut().push(num_format_vec);
+        Ok(())
+    }
+
+    /// This function is used to set the title of the chart:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_title_name-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # let mut chart = workbook.add_chart(ChartType::Column);
+    /// # chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.set_title_name("Year End Results")?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The name can also be a formula such as `"=Sheet1!$A$1"` to link the title to a worksheet cell.
+    pub fn set_title_name(&mut self, name: &str) -> Result<(), XlsxError> {
+        let name_vec = convert_str(name)?;
+        unsafe {
+            libxlsxwriter_sys::chart_title_set_name(self.chart, name_vec.as_ptr() as *const c_char);
+        }
+        self._workbook.const_str.borrow_mut().push(name_vec);
+        Ok(())
+    }
+
+    /// This function is used to link the title of the chart to a worksheet cell, so that the title is updated when the cell changes:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_title_name_range-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 1..6 {
+    /// #     worksheet.write_number(i, 0, (i*10).into(), None)?;
+    /// # }
+    /// let date_format = workbook.add_format().set_num_format("mmmm yyyy");
+    /// worksheet.write_datetime(0, 0, &DateTime::new(2020, 4, 1, 0, 0, 0.0), Some(&date_format))?;
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// chart.set_title_name_range("Sheet1", 0, 0)?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Excel displays the text of the cell as it is shown in the worksheet, so the title follows the number format of the cell,
+    /// like the date formatted as "April 2020" above. libxlsxwriter has no separate number format for chart titles.
+    pub fn set_title_name_range(
+        &mut self,
+        sheet_name: &str,
+        row: WorksheetRow,
+        column: WorksheetCol,
+    ) -> Result<(), XlsxError> {
+        let sheet_name_vec = convert_str(sheet_name)?;
+        unsafe {
+            libxlsxwriter_sys::chart_title_set_name_range(
+                self.chart,
+                sheet_name_vec.as_ptr() as *const c_char,
+                row,
+                column,
+            );
+        }
+        self._workbook.const_str.borrow_mut().push(sheet_name_vec);
+        Ok(())
+    }
+
+    /// This function is used to set the font properties of the chart title, for example a large bold title:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_title_name_font-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.set_title_name("Year End Results")?;
+    /// chart.set_title_name_font(&ChartFont::new().set_size(16.0).set_bold())?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Note
+    /// libxlsxwriter doesn't support displaying the title over the plot area, so there is no `set_title_overlay()` function:
+    /// the title always reserves space above the plot area, which is larger for a larger font.
+    /// To give the plot area all the space of the chart, turn the title off with [Chart.set_title_off()](struct.Chart.html#method.set_title_off)
+    /// and write the title in a worksheet cell above the chart.
+    pub fn set_title_name_font(&mut self, font: &ChartFont) -> Result<(), XlsxError> {
+        unsafe {
+            libxlsxwriter_sys::chart_title_set_name_font(
+                self.chart,
+                &mut font.to_c_struct()?.chart_font,
+            );
+        }
+        Ok(())
+    }
+
+    /// This function is used to turn off the automatic title of a chart with a single series.
+    pub fn set_title_off(&mut self) {
+        unsafe {
+            libxlsxwriter_sys::chart_title_off(self.chart);
+        }
+    }
+
+    /// This function is used to set the style of the chart to one of the 48 built-in styles of the Excel "Chart Styles" gallery:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_style-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.set_style(ChartStyle::Dark2);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// See [ChartStyle](enum.ChartStyle.html) for the layout of the styles.
+    /// The style only sets the default colors and effects: the formatting set with the other chart functions takes precedence over it.
+    pub fn set_style(&mut self, style: ChartStyle) {
+        unsafe {
+            libxlsxwriter_sys::chart_set_style(self.chart, style.value());
+        }
+    }
+
+    /// Set the size of the chart in pixels when it is inserted into a worksheet:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_size-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// assert_eq!(chart.default_size(), (480, 288));
+    /// chart.set_size(720, 432);
+    /// assert_eq!(chart.default_size(), (720, 432));
+    /// worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// libxlsxwriter has no chart size, so [Worksheet.insert_chart()](struct.Worksheet.html#method.insert_chart) scales the default 480x288 chart to this size.
+    /// The size is ignored when the chart is displayed in a chartsheet, which is always filled by its chart.
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.width = Some(width);
+        self.height = Some(height);
+    }
+
+    /// Set the width of the chart in pixels. The height keeps the 5:3 aspect ratio of the default size unless it was set:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_width-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.set_width(600);
+    /// assert_eq!(chart.default_size(), (600, 360));
+    /// chart.set_height(300);
+    /// assert_eq!(chart.default_size(), (600, 300));
+    /// worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The derived dimension is rounded to the nearest pixel. Once both the width and the height are set, with these functions or with
+    /// [Chart.set_size()](struct.Chart.html#method.set_size), changing one of them doesn't change the other.
+    pub fn set_width(&mut self, width: u32) {
+        self.width = Some(width);
+    }
+
+    /// Set the height of the chart in pixels. The width keeps the 5:3 aspect ratio of the default size unless it was set,
+    /// see [Chart.set_width()](struct.Chart.html#method.set_width).
+    pub fn set_height(&mut self, height: u32) {
+        self.height = Some(height);
+    }
+
+    /// Returns the `(width, height)` in pixels of the chart when it is inserted into a worksheet, which is 480x288 unless it was changed with
+    /// [Chart.set_size()](struct.Chart.html#method.set_size), [Chart.set_width()](struct.Chart.html#method.set_width) or [Chart.set_height()](struct.Chart.html#method.set_height).
+    /// It can be used to plan the layout of the charts of a worksheet before inserting them.
+    pub fn default_size(&self) -> (u32, u32) {
+        let (default_width, default_height) = DEFAULT_CHART_SIZE;
+        let scale = |value: u32, to: u32, from: u32| {
+            ((u64::from(value) * u64::from(to) + u64::from(from) / 2) / u64::from(from)) as u32
+        };
+        match (self.width, self.height) {
+            (None, None) => DEFAULT_CHART_SIZE,
+            (Some(width), None) => (width, scale(width, default_height, default_width)),
+            (None, Some(height)) => (scale(height, default_width, default_height), height),
+            (Some(width), Some(height)) => (width, height),
+        }
+    }
+
+    /// This function is used to set the gap between the bars or columns of a Bar or Column chart, as a percentage of the bar width:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_series_gap-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i * 10 + 2).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    /// chart.set_series_gap(50)?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The range is 0 to 500, the Excel default is 150. Values outside of the range return an error instead of being changed by Excel.
+    ///
+    /// The gap and the overlap are properties of the chart in Excel, not of a series: they apply to all the series of the chart.
+    pub fn set_series_gap(&mut self, gap: u16) -> Result<(), XlsxError> {
+        if gap > 500 {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        unsafe {
+            libxlsxwriter_sys::chart_set_series_gap(self.chart, gap);
+        }
+        Ok(())
+    }
+
+    /// This function is used to set the overlap between the bars or columns of the series of a Bar or Column chart, as a percentage of the bar width:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_series_overlap-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i * 10 + 2).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Bar);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    /// chart.set_series_overlap(-20)?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The range is -100 to 100. A negative value adds a space between the bars of the series.
+    /// Values outside of the range return an error instead of being changed by Excel.
+    pub fn set_series_overlap(&mut self, overlap: i8) -> Result<(), XlsxError> {
+        if !(-100..=100).contains(&overlap) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        unsafe {
+            libxlsxwriter_sys::chart_set_series_overlap(self.chart, overlap);
+        }
+        Ok(())
+    }
+
+    /// This function sets both the gap and the overlap of the bars or columns, which are usually set together:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-configure_bars-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i * 10 + 2).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    /// chart.configure_bars(100, 0)?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The values are validated like [Chart.set_series_gap()](struct.Chart.html#method.set_series_gap) and
+    /// [Chart.set_series_overlap()](struct.Chart.html#method.set_series_overlap), and the chart isn't changed when one of them is out of range.
+    ///
+    /// In the clustered `Bar` and `Column` charts the bars of the series are side by side, and the overlap controls how much they cover each other.
+    /// In the stacked and percent stacked charts the bars of the series are stacked, so libxlsxwriter uses an overlap of 100 by default:
+    /// any other overlap moves the parts of a stack apart. Use 100 for these charts to only change the gap.
+    pub fn configure_bars(&mut self, gap: u16, overlap: i8) -> Result<(), XlsxError> {
+        if gap > 500 || !(-100..=100).contains(&overlap) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        self.set_series_gap(gap)?;
+        self.set_series_overlap(overlap)
+    }
+
+    /// This function is used to set the position of the chart legend, or to remove it:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_legend_position-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.set_legend_position(ChartLegendPosition::Bottom);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// `ChartLegendPosition::None` removes the whole legend, and the plot area is enlarged to use its space.
+    /// To remove only some entries of the legend, use [Chart.delete_legend_series()](struct.Chart.html#method.delete_legend_series).
+    ///
+    /// This is the same as [ChartLegend.set_position()](struct.ChartLegend.html#method.set_position) on the legend returned by [Chart.legend()](struct.Chart.html#method.legend).
+    pub fn set_legend_position(&mut self, position: ChartLegendPosition) {
+        self.legend().set_position(position);
+    }
+
+    /// Get the legend of the chart. The returned `ChartLegend` is used to configure the position, the overlay, the font and the entries of the legend.
+    pub fn legend<'b>(&'b mut self) -> ChartLegend<'a, 'b> {
+        ChartLegend { chart: self }
+    }
+
+    /// This function is used to remove the entries of some series from the chart legend. The series are identified by their zero-based index, in the order they were added:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-delete_legend_series-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i * 10 + 2).into(), None)?;
+    /// #     worksheet.write_number(i, 2, (i * 10 + 4).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    /// chart.add_series(None, Some("=Sheet1!$C$1:$C$5"))?;
+    /// // Hide the entries of the first and the third series.
+    /// chart.delete_legend_series(&[0, 2])?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Each call replaces the entries deleted by the previous one. Negative indices return an error.
+    ///
+    /// Deleting the entries of all the series displays no entries, like removing the legend with
+    /// [Chart.set_legend_position()](struct.Chart.html#method.set_legend_position) and `ChartLegendPosition::None`, but Excel still reserves the space of the empty legend.
+    /// The two functions don't conflict: the deleted entries are ignored when the legend is removed.
+    pub fn delete_legend_series(&mut self, series: &[i16]) -> Result<(), XlsxError> {
+        if series.iter().any(|index| *index < 0) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        // libxlsxwriter copies the list, which is terminated by -1.
+        let mut delete_series: Vec<i16> = series.to_vec();
+        delete_series.push(-1);
+        unsafe {
+            let result = libxlsxwriter_sys::chart_legend_delete_series(
+                self.chart,
+                delete_series.as_mut_ptr(),
+            );
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
         }
     }
+
+    /// Get the X axis of the chart. The returned `ChartAxis` is used to configure the axis.
+    pub fn x_axis(&mut self) -> ChartAxis<'a> {
+        ChartAxis {
+            _workbook: self._workbook,
+            chart_axis: unsafe { (*self.chart).x_axis },
+        }
+    }
+
+    /// Get the Y axis of the chart. The returned `ChartAxis` is used to configure the axis.
+    pub fn y_axis(&mut self) -> ChartAxis<'a> {
+        ChartAxis {
+            _workbook: self._workbook,
+            chart_axis: unsafe { (*self.chart).y_axis },
+        }
+    }
+
+    /// Applies the same configuration to the X and Y axes of the chart, for example to use the same font for the numbers of both axes:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-configure_axes-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// let font = ChartFont::new().set_name("Arial").set_size(9.0);
+    /// chart.configure_axes(|axis| axis.set_num_font(&font))?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The closure is called with the X axis and then with the Y axis, and the first error it returns is returned without configuring the Y axis.
+    /// Use [Chart.x_axis()](struct.Chart.html#method.x_axis) and [Chart.y_axis()](struct.Chart.html#method.y_axis) for the settings which differ.
+    pub fn configure_axes<F: FnMut(&mut ChartAxis<'a>) -> Result<(), XlsxError>>(
+        &mut self,
+        mut f: F,
+    ) -> Result<(), XlsxError> {
+        f(&mut self.x_axis())?;
+        f(&mut self.y_axis())
+    }
+
+    /// This function applies the same font to the text of the whole chart, for example the font of a corporate style:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_default_font-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i * 5).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    /// chart.set_title_name("Quarterly Results")?;
+    /// chart.x_axis().set_name("Quarter")?;
+    /// chart.y_axis().set_name("Revenue")?;
+    /// let corporate = ChartFont::new()
+    ///     .set_name("Calibri Light")
+    ///     .set_color(FormatColor::Custom(0x1F3864));
+    /// chart.set_default_font(&corporate)?;
+    /// // The settings which differ are set afterwards.
+    /// chart.set_title_name_font(&corporate.clone().set_size(16.0).set_bold())?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The font is applied with [Chart.set_title_name_font()](struct.Chart.html#method.set_title_name_font), and with
+    /// [ChartAxis.set_name_font()](struct.ChartAxis.html#method.set_name_font) and [ChartAxis.set_num_font()](struct.ChartAxis.html#method.set_num_font)
+    /// on the X and Y axes, and with [ChartLegend.set_font()](struct.ChartLegend.html#method.set_font). The fonts of the titles apply when the titles are displayed.
+    ///
+    /// The data labels of the series aren't changed, because they are set per series with
+    /// [ChartSeries.set_labels_font()](struct.ChartSeries.html#method.set_labels_font). Each call replaces the whole font of these elements,
+    /// so a rotation or a size given in `font` applies to all of them.
+    pub fn set_default_font(&mut self, font: &ChartFont) -> Result<(), XlsxError> {
+        self.set_title_name_font(font)?;
+        self.configure_axes(|axis| {
+            axis.set_name_font(font)?;
+            axis.set_num_font(font)
+        })?;
+        self.legend().set_font(font)
+    }
 }
@@ -1,13 +1,20 @@
 use super::constants::*;
+use super::error_bars::*;
 use super::structs::*;
-use crate::{convert_bool, convert_str, Workbook, WorksheetCol, WorksheetRow};
+use crate::{
+    convert_bool, convert_str, CellRange, Workbook, WorksheetCol, WorksheetRow, XlsxError,
+};
 use std::os::raw::c_char;
 
 /// Struct to represent an Excel chart data series.
 /// This struct is created using the chart.add_series() function. It is used in functions that modify a chart series but the members of the struct aren't modified directly.
+///
+/// The gap and the overlap of the bars of Bar and Column charts are set for all the series with
+/// [Chart.set_series_gap()](struct.Chart.html#method.set_series_gap) and [Chart.set_series_overlap()](struct.Chart.html#method.set_series_overlap).
 pub struct ChartSeries<'a> {
     pub(crate) _workbook: &'a Workbook,
     pub(crate) chart_series: *mut libxlsxwriter_sys::lxw_chart_series,
+    pub(crate) labels_enabled: bool,
 }
 
 impl<'a> ChartSeries<'a> {
@@ -21,11 +28,12 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// let mut series = chart.add_series(None, None);
-    /// series.set_categories("Sheet1", 0, 0, 4, 0); // "=Sheet1!$A$1:$A$5"
-    /// series.set_values("Sheet1", 0, 1, 4, 1);     // "=Sheet1!$B$1:$B$5"
+    /// let mut series = chart.add_series(None, None)?;
+    /// series.set_categories("Sheet1", 0, 0, 4, 0)?; // "=Sheet1!$A$1:$A$5"
+    /// series.set_values("Sheet1", 0, 1, 4, 1)?;     // "=Sheet1!$B$1:$B$5"
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # for i in 0..5 {
@@ -42,8 +50,8 @@ impl<'a> ChartSeries<'a> {
         first_column: WorksheetCol,
         last_row: WorksheetRow,
         last_column: WorksheetCol,
-    ) {
-        let sheet_name_vec = convert_str(sheet_name);
+    ) -> Result<(), XlsxError> {
+        let sheet_name_vec = convert_str(sheet_name)?;
         unsafe {
             libxlsxwriter_sys::chart_series_set_categories(
                 self.chart_series,
@@ -55,6 +63,7 @@ impl<'a> ChartSeries<'a> {
             );
         }
         self._workbook.const_str.borrow_mut().push(sheet_name_vec);
+        Ok(())
     }
 
     /// The categories and values of a chart data series are generally set using the `Chart.add_series()` function and Excel range formulas like "=Sheet1!$A$2:$A$7".
@@ -67,8 +76,8 @@ impl<'a> ChartSeries<'a> {
         first_column: WorksheetCol,
         last_row: WorksheetRow,
         last_column: WorksheetCol,
-    ) {
-        let sheet_name_vec = convert_str(sheet_name);
+    ) -> Result<(), XlsxError> {
+        let sheet_name_vec = convert_str(sheet_name)?;
         unsafe {
             libxlsxwriter_sys::chart_series_set_values(
                 self.chart_series,
@@ -80,6 +89,7 @@ impl<'a> ChartSeries<'a> {
             );
         }
         self._workbook.const_str.borrow_mut().push(sheet_name_vec);
+        Ok(())
     }
 
     /// This function is used to set the name for a chart data series. The series name in Excel is displayed in the chart legend and in the formula bar. The name property is optional and if it isn't supplied it will default to `Series 1..n`.
@@ -91,10 +101,11 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// let mut series = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// series.set_name("Quarterly budget data");
+    /// let mut series = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// series.set_name("Quarterly budget data")?;
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -117,10 +128,11 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// let mut series = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// series.set_name("=Sheet1!$A$1:$A$1");
+    /// let mut series = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// series.set_name("=Sheet1!$A$1:$A$1")?;
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -134,8 +146,8 @@ impl<'a> ChartSeries<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_name(&mut self, name: &str) {
-        let name_vec = convert_str(name);
+    pub fn set_name(&mut self, name: &str) -> Result<(), XlsxError> {
+        let name_vec = convert_str(name)?;
         unsafe {
             libxlsxwriter_sys::chart_series_set_name(
                 self.chart_series,
@@ -143,6 +155,68 @@ impl<'a> ChartSeries<'a> {
             );
         }
         self._workbook.const_str.borrow_mut().push(name_vec);
+        Ok(())
+    }
+
+    /// This function sets the name, the categories and the values of a series from cell ranges of the worksheet `sheet_name` in one call,
+    /// for example when a configuration block of the worksheet drives a chart:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_from_config-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Column);
+    /// let mut series = chart.add_series(None, None)?;
+    /// series.set_from_config(
+    ///     "Sheet1",
+    ///     &CellRange::new(0, 1, 0, 1), // =Sheet1!$B$1
+    ///     &CellRange::new(1, 0, 5, 0), // =Sheet1!$A$2:$A$6
+    ///     &CellRange::new(1, 1, 5, 1), // =Sheet1!$B$2:$B$6
+    /// )?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # worksheet.write_string(0, 1, "Sales", None)?;
+    /// # for i in 1..6 {
+    /// #     worksheet.write_string(i, 0, &format!("Month {}", i), None)?;
+    /// #     worksheet.write_number(i, 1, (i*10).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// It is the same as [ChartSeries.set_name_range()](struct.ChartSeries.html#method.set_name_range), [ChartSeries.set_categories()](struct.ChartSeries.html#method.set_categories)
+    /// and [ChartSeries.set_values()](struct.ChartSeries.html#method.set_values), so the chart is updated by Excel when the cells change.
+    /// The series should be added with `None` categories and values. `name_cell` must be a single cell, otherwise an error is returned and the series isn't changed.
+    pub fn set_from_config(
+        &mut self,
+        sheet_name: &str,
+        name_cell: &CellRange,
+        categories: &CellRange,
+        values: &CellRange,
+    ) -> Result<(), XlsxError> {
+        if name_cell.first_row != name_cell.last_row || name_cell.first_col != name_cell.last_col {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        self.set_name_range(sheet_name, name_cell.first_row, name_cell.first_col)?;
+        self.set_categories(
+            sheet_name,
+            categories.first_row,
+            categories.first_col,
+            categories.last_row,
+            categories.last_col,
+        )?;
+        self.set_values(
+            sheet_name,
+            values.first_row,
+            values.first_col,
+            values.last_row,
+            values.last_col,
+        )
     }
 
     /// The `ChartSeries.set_name_range()` function can be used to set a series name range and is an alternative to using `ChartSeries.set_name()` and a string formula:
@@ -153,10 +227,11 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// let mut series = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"));
-    /// series.set_name_range("Sheet1", 0, 1); // =Sheet1!$B$1
+    /// let mut series = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"))?;
+    /// series.set_name_range("Sheet1", 0, 1)?; // =Sheet1!$B$1
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -170,8 +245,35 @@ impl<'a> ChartSeries<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_name_range(&mut self, sheet_name: &str, row: WorksheetRow, column: WorksheetCol) {
-        let sheet_name_vec = convert_str(sheet_name);
+    /// The name cell may contain a number or a date instead of a string. Excel then displays the formatted value of the cell in the legend,
+    /// so the format of the cell applies to the legend entry, and the legend follows the cell when its value or its format change:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_name_range-2.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let month = workbook.add_format().set_num_format("mmm yyyy");
+    /// worksheet.write_datetime(0, 0, &DateTime::date(2024, 3, 1), Some(&month))?;
+    /// # for i in 1..6 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// let mut series = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// series.set_name_range("Sheet1", 0, 0)?; // the legend shows "Mar 2024"
+    /// chart.set_legend_position(ChartLegendPosition::Bottom);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Applications which display the value cached in the file instead of reading the cell may show the name without the format of the cell.
+    pub fn set_name_range(
+        &mut self,
+        sheet_name: &str,
+        row: WorksheetRow,
+        column: WorksheetCol,
+    ) -> Result<(), XlsxError> {
+        let sheet_name_vec = convert_str(sheet_name)?;
         unsafe {
             libxlsxwriter_sys::chart_series_set_name_range(
                 self.chart_series,
@@ -181,6 +283,7 @@ impl<'a> ChartSeries<'a> {
             );
         }
         self._workbook.const_str.borrow_mut().push(sheet_name_vec);
+        Ok(())
     }
 
     /// Set the line/border properties of a chart series:
@@ -191,16 +294,17 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// let mut series2 = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"));
-    /// let mut series3 = chart.add_series(None, Some("=Sheet1!$C$2:$C$6"));
+    /// let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// let mut series2 = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"))?;
+    /// let mut series3 = chart.add_series(None, Some("=Sheet1!$C$2:$C$6"))?;
     /// let mut chart_line = ChartLine::new();
     /// chart_line.color = FormatColor::Red;
     /// series1.set_line(&chart_line);
     /// series2.set_line(&chart_line);
     /// series3.set_line(&chart_line);
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -221,6 +325,34 @@ impl<'a> ChartSeries<'a> {
         }
     }
 
+    /// Hide the line of a chart series. This turns a scatter chart with lines and markers into a chart of points:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_line_none-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..10 {
+    /// #     worksheet.write_number(i, 0, i.into(), None)?;
+    /// #     worksheet.write_number(i, 1, ((i * 7) % 10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::ScatterStraightWithMarkers);
+    /// let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$10"), Some("=Sheet1!$B$1:$B$10"))?;
+    /// series.set_line_none();
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This is the same as [ChartSeries.set_line()](struct.ChartSeries.html#method.set_line) with a [ChartLine](struct.ChartLine.html) whose `none` field is `true`.
+    /// Only the line is hidden: the markers are kept, so a series without markers, such as a series of a `ScatterStraight` chart, becomes invisible.
+    /// A `Scatter` chart already displays its series as points without lines.
+    pub fn set_line_none(&mut self) {
+        self.set_line(&ChartLine {
+            none: true,
+            ..ChartLine::default()
+        });
+    }
+
     /// Set the fill properties of a chart series:
     /// ```rust
     /// # use xlsxwriter::*;
@@ -229,9 +361,9 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// # let mut series2 = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"));
-    /// # let mut series3 = chart.add_series(None, Some("=Sheet1!$C$2:$C$6"));
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// # let mut series2 = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"))?;
+    /// # let mut series3 = chart.add_series(None, Some("=Sheet1!$C$2:$C$6"))?;
     /// let mut chart_fill_1 = ChartFill::new();
     /// chart_fill_1.color = FormatColor::Red;
     /// let mut chart_fill_2 = ChartFill::new();
@@ -242,7 +374,8 @@ impl<'a> ChartSeries<'a> {
     /// series2.set_fill(&chart_fill_2);
     /// series3.set_fill(&chart_fill_3);
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -263,6 +396,38 @@ impl<'a> ChartSeries<'a> {
         }
     }
 
+    /// Remove the area fill of a chart series, for example to display an area series like a line:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_fill_none-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Area);
+    /// let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// series.set_fill_none();
+    /// series.set_line(&ChartLine {
+    ///     color: FormatColor::Blue,
+    ///     width: 2.0,
+    ///     ..ChartLine::new()
+    /// });
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This is the same as [ChartSeries.set_fill()](struct.ChartSeries.html#method.set_fill) with the `none` field of [ChartFill](struct.ChartFill.html) set:
+    /// the series is written with no fill instead of the automatic fill of the chart style. The border line of the series isn't changed,
+    /// so an area series without a fill and without a line isn't visible.
+    pub fn set_fill_none(&mut self) {
+        self.set_fill(&ChartFill {
+            none: true,
+            ..ChartFill::new()
+        });
+    }
+
     /// Invert the fill color for negative values. Usually only applicable to column and bar charts.
     /// ```rust
     /// # use xlsxwriter::*;
@@ -271,12 +436,12 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// # let mut series2 = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"));
-    /// # let mut series3 = chart.add_series(None, Some("=Sheet1!$C$2:$C$6"));
-    /// # series1.set_name("=Sheet1!$A$1");
-    /// # series2.set_name("=Sheet1!$B$1");
-    /// # series3.set_name("=Sheet1!$C$1");
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// # let mut series2 = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"))?;
+    /// # let mut series3 = chart.add_series(None, Some("=Sheet1!$C$2:$C$6"))?;
+    /// # series1.set_name("=Sheet1!$A$1")?;
+    /// # series2.set_name("=Sheet1!$B$1")?;
+    /// # series3.set_name("=Sheet1!$C$1")?;
     /// # let mut chart_fill_1 = ChartFill::new();
     /// # chart_fill_1.color = FormatColor::Red;
     /// # let mut chart_fill_2 = ChartFill::new();
@@ -290,7 +455,8 @@ impl<'a> ChartSeries<'a> {
     /// # series3.set_fill(&chart_fill_3);
     /// # series3.set_invert_if_negative();
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -319,16 +485,17 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Column);
-    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// # let mut series2 = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"));
-    /// # series1.set_name("=Sheet1!$A$1");
-    /// # series2.set_name("=Sheet1!$B$1");
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// # let mut series2 = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"))?;
+    /// # series1.set_name("=Sheet1!$A$1")?;
+    /// # series2.set_name("=Sheet1!$B$1")?;
     /// let pattern1 = ChartPattern::new(FormatColor::Custom(0x804000), FormatColor::Custom(0xC68C53), ChartPatternType::Shingle);
     /// series1.set_pattern(&pattern1);
     /// let pattern2 = ChartPattern::new(FormatColor::Custom(0xB30000), FormatColor::Custom(0xFF6666), ChartPatternType::HorizontalBrick);
     /// series2.set_pattern(&pattern2);
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Shingle", None)?;
@@ -356,11 +523,12 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Line);
-    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// # series1.set_name("=Sheet1!$A$1");
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// # series1.set_name("=Sheet1!$A$1")?;
     /// series1.set_marker_type(ChartMarkerType::MarkerDiamond);
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -372,6 +540,29 @@ impl<'a> ChartSeries<'a> {
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-chart_series-set_marker_type-1.png)
+    ///
+    /// The default markers depend on the chart type: `Line`, `Radar` with markers and the `Scatter` types with markers show automatic markers,
+    /// and the other types show none. `ChartMarkerType::MarkerNone` overrides the default, for example to hide the markers of some series
+    /// of a `ScatterSmoothWithMarkers` chart:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_marker-type-2.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, i.into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i * i).into(), None)?;
+    /// #     worksheet.write_number(i, 2, (i * 3).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::ScatterSmoothWithMarkers);
+    /// chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    /// let mut trend = chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$C$1:$C$5"))?;
+    /// trend.set_marker_type(ChartMarkerType::MarkerNone); // a smooth line only
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn set_marker_type(&mut self, maker_type: ChartMarkerType) {
         unsafe {
             libxlsxwriter_sys::chart_series_set_marker_type(self.chart_series, maker_type.value())
@@ -386,12 +577,13 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Line);
-    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// # series1.set_name("=Sheet1!$A$1");
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// # series1.set_name("=Sheet1!$A$1")?;
     /// series1.set_marker_type(ChartMarkerType::MarkerDiamond);
     /// series1.set_marker_size(10);
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -414,15 +606,16 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Line);
-    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// # series1.set_name("=Sheet1!$A$1");
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// # series1.set_name("=Sheet1!$A$1")?;
     /// series1.set_marker_type(ChartMarkerType::MarkerDiamond);
     /// let mut marker_line = ChartLine::new();
     /// marker_line.color = FormatColor::Red;
     /// series1.set_marker_line(&marker_line);
     /// series1.set_marker_size(10);
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -450,8 +643,8 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Line);
-    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// # series1.set_name("=Sheet1!$A$1");
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// # series1.set_name("=Sheet1!$A$1")?;
     /// series1.set_marker_type(ChartMarkerType::MarkerDiamond);
     /// let mut marker_line = ChartLine::new();
     /// marker_line.color = FormatColor::Red;
@@ -461,7 +654,8 @@ impl<'a> ChartSeries<'a> {
     /// series1.set_marker_fill(&marker_fill);
     /// series1.set_marker_size(10);
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -481,7 +675,105 @@ impl<'a> ChartSeries<'a> {
         }
     }
 
-    // TODO: chart_series_set_points
+    /// Set the formatting and the data labels of the individual points of a series, for example the slices of a pie chart.
+    /// The first element of `points` is used for the first point of the series, and so on. Use `ChartPoint::new()` for the points
+    /// which keep the formatting of the series.
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_points-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Pie);
+    /// # let mut series1 = chart.add_series(Some("=Sheet1!$A$1:$A$3"), Some("=Sheet1!$B$1:$B$3"))?;
+    /// series1.set_labels();
+    /// let mut other = ChartPoint::new();
+    /// other.fill = Some(ChartFill {
+    ///     color: FormatColor::Gray,
+    ///     ..ChartFill::new()
+    /// });
+    /// other.label_text = Some("Other".to_string());
+    /// series1.set_points(&[ChartPoint::new(), ChartPoint::new(), other])?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for (i, name) in ["Apples", "Pears", "Misc"].iter().enumerate() {
+    /// #     worksheet.write_string(i as WorksheetRow, 0, name, None)?;
+    /// #     worksheet.write_number(i as WorksheetRow, 1, (i * 10 + 5) as f64, None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// When a point has a `label_text`, the data labels of the series are turned on and the points without a `label_text` keep their default label.
+    pub fn set_points(&mut self, points: &[ChartPoint]) -> Result<(), XlsxError> {
+        let mut lines: Vec<_> = points.iter().map(|x| x.line.map(|x| x.value())).collect();
+        let mut fills: Vec<_> = points
+            .iter()
+            .map(|x| x.fill.as_ref().map(|x| x.value()))
+            .collect();
+        let mut patterns: Vec<_> = points
+            .iter()
+            .map(|x| x.pattern.map(|x| x.value()))
+            .collect();
+        let mut c_points: Vec<_> = lines
+            .iter_mut()
+            .zip(fills.iter_mut())
+            .zip(patterns.iter_mut())
+            .map(
+                |((line, fill), pattern)| libxlsxwriter_sys::lxw_chart_point {
+                    line: option_to_mut_ptr(line),
+                    fill: option_to_mut_ptr(fill),
+                    pattern: option_to_mut_ptr(pattern),
+                },
+            )
+            .collect();
+        let mut c_point_ptrs: Vec<_> = c_points.iter_mut().map(|x| x as *mut _).collect();
+        c_point_ptrs.push(std::ptr::null_mut());
+        unsafe {
+            let result = libxlsxwriter_sys::chart_series_set_points(
+                self.chart_series,
+                c_point_ptrs.as_mut_ptr(),
+            );
+            if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                return Err(XlsxError::new(result));
+            }
+        }
+
+        if points.iter().all(|x| x.label_text.is_none()) {
+            return Ok(());
+        }
+        let label_texts = points
+            .iter()
+            .map(|x| x.label_text.as_deref().map(convert_str).transpose())
+            .collect::<Result<Vec<_>, XlsxError>>()?;
+        let mut c_labels: Vec<_> = label_texts
+            .iter()
+            .map(|text| {
+                let mut c_label: libxlsxwriter_sys::lxw_chart_data_label =
+                    unsafe { std::mem::zeroed() };
+                c_label.value = text
+                    .as_ref()
+                    .map(|x| x.as_ptr() as *mut c_char)
+                    .unwrap_or(std::ptr::null_mut());
+                c_label
+            })
+            .collect();
+        let mut c_label_ptrs: Vec<_> = c_labels.iter_mut().map(|x| x as *mut _).collect();
+        c_label_ptrs.push(std::ptr::null_mut());
+        unsafe {
+            let result = libxlsxwriter_sys::chart_series_set_labels_custom(
+                self.chart_series,
+                c_label_ptrs.as_mut_ptr(),
+            );
+            if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                return Err(XlsxError::new(result));
+            }
+        }
+        self.labels_enabled = true;
+        Ok(())
+    }
 
     /// This function is used to set the smooth property of a line series. It is only applicable to the line and scatter chart types.
     /// ```rust
@@ -491,11 +783,12 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Line);
-    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// # series1.set_name("=Sheet1!$A$1");
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// # series1.set_name("=Sheet1!$A$1")?;
     /// series1.set_smooth(true);
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -507,6 +800,11 @@ impl<'a> ChartSeries<'a> {
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-chart_series-set_smooth-1.png)
+    ///
+    /// The smooth property only changes the line of the series and is independent of its markers:
+    /// a smooth series keeps the markers set with [ChartSeries.set_marker_type()](struct.ChartSeries.html#method.set_marker_type), or the default markers of the chart type.
+    /// For example a `ChartType::ScatterSmooth` chart has no markers by default, and setting a marker type on one of its series displays a smooth line with markers for that series.
+    /// A `ChartType::Scatter` chart has no lines, so smoothing its series has no visible effect unless a line is set with [ChartSeries.set_line()](struct.ChartSeries.html#method.set_line).
     pub fn set_smooth(&mut self, smooth: bool) {
         unsafe {
             libxlsxwriter_sys::chart_series_set_smooth(self.chart_series, convert_bool(smooth))
@@ -521,12 +819,13 @@ impl<'a> ChartSeries<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// # write_worksheet(&mut worksheet)?; // write worksheet contents
     /// # let mut chart = workbook.add_chart(ChartType::Line);
-    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"));
-    /// # series1.set_name("=Sheet1!$A$1");
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// # series1.set_name("=Sheet1!$A$1")?;
     /// series1.set_marker_type(ChartMarkerType::MarkerDiamond);
     /// series1.set_labels();
     /// # worksheet.insert_chart(1, 3, &chart)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
     /// # worksheet.write_string(0, 0, "Set 1", None)?;
@@ -540,5 +839,326 @@ impl<'a> ChartSeries<'a> {
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-chart_series-set_smooth-1.png)
     pub fn set_labels(&mut self) {
         unsafe { libxlsxwriter_sys::chart_series_set_labels(self.chart_series) }
+        self.labels_enabled = true;
     }
+
+    /// This function is used to set the parameters of the data labels of a chart series. Data labels are turned on when this function is called.
+    ///
+    /// * `show_name`: Display the series name.
+    /// * `show_category`: Display the series category.
+    /// * `show_value`: Display the series value.
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_labels_options-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Column);
+    /// # let mut series1 = chart.add_series(Some("=Sheet1!$A$2:$A$6"), Some("=Sheet1!$B$2:$B$6"))?;
+    /// series1.set_labels_options(false, true, true);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 1..6 {
+    /// #     worksheet.write_string(i, 0, &format!("Item {}", i), None)?;
+    /// #     worksheet.write_number(i, 1, (i * 10).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_labels_options(&mut self, show_name: bool, show_category: bool, show_value: bool) {
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_labels_options(
+                self.chart_series,
+                convert_bool(show_name),
+                convert_bool(show_category),
+                convert_bool(show_value),
+            )
+        }
+        self.labels_enabled = true;
+    }
+
+    /// This function is used to set the position of the data labels of a chart series:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_labels_position-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Column);
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// series1.set_labels();
+    /// series1.set_labels_position(ChartLabelPosition::OutsideEnd);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 1..6 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Not all positions are valid for all chart types. An unsupported position is ignored by libxlsxwriter and the default position is used.
+    pub fn set_labels_position(&mut self, position: ChartLabelPosition) {
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_labels_position(self.chart_series, position.value())
+        }
+    }
+
+    /// This function is used to set the number format of the data labels of a chart series:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_labels_num_format-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Column);
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// series1.set_labels();
+    /// series1.set_labels_num_format("$#,##0")?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 1..6 {
+    /// #     worksheet.write_number(i, 0, (i * 1000).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// By default the data labels use the number format of the source cells of the values, like the labels created in Excel.
+    /// Setting a number format replaces it, and [ChartSeries.set_labels_num_format_linked()](struct.ChartSeries.html#method.set_labels_num_format_linked) restores the default.
+    pub fn set_labels_num_format(&mut self, num_format: &str) -> Result<(), XlsxError> {
+        let num_format_vec = convert_str(num_format)?;
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_labels_num_format(
+                self.chart_series,
+                num_format_vec.as_ptr() as *const c_char,
+            );
+        }
+        self._workbook.const_str.borrow_mut().push(num_format_vec);
+        Ok(())
+    }
+
+    /// This function is used to make the data labels of a chart series use the number format of the source cells of the values, so that they follow the worksheet formatting:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_labels_num_format_linked-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let currency = workbook.add_format().set_num_format("$#,##0.00");
+    /// for i in 1..6 {
+    ///     worksheet.write_number(i, 0, (i * 1250).into(), Some(&currency))?;
+    /// }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// series1.set_labels();
+    /// // The labels are displayed as $1,250.00, $2,500.00, ...
+    /// series1.set_labels_num_format_linked();
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This is the default, so this function is only needed to remove a number format set with
+    /// [ChartSeries.set_labels_num_format()](struct.ChartSeries.html#method.set_labels_num_format).
+    pub fn set_labels_num_format_linked(&mut self) {
+        // libxlsxwriter writes no number format for the labels without one, which Excel links to the source.
+        // The previous format is owned by libxlsxwriter and is left allocated until the workbook is freed.
+        unsafe {
+            (*self.chart_series).label_num_format = std::ptr::null_mut();
+        }
+    }
+
+    /// This function is used to set the font properties of the data labels of a chart series:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_labels_font-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Column);
+    /// # let mut series1 = chart.add_series(None, Some("=Sheet1!$A$2:$A$6"))?;
+    /// let font = ChartFont {
+    ///     bold: true,
+    ///     color: Some(FormatColor::White),
+    ///     ..ChartFont::default()
+    /// };
+    /// series1.set_labels();
+    /// series1.set_labels_position(ChartLabelPosition::InsideEnd);
+    /// series1.set_labels_font(&font)?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 1..6 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The data labels must be turned on with `ChartSeries.set_labels()` or `ChartSeries.set_labels_options()` before this function is called, otherwise an error is returned.
+    pub fn set_labels_font(&mut self, font: &ChartFont) -> Result<(), XlsxError> {
+        if !self.labels_enabled {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_labels_font(
+                self.chart_series,
+                &mut font.to_c_struct()?.chart_font,
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the X error bars of the series.
+    ///
+    /// X error bars are only displayed for Scatter and Bar charts.
+    pub fn x_error_bars(&mut self) -> ChartErrorBars<'a> {
+        self.error_bars(libxlsxwriter_sys::lxw_chart_error_bar_axis_LXW_CHART_ERROR_BAR_AXIS_X)
+    }
+
+    /// Returns the Y error bars of the series.
+    ///
+    /// Y error bars are displayed for all chart types except Bar charts, which use X error bars instead.
+    pub fn y_error_bars(&mut self) -> ChartErrorBars<'a> {
+        self.error_bars(libxlsxwriter_sys::lxw_chart_error_bar_axis_LXW_CHART_ERROR_BAR_AXIS_Y)
+    }
+
+    /// Set the type and the value of the X error bars of the series:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_error_bars-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Scatter);
+    /// let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    /// series.set_x_error_bars(ChartErrorBarType::Fixed, 0.5);
+    /// series.set_y_error_bars(ChartErrorBarType::Fixed, 2.0);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, i.into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i*10).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use `ChartSeries.x_error_bars()` to set the direction, end cap and line of the error bars.
+    pub fn set_x_error_bars(&mut self, error_type: ChartErrorBarType, value: f64) {
+        self.x_error_bars().set_type(error_type, value);
+    }
+
+    /// Set the type and the value of the Y error bars of the series. See `ChartSeries.set_x_error_bars()` for an example.
+    pub fn set_y_error_bars(&mut self, error_type: ChartErrorBarType, value: f64) {
+        self.y_error_bars().set_type(error_type, value);
+    }
+
+    /// Add a trendline of the given type to the series. The value is the order of a polynomial trendline or the period of a moving average trendline, and is ignored for the other types.
+    ///
+    /// Use [ChartSeries.set_trendline_struct()](struct.ChartSeries.html#method.set_trendline_struct) to also set the name, the line and the displayed equation of the trendline.
+    pub fn set_trendline(&mut self, trendline_type: ChartTrendlineType, value: u8) {
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_trendline(
+                self.chart_series,
+                trendline_type.value(),
+                value,
+            );
+        }
+    }
+
+    /// Add a trendline to the series with its name, line and display options:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_trendline_struct-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # write_worksheet(&mut worksheet)?; // write worksheet contents
+    /// # let mut chart = workbook.add_chart(ChartType::Scatter);
+    /// let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$6"), Some("=Sheet1!$B$1:$B$6"))?;
+    /// let mut trendline = ChartTrendline::new(ChartTrendlineType::Linear);
+    /// trendline.display_equation = true;
+    /// trendline.line = Some(ChartLine {
+    ///     color: FormatColor::Red,
+    ///     dash_type: ChartDashType::Dash,
+    ///     ..ChartLine::new()
+    /// });
+    /// series.set_trendline_struct(&trendline)?;
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// # fn write_worksheet(worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    /// # for i in 0..6 {
+    /// #     worksheet.write_number(i, 0, (i + 1).into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i * 3 + i % 2).into(), None)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// libxlsxwriter ignores the name, line and display options set before the trendline type, so this function sets the type first and then the other properties.
+    /// The equation and the R-squared value aren't available for moving average trendlines and are ignored for them.
+    /// Calling this function again replaces the type of the trendline but keeps the options which were set before.
+    pub fn set_trendline_struct(&mut self, trendline: &ChartTrendline) -> Result<(), XlsxError> {
+        let name_vec = trendline.name.as_deref().map(convert_str).transpose()?;
+        self.set_trendline(trendline.trendline_type, trendline.value);
+        unsafe {
+            if let Some(name) = name_vec.as_ref() {
+                libxlsxwriter_sys::chart_series_set_trendline_name(
+                    self.chart_series,
+                    name.as_ptr() as *const c_char,
+                );
+            }
+            if let Some(line) = trendline.line.as_ref() {
+                libxlsxwriter_sys::chart_series_set_trendline_line(
+                    self.chart_series,
+                    &mut line.value(),
+                );
+            }
+            if trendline.display_equation {
+                libxlsxwriter_sys::chart_series_set_trendline_equation(self.chart_series);
+            }
+            if trendline.display_r_squared {
+                libxlsxwriter_sys::chart_series_set_trendline_r_squared(self.chart_series);
+            }
+        }
+        if let Some(name) = name_vec {
+            self._workbook.const_str.borrow_mut().push(name);
+        }
+        Ok(())
+    }
+
+    fn error_bars(
+        &mut self,
+        axis_type: libxlsxwriter_sys::lxw_chart_error_bar_axis,
+    ) -> ChartErrorBars<'a> {
+        unsafe {
+            ChartErrorBars {
+                _workbook: self._workbook,
+                error_bars: libxlsxwriter_sys::chart_series_get_error_bars(
+                    self.chart_series,
+                    axis_type,
+                ),
+            }
+        }
+    }
+}
+
+fn option_to_mut_ptr<T>(value: &mut Option<T>) -> *mut T {
+    value
+        .as_mut()
+        .map(|x| x as *mut T)
+        .unwrap_or(std::ptr::null_mut())
 }
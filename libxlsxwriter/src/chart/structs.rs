@@ -1,5 +1,6 @@
-use super::super::{convert_bool, FormatColor};
+use super::super::{convert_bool, convert_str, FormatColor, XlsxError};
 use super::constants::*;
+use std::os::raw::c_char;
 
 /// Struct to represent a chart pattern.
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
@@ -107,3 +108,178 @@ impl Default for ChartFill {
         }
     }
 }
+
+/// Struct to represent a chart font.
+///
+/// Fields which are `None` or zero use the Excel default values: a size of `0.0` isn't written to the chart, so the text keeps the default size
+/// of the chart element instead of being invisible, and a color of `None` keeps the automatic color. Only the properties which are set are changed:
+/// ```rust
+/// # use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-chart_font-new-1.xlsx");
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// # worksheet.write_number(0, 0, 10.0, None)?;
+/// let mut chart = workbook.add_chart(ChartType::Column);
+/// chart.add_series(None, Some("=Sheet1!$A$1:$A$1"))?;
+/// let font = ChartFont::new().set_color(FormatColor::Red).set_bold();
+/// assert_eq!(font.size, 0.0);
+/// chart.set_title_name("Sales")?;
+/// chart.set_title_name_font(&font)?;
+/// # worksheet.insert_chart(1, 3, &chart)?;
+/// # workbook.close()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Default)]
+pub struct ChartFont {
+    /// The chart font name, such as "Arial" or "Calibri".
+    pub name: Option<String>,
+    /// The chart font size in points. `0.0` means the default size.
+    pub size: f64,
+    /// The chart font bold property.
+    pub bold: bool,
+    /// The chart font italic property.
+    pub italic: bool,
+    /// The chart font underline property.
+    pub underline: bool,
+    /// The chart font rotation property. Range: -90 to 90, and 270, 271 and 360.
+    pub rotation: i32,
+    /// The chart font color. `None` means the automatic color.
+    pub color: Option<FormatColor>,
+    /// The chart font pitch family property. Rarely required, set to 0.
+    pub pitch_family: u8,
+    /// The chart font character set property. Rarely required, set to 0.
+    pub charset: u8,
+    /// The chart font baseline property. Rarely required, set to 0.
+    pub baseline: i8,
+}
+
+impl ChartFont {
+    /// Create a font with the default values of the chart element it is applied to, see [ChartFont](struct.ChartFont.html).
+    pub fn new() -> Self {
+        ChartFont::default()
+    }
+
+    /// Set the font name, such as "Arial" or "Calibri".
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the font size in points.
+    pub fn set_size(mut self, size: f64) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the bold property of the font.
+    pub fn set_bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Set the italic property of the font.
+    pub fn set_italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Set the underline property of the font.
+    pub fn set_underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Set the rotation of the font in degrees. Range: -90 to 90, and 270, 271 and 360.
+    pub fn set_rotation(mut self, rotation: i32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Set the color of the font.
+    pub fn set_color(mut self, color: FormatColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub(crate) fn to_c_struct(&self) -> Result<CChartFont, XlsxError> {
+        let name = self.name.as_deref().map(convert_str).transpose()?;
+        let chart_font = libxlsxwriter_sys::lxw_chart_font {
+            name: name
+                .as_ref()
+                .map(|x| x.as_ptr() as *mut c_char)
+                .unwrap_or(std::ptr::null_mut()),
+            size: self.size,
+            bold: convert_bool(self.bold),
+            italic: convert_bool(self.italic),
+            underline: convert_bool(self.underline),
+            rotation: self.rotation,
+            color: self.color.map(|x| x.value()).unwrap_or(0),
+            pitch_family: self.pitch_family,
+            charset: self.charset,
+            baseline: self.baseline,
+        };
+        Ok(CChartFont { name, chart_font })
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) struct CChartFont {
+    name: Option<Vec<u8>>,
+
+    pub(crate) chart_font: libxlsxwriter_sys::lxw_chart_font,
+}
+
+/// Struct to represent the formatting and the data label of a point of a chart series, used by
+/// [ChartSeries.set_points()](struct.ChartSeries.html#method.set_points).
+///
+/// Fields which are `None` use the formatting and the data label of the series.
+#[derive(Clone, PartialEq, PartialOrd, Default)]
+pub struct ChartPoint {
+    /// The line/border properties of the point.
+    pub line: Option<ChartLine>,
+    /// The fill properties of the point.
+    pub fill: Option<ChartFill>,
+    /// The pattern properties of the point.
+    pub pattern: Option<ChartPattern>,
+    /// Custom text of the data label of the point, displayed instead of its value.
+    /// A text starting with `=` is a reference to a worksheet cell such as `"=Sheet1!$C$2"`.
+    pub label_text: Option<String>,
+}
+
+impl ChartPoint {
+    pub fn new() -> Self {
+        ChartPoint::default()
+    }
+}
+
+/// Struct to represent the trendline of a chart series, used by
+/// [ChartSeries.set_trendline_struct()](struct.ChartSeries.html#method.set_trendline_struct).
+#[derive(Clone, PartialEq, PartialOrd)]
+pub struct ChartTrendline {
+    /// The trendline type.
+    pub trendline_type: ChartTrendlineType,
+    /// The order of a polynomial trendline or the period of a moving average trendline. Ignored for the other types.
+    pub value: u8,
+    /// The name of the trendline in the legend. Excel generates a name such as "Linear (Series1)" when it is `None`.
+    pub name: Option<String>,
+    /// The line properties of the trendline. The Excel default line is used when it is `None`.
+    pub line: Option<ChartLine>,
+    /// Display the equation of the trendline on the chart.
+    pub display_equation: bool,
+    /// Display the R-squared value of the trendline on the chart.
+    pub display_r_squared: bool,
+}
+
+impl ChartTrendline {
+    pub fn new(trendline_type: ChartTrendlineType) -> Self {
+        ChartTrendline {
+            trendline_type,
+            value: 2,
+            name: None,
+            line: None,
+            display_equation: false,
+            display_r_squared: false,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use super::{Chart, Workbook, XlsxError};
+
+/// The Chartsheet object represents an Excel chartsheet, a sheet which only contains a chart.
+///
+/// A Chartsheet object isn't created directly. Instead a chartsheet is created by calling the
+/// [Workbook.add_chartsheet()](struct.Workbook.html#method.add_chartsheet) function from a Workbook object:
+/// ```rust
+/// use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// let workbook = Workbook::new("test-chartsheet.xlsx");
+/// let mut worksheet = workbook.add_worksheet(None)?;
+/// for i in 0..5 {
+///     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+/// }
+/// let mut chart = workbook.add_chart(ChartType::Column);
+/// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+/// let mut chartsheet = workbook.add_chartsheet(Some("Chart"))?;
+/// chartsheet.set_chart(&chart)?;
+/// workbook.close()?;
+/// Ok(())
+/// # }
+/// ```
+pub struct Chartsheet<'a> {
+    pub(crate) _workbook: &'a Workbook,
+    pub(crate) chartsheet: *mut libxlsxwriter_sys::lxw_chartsheet,
+}
+
+impl<'a> Chartsheet<'a> {
+    /// Set the chart displayed in the chartsheet. A chart can only be displayed in one chartsheet or worksheet.
+    pub fn set_chart(&mut self, chart: &Chart) -> Result<(), XlsxError> {
+        unsafe {
+            let result = libxlsxwriter_sys::chartsheet_set_chart(self.chartsheet, chart.chart);
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// Make the chartsheet the active sheet when the workbook is opened.
+    pub fn activate(&mut self) {
+        unsafe {
+            libxlsxwriter_sys::chartsheet_activate(self.chartsheet);
+        }
+    }
+
+    /// Set the zoom factor of the chartsheet in percent, such as 200 for 200%:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chartsheet-set_zoom-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # let mut chart = workbook.add_chart(ChartType::Column);
+    /// # chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// let mut chartsheet = workbook.add_chartsheet(None)?;
+    /// chartsheet.set_chart(&chart)?;
+    /// chartsheet.set_zoom(200);
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The range is 10 to 400, the default is 100. libxlsxwriter ignores values outside of the range.
+    ///
+    /// The zoom only changes how the chart is displayed on screen, not its printed size.
+    pub fn set_zoom(&mut self, scale: u16) {
+        unsafe {
+            libxlsxwriter_sys::chartsheet_set_zoom(self.chartsheet, scale);
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use super::{convert_bool, convert_str, Format, FormatColor, XlsxError};
+use std::os::raw::c_char;
+
+/// Type of a conditional format.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ConditionalFormatType {
+    /// Format cells whose value matches the criteria.
+    Cell,
+    /// Format cells whose text matches the criteria.
+    Text,
+    /// Format cells for which a formula is true.
+    Formula,
+    /// Color scale with two colors.
+    TwoColorScale,
+    /// Color scale with three colors.
+    ThreeColorScale,
+    /// Data bar.
+    DataBar,
+}
+
+impl ConditionalFormatType {
+    fn value(self) -> u8 {
+        let value = match self {
+            ConditionalFormatType::Cell => {
+                libxlsxwriter_sys::lxw_conditional_format_types_LXW_CONDITIONAL_TYPE_CELL
+            }
+            ConditionalFormatType::Text => {
+                libxlsxwriter_sys::lxw_conditional_format_types_LXW_CONDITIONAL_TYPE_TEXT
+            }
+            ConditionalFormatType::Formula => {
+                libxlsxwriter_sys::lxw_conditional_format_types_LXW_CONDITIONAL_TYPE_FORMULA
+            }
+            ConditionalFormatType::TwoColorScale => {
+                libxlsxwriter_sys::lxw_conditional_format_types_LXW_CONDITIONAL_2_COLOR_SCALE
+            }
+            ConditionalFormatType::ThreeColorScale => {
+                libxlsxwriter_sys::lxw_conditional_format_types_LXW_CONDITIONAL_3_COLOR_SCALE
+            }
+            ConditionalFormatType::DataBar => {
+                libxlsxwriter_sys::lxw_conditional_format_types_LXW_CONDITIONAL_DATA_BAR
+            }
+        };
+        value as u8
+    }
+}
+
+/// Criteria of a cell or text conditional format. The `Text*` criteria are only used by text conditional formats.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ConditionalFormatCriteria {
+    EqualTo,
+    NotEqualTo,
+    GreaterThan,
+    LessThan,
+    GreaterThanOrEqualTo,
+    LessThanOrEqualTo,
+    Between,
+    NotBetween,
+    TextContaining,
+    TextNotContaining,
+    TextBeginsWith,
+    TextEndsWith,
+}
+
+impl ConditionalFormatCriteria {
+    fn value(self) -> u8 {
+        let value = match self {
+            ConditionalFormatCriteria::EqualTo => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_EQUAL_TO,
+            ConditionalFormatCriteria::NotEqualTo => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_NOT_EQUAL_TO,
+            ConditionalFormatCriteria::GreaterThan => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_GREATER_THAN,
+            ConditionalFormatCriteria::LessThan => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_LESS_THAN,
+            ConditionalFormatCriteria::GreaterThanOrEqualTo => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_GREATER_THAN_OR_EQUAL_TO,
+            ConditionalFormatCriteria::LessThanOrEqualTo => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_LESS_THAN_OR_EQUAL_TO,
+            ConditionalFormatCriteria::Between => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_BETWEEN,
+            ConditionalFormatCriteria::NotBetween => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_NOT_BETWEEN,
+            ConditionalFormatCriteria::TextContaining => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_TEXT_CONTAINING,
+            ConditionalFormatCriteria::TextNotContaining => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_TEXT_NOT_CONTAINING,
+            ConditionalFormatCriteria::TextBeginsWith => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_TEXT_BEGINS_WITH,
+            ConditionalFormatCriteria::TextEndsWith => libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_TEXT_ENDS_WITH,
+        };
+        value as u8
+    }
+}
+
+/// Rule type of the minimum, middle and maximum values of color scales and data bars.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ConditionalFormatRuleType {
+    Minimum,
+    Number,
+    Percent,
+    Percentile,
+    Formula,
+    Maximum,
+}
+
+impl ConditionalFormatRuleType {
+    fn value(self) -> u8 {
+        let value = match self {
+            ConditionalFormatRuleType::Minimum => libxlsxwriter_sys::lxw_conditional_format_rule_types_LXW_CONDITIONAL_RULE_TYPE_MINIMUM,
+            ConditionalFormatRuleType::Number => libxlsxwriter_sys::lxw_conditional_format_rule_types_LXW_CONDITIONAL_RULE_TYPE_NUMBER,
+            ConditionalFormatRuleType::Percent => libxlsxwriter_sys::lxw_conditional_format_rule_types_LXW_CONDITIONAL_RULE_TYPE_PERCENT,
+            ConditionalFormatRuleType::Percentile => libxlsxwriter_sys::lxw_conditional_format_rule_types_LXW_CONDITIONAL_RULE_TYPE_PERCENTILE,
+            ConditionalFormatRuleType::Formula => libxlsxwriter_sys::lxw_conditional_format_rule_types_LXW_CONDITIONAL_RULE_TYPE_FORMULA,
+            ConditionalFormatRuleType::Maximum => libxlsxwriter_sys::lxw_conditional_format_rule_types_LXW_CONDITIONAL_RULE_TYPE_MAXIMUM,
+        };
+        value as u8
+    }
+}
+
+/// Conditional format options used by [Worksheet.conditional_format_range()](struct.Worksheet.html#method.conditional_format_range).
+///
+/// ```rust
+/// # use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-conditional_format-1.xlsx");
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// let format = workbook.add_format().set_bg_color(FormatColor::Red);
+/// let conditional_format =
+///     ConditionalFormat::cell(ConditionalFormatCriteria::GreaterThan, 50.0, &format);
+/// worksheet.conditional_format_range(0, 0, 9, 0, &conditional_format)?;
+/// # workbook.close()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The string values are used instead of the number values when they are set. They can contain a cell reference or a formula such as `"$B$1"`.
+/// Colors which are `None` use the Excel default colors.
+#[derive(Clone)]
+pub struct ConditionalFormat<'a> {
+    pub format_type: ConditionalFormatType,
+    pub criteria: Option<ConditionalFormatCriteria>,
+    pub value: f64,
+    pub value_string: Option<String>,
+    pub min_rule_type: Option<ConditionalFormatRuleType>,
+    pub min_value: f64,
+    pub min_value_string: Option<String>,
+    pub min_color: Option<FormatColor>,
+    pub mid_rule_type: Option<ConditionalFormatRuleType>,
+    pub mid_value: f64,
+    pub mid_value_string: Option<String>,
+    pub mid_color: Option<FormatColor>,
+    pub max_rule_type: Option<ConditionalFormatRuleType>,
+    pub max_value: f64,
+    pub max_value_string: Option<String>,
+    pub max_color: Option<FormatColor>,
+    pub bar_color: Option<FormatColor>,
+    pub bar_only: bool,
+    pub stop_if_true: bool,
+    pub format: Option<&'a Format<'a>>,
+}
+
+impl<'a> ConditionalFormat<'a> {
+    pub fn new(format_type: ConditionalFormatType) -> ConditionalFormat<'a> {
+        ConditionalFormat {
+            format_type,
+            criteria: None,
+            value: 0.,
+            value_string: None,
+            min_rule_type: None,
+            min_value: 0.,
+            min_value_string: None,
+            min_color: None,
+            mid_rule_type: None,
+            mid_value: 0.,
+            mid_value_string: None,
+            mid_color: None,
+            max_rule_type: None,
+            max_value: 0.,
+            max_value_string: None,
+            max_color: None,
+            bar_color: None,
+            bar_only: false,
+            stop_if_true: false,
+            format: None,
+        }
+    }
+
+    /// Create a cell conditional format which applies `format` to cells matching `criteria` and `value`.
+    ///
+    /// For the `Between` and `NotBetween` criteria use [ConditionalFormat.cell_between()](struct.ConditionalFormat.html#method.cell_between).
+    pub fn cell(
+        criteria: ConditionalFormatCriteria,
+        value: f64,
+        format: &'a Format<'a>,
+    ) -> ConditionalFormat<'a> {
+        ConditionalFormat {
+            criteria: Some(criteria),
+            value,
+            format: Some(format),
+            ..ConditionalFormat::new(ConditionalFormatType::Cell)
+        }
+    }
+
+    /// Create a cell conditional format which applies `format` to cells between `min_value` and `max_value`.
+    pub fn cell_between(
+        min_value: f64,
+        max_value: f64,
+        format: &'a Format<'a>,
+    ) -> ConditionalFormat<'a> {
+        ConditionalFormat {
+            criteria: Some(ConditionalFormatCriteria::Between),
+            min_value,
+            max_value,
+            format: Some(format),
+            ..ConditionalFormat::new(ConditionalFormatType::Cell)
+        }
+    }
+
+    /// Create a text conditional format which applies `format` to cells whose text matches `criteria` and `text`:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-conditional_format-text-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let format = workbook.add_format().set_font_color(FormatColor::Red);
+    /// let conditional_format =
+    ///     ConditionalFormat::text(ConditionalFormatCriteria::TextContaining, "ERROR", &format);
+    /// worksheet.conditional_format_range(0, 0, 9, 0, &conditional_format)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// `criteria` must be one of the `Text*` criteria. The text is stored in `value_string` and copied when the conditional format is added
+    /// to a worksheet, so the conditional format can be dropped or reused afterwards. The match isn't case sensitive, like in Excel.
+    pub fn text(
+        criteria: ConditionalFormatCriteria,
+        text: &str,
+        format: &'a Format<'a>,
+    ) -> ConditionalFormat<'a> {
+        ConditionalFormat {
+            criteria: Some(criteria),
+            value_string: Some(text.to_string()),
+            format: Some(format),
+            ..ConditionalFormat::new(ConditionalFormatType::Text)
+        }
+    }
+
+    /// Create a formula conditional format which applies `format` to the cells for which `formula` is true,
+    /// for example to highlight the rows whose status is "Overdue":
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-conditional_format-formula-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_row(0, 0, &["Invoice", "Customer", "Amount", "Status"], None)?;
+    /// worksheet.write_row(1, 0, &["1001", "Acme", "250", "Paid"], None)?;
+    /// worksheet.write_row(2, 0, &["1002", "Initech", "120", "Overdue"], None)?;
+    /// let overdue = workbook.add_format().set_bg_color(FormatColor::Custom(0xFFC7CE));
+    /// let conditional_format = ConditionalFormat::formula(r#"=$D2="Overdue""#, &overdue);
+    /// worksheet.conditional_format_range(1, 0, 2, 3, &conditional_format)?; // A2:D3
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The formula is written for the top-left cell of the range and Excel evaluates it for every cell of the range by moving the relative references,
+    /// like when a formula is copied. In the example `$D2` is evaluated as `$D2` for the cells of row 2 and as `$D3` for the cells of row 3:
+    /// the `$` anchors the column so that every cell of a row checks the status column, and the row is relative so that each row is evaluated independently.
+    /// A reference without `$` such as `D2` would check column E for the cells of column B, and an absolute reference such as `$D$2` would apply
+    /// the result of the first row to every row. The formula is stored in `value_string`.
+    pub fn formula(formula: &str, format: &'a Format<'a>) -> ConditionalFormat<'a> {
+        ConditionalFormat {
+            value_string: Some(formula.to_string()),
+            format: Some(format),
+            ..ConditionalFormat::new(ConditionalFormatType::Formula)
+        }
+    }
+
+    /// Create a two color scale with the Excel default colors.
+    pub fn two_color_scale() -> ConditionalFormat<'a> {
+        ConditionalFormat::new(ConditionalFormatType::TwoColorScale)
+    }
+
+    /// Create a three color scale with the Excel default colors.
+    pub fn three_color_scale() -> ConditionalFormat<'a> {
+        ConditionalFormat::new(ConditionalFormatType::ThreeColorScale)
+    }
+
+    /// Create a data bar with the Excel default color.
+    pub fn data_bar() -> ConditionalFormat<'a> {
+        ConditionalFormat::new(ConditionalFormatType::DataBar)
+    }
+
+    pub(crate) fn to_c_struct(&self) -> Result<CConditionalFormat, XlsxError> {
+        let mut value_string = option_str_to_cstr_bytes(&self.value_string)?;
+        let mut min_value_string = option_str_to_cstr_bytes(&self.min_value_string)?;
+        let mut mid_value_string = option_str_to_cstr_bytes(&self.mid_value_string)?;
+        let mut max_value_string = option_str_to_cstr_bytes(&self.max_value_string)?;
+
+        let mut conditional_format: libxlsxwriter_sys::lxw_conditional_format =
+            unsafe { std::mem::zeroed() };
+        conditional_format.type_ = self.format_type.value();
+        conditional_format.criteria = self.criteria.map(|x| x.value()).unwrap_or(0);
+        conditional_format.value = self.value;
+        conditional_format.value_string = cstr_bytes_to_ptr(&mut value_string);
+        conditional_format.min_rule_type = self.min_rule_type.map(|x| x.value()).unwrap_or(0);
+        conditional_format.min_value = self.min_value;
+        conditional_format.min_value_string = cstr_bytes_to_ptr(&mut min_value_string);
+        conditional_format.min_color = self.min_color.map(|x| x.value()).unwrap_or(0);
+        conditional_format.mid_rule_type = self.mid_rule_type.map(|x| x.value()).unwrap_or(0);
+        conditional_format.mid_value = self.mid_value;
+        conditional_format.mid_value_string = cstr_bytes_to_ptr(&mut mid_value_string);
+        conditional_format.mid_color = self.mid_color.map(|x| x.value()).unwrap_or(0);
+        conditional_format.max_rule_type = self.max_rule_type.map(|x| x.value()).unwrap_or(0);
+        conditional_format.max_value = self.max_value;
+        conditional_format.max_value_string = cstr_bytes_to_ptr(&mut max_value_string);
+        conditional_format.max_color = self.max_color.map(|x| x.value()).unwrap_or(0);
+        conditional_format.bar_color = self.bar_color.map(|x| x.value()).unwrap_or(0);
+        conditional_format.bar_only = convert_bool(self.bar_only);
+        conditional_format.stop_if_true = convert_bool(self.stop_if_true);
+        conditional_format.format = self
+            .format
+            .map(|x| x.format)
+            .unwrap_or(std::ptr::null_mut());
+
+        Ok(CConditionalFormat {
+            value_string,
+            min_value_string,
+            mid_value_string,
+            max_value_string,
+            conditional_format,
+        })
+    }
+}
+
+fn option_str_to_cstr_bytes(s: &Option<String>) -> Result<Option<Vec<u8>>, XlsxError> {
+    s.as_deref().map(convert_str).transpose()
+}
+
+fn cstr_bytes_to_ptr(s: &mut Option<Vec<u8>>) -> *mut c_char {
+    s.as_mut()
+        .map(|x| x.as_mut_ptr())
+        .unwrap_or(std::ptr::null_mut()) as *mut c_char
+}
+
+#[allow(dead_code)]
+pub(crate) struct CConditionalFormat {
+    value_string: Option<Vec<u8>>,
+    min_value_string: Option<Vec<u8>>,
+    mid_value_string: Option<Vec<u8>>,
+    max_value_string: Option<Vec<u8>>,
+
+    pub(crate) conditional_format: libxlsxwriter_sys::lxw_conditional_format,
+}
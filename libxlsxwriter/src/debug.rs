@@ -0,0 +1,48 @@
+//! Helpers to inspect the written xlsx files, enabled by the `debug` feature.
+
+use super::{error, XlsxError};
+use std::io::{Cursor, Read};
+
+/// Returns the XML of the part `name` of an xlsx file, for example to check the chart XML written by libxlsxwriter:
+/// ```rust
+/// # use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// let workbook = Workbook::new("test-xlsx_part_xml-1.xlsx");
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// # worksheet.write_number(0, 0, 10.0, None)?;
+/// # let mut chart = workbook.add_chart(ChartType::Bar);
+/// # chart.add_series(None, Some("=Sheet1!$A$1:$A$1"))?;
+/// # worksheet.insert_chart(1, 3, &chart)?;
+/// workbook.close()?;
+/// let xlsx = std::fs::read("test-xlsx_part_xml-1.xlsx").unwrap();
+/// let chart_xml = xlsx_part_xml(&xlsx, "chart1.xml")?;
+/// assert!(chart_xml.contains("<c:barChart>"));
+/// # Ok(())
+/// # }
+/// ```
+/// `name` is either the full path of the part in the archive, such as `"xl/charts/chart1.xml"`, or the file name of the part,
+/// such as `"chart1.xml"`, which returns the first part with this file name.
+///
+/// An error is returned if `xlsx` isn't a valid xlsx file, if the part doesn't exist or if it isn't UTF-8 text.
+pub fn xlsx_part_xml(xlsx: &[u8], name: &str) -> Result<String, XlsxError> {
+    let invalid = |_| XlsxError::new(error::UNKNOWN_ERROR_CODE);
+    let mut archive = zip::ZipArchive::new(Cursor::new(xlsx)).map_err(invalid)?;
+    let suffix = format!("/{}", name);
+    let index = (0..archive.len())
+        .find(|i| {
+            archive
+                .by_index(*i)
+                .map(|entry| entry.name() == name || entry.name().ends_with(&suffix))
+                .unwrap_or(false)
+        })
+        .ok_or_else(|| {
+            XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION)
+        })?;
+    let mut xml = String::new();
+    archive
+        .by_index(index)
+        .map_err(invalid)?
+        .read_to_string(&mut xml)
+        .map_err(|_| XlsxError::new(error::UNKNOWN_ERROR_CODE))?;
+    Ok(xml)
+}
@@ -3,8 +3,14 @@ use std::ffi;
 use std::fmt::{self, Display};
 
 pub const UNKNOWN_ERROR_CODE: libxlsxwriter_sys::lxw_error = 1000;
+pub const INVALID_FORMULA_ERROR_CODE: libxlsxwriter_sys::lxw_error = 1001;
+pub const FILE_EXISTS_ERROR_CODE: libxlsxwriter_sys::lxw_error = 1002;
+pub const NON_UTF8_PATH_ERROR_CODE: libxlsxwriter_sys::lxw_error = 1003;
+pub const RICH_STRING_TOO_LONG_ERROR_CODE: libxlsxwriter_sys::lxw_error = 1004;
+pub const TOO_MANY_FRAGMENTS_ERROR_CODE: libxlsxwriter_sys::lxw_error = 1005;
+pub const ROW_OR_COLUMN_OUT_OF_BOUNDS_ERROR_CODE: libxlsxwriter_sys::lxw_error = 1006;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct XlsxError {
     error: libxlsxwriter_sys::lxw_error,
 }
@@ -15,6 +21,48 @@ impl XlsxError {
     pub fn new(error: libxlsxwriter_sys::lxw_error) -> XlsxError {
         XlsxError { error }
     }
+
+    /// Returns `true` for the error returned when an obviously broken formula is written with the `validate_formulas` option
+    /// of [WorkbookOptions](struct.WorkbookOptions.html).
+    pub fn is_invalid_formula(&self) -> bool {
+        self.error == INVALID_FORMULA_ERROR_CODE
+    }
+
+    /// Returns `true` for the error returned by [Workbook.new_no_overwrite()](struct.Workbook.html#method.new_no_overwrite) when the file already exists.
+    pub fn is_file_exists(&self) -> bool {
+        self.error == FILE_EXISTS_ERROR_CODE
+    }
+
+    /// Returns `true` for the error returned for a workbook created with [Workbook.new()](struct.Workbook.html#method.new)
+    /// with a file name which isn't valid UTF-8.
+    pub fn is_non_utf8_path(&self) -> bool {
+        self.error == NON_UTF8_PATH_ERROR_CODE
+    }
+
+    /// Returns `true` for the error returned by [Worksheet.write_rich_string()](struct.Worksheet.html#method.write_rich_string)
+    /// for a rich string longer than the limit of a string cell.
+    pub fn is_rich_string_too_long(&self) -> bool {
+        self.error == RICH_STRING_TOO_LONG_ERROR_CODE
+    }
+
+    /// Returns `true` for the error returned by [Worksheet.write_rich_string()](struct.Worksheet.html#method.write_rich_string)
+    /// for a rich string with more fragments than libxlsxwriter supports.
+    pub fn is_too_many_fragments(&self) -> bool {
+        self.error == TOO_MANY_FRAGMENTS_ERROR_CODE
+    }
+
+    /// Returns `true` for the error returned by the `write_*` functions of [Worksheet](struct.Worksheet.html) for a cell
+    /// after the last row or the last column of a worksheet.
+    pub fn is_row_or_column_out_of_bounds(&self) -> bool {
+        self.error == ROW_OR_COLUMN_OUT_OF_BOUNDS_ERROR_CODE
+    }
+}
+
+/// Strings passed to libxlsxwriter can't contain a NUL byte.
+impl From<ffi::NulError> for XlsxError {
+    fn from(_: ffi::NulError) -> XlsxError {
+        XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION)
+    }
 }
 
 impl Display for XlsxError {
@@ -22,6 +70,24 @@ impl Display for XlsxError {
         if self.error == UNKNOWN_ERROR_CODE {
             return write!(f, "Unknown Errror");
         }
+        if self.error == INVALID_FORMULA_ERROR_CODE {
+            return write!(f, "Invalid formula");
+        }
+        if self.error == FILE_EXISTS_ERROR_CODE {
+            return write!(f, "File already exists");
+        }
+        if self.error == NON_UTF8_PATH_ERROR_CODE {
+            return write!(f, "File name is not valid UTF-8");
+        }
+        if self.error == RICH_STRING_TOO_LONG_ERROR_CODE {
+            return write!(f, "Rich string is too long");
+        }
+        if self.error == TOO_MANY_FRAGMENTS_ERROR_CODE {
+            return write!(f, "Rich string has too many fragments");
+        }
+        if self.error == ROW_OR_COLUMN_OUT_OF_BOUNDS_ERROR_CODE {
+            return write!(f, "Row or column is out of the bounds of the worksheet");
+        }
         unsafe {
             match ffi::CStr::from_ptr(libxlsxwriter_sys::lxw_strerror(self.error)).to_str() {
                 Ok(error_text) => write!(f, "{}", error_text),
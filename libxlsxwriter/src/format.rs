@@ -1,4 +1,4 @@
-use super::Workbook;
+use super::{Workbook, XlsxError};
 use std::ffi::CString;
 
 #[allow(clippy::unreadable_literal)]
@@ -21,6 +21,11 @@ pub enum FormatColor {
     White,
     Yellow,
     Custom(u32),
+    /// The Excel "Automatic" color. No color is written, so the element uses the default color of Excel, which is the
+    /// window text color (black by default) for fonts. It doesn't change with the background color of the cell.
+    ///
+    /// It can be used to reset a color set earlier, for example in a format created with [Format.merge()](struct.Format.html#method.merge).
+    Automatic,
 }
 
 #[allow(clippy::unreadable_literal)]
@@ -44,6 +49,8 @@ impl FormatColor {
             FormatColor::White => 0xFFFFFF,
             FormatColor::Yellow => 0xFFFF00,
             FormatColor::Custom(x) => x,
+            // LXW_COLOR_UNSET
+            FormatColor::Automatic => 0,
         }
     }
 }
@@ -272,6 +279,126 @@ impl FormatBorder {
     }
 }
 
+/// Direction of the diagonal border of a cell, used by [Format.set_diagonal()](struct.Format.html#method.set_diagonal).
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FormatDiagonalType {
+    /// Cell diagonal border from bottom left to top right.
+    Up,
+    /// Cell diagonal border from top left to bottom right.
+    Down,
+    /// Cell diagonal border in both directions.
+    UpDown,
+}
+
+impl FormatDiagonalType {
+    pub fn value(self) -> u8 {
+        let value = match self {
+            FormatDiagonalType::Up => {
+                libxlsxwriter_sys::lxw_format_diagonal_types_LXW_DIAGONAL_BORDER_UP
+            }
+            FormatDiagonalType::Down => {
+                libxlsxwriter_sys::lxw_format_diagonal_types_LXW_DIAGONAL_BORDER_DOWN
+            }
+            FormatDiagonalType::UpDown => {
+                libxlsxwriter_sys::lxw_format_diagonal_types_LXW_DIAGONAL_BORDER_UP_DOWN
+            }
+        };
+        value as u8
+    }
+}
+
+/// Built-in number formats of Excel, used by [Format.set_num_format_preset()](struct.Format.html#method.set_num_format_preset).
+///
+/// Excel identifies these formats by an index instead of storing their format string in the file. The value of each variant is its index:
+///
+/// | Index | Variant | Format string |
+/// |-------|---------|---------------|
+/// | 0 | `General` | `General` |
+/// | 1 | `Integer` | `0` |
+/// | 2 | `TwoDecimals` | `0.00` |
+/// | 3 | `ThousandsInteger` | `#,##0` |
+/// | 4 | `ThousandsTwoDecimals` | `#,##0.00` |
+/// | 5 | `Currency` | `($#,##0_);($#,##0)` |
+/// | 6 | `CurrencyNegativeRed` | `($#,##0_);[Red]($#,##0)` |
+/// | 7 | `CurrencyTwoDecimals` | `($#,##0.00_);($#,##0.00)` |
+/// | 8 | `CurrencyTwoDecimalsNegativeRed` | `($#,##0.00_);[Red]($#,##0.00)` |
+/// | 9 | `Percent` | `0%` |
+/// | 10 | `PercentTwoDecimals` | `0.00%` |
+/// | 11 | `Scientific` | `0.00E+00` |
+/// | 12 | `Fraction` | `# ?/?` |
+/// | 13 | `FractionTwoDigits` | `# ??/??` |
+/// | 14 | `Date` | `m/d/yy` |
+/// | 15 | `DayMonthYear` | `d-mmm-yy` |
+/// | 16 | `DayMonth` | `d-mmm` |
+/// | 17 | `MonthYear` | `mmm-yy` |
+/// | 18 | `Time12Hour` | `h:mm AM/PM` |
+/// | 19 | `Time12HourSeconds` | `h:mm:ss AM/PM` |
+/// | 20 | `Time` | `h:mm` |
+/// | 21 | `TimeSeconds` | `h:mm:ss` |
+/// | 22 | `DateTime` | `m/d/yy h:mm` |
+/// | 37 | `ThousandsNegativeParentheses` | `#,##0 ;(#,##0)` |
+/// | 38 | `ThousandsNegativeParenthesesRed` | `#,##0 ;[Red](#,##0)` |
+/// | 39 | `ThousandsTwoDecimalsNegativeParentheses` | `#,##0.00;(#,##0.00)` |
+/// | 40 | `ThousandsTwoDecimalsNegativeParenthesesRed` | `#,##0.00;[Red](#,##0.00)` |
+/// | 41 | `Accounting` | `_(* #,##0_);_(* (#,##0);_(* "-"_);_(@_)` |
+/// | 42 | `AccountingCurrency` | `_($* #,##0_);_($* (#,##0);_($* "-"_);_(@_)` |
+/// | 43 | `AccountingTwoDecimals` | `_(* #,##0.00_);_(* (#,##0.00);_(* "-"??_);_(@_)` |
+/// | 44 | `AccountingCurrencyTwoDecimals` | `_($* #,##0.00_);_($* (#,##0.00);_($* "-"??_);_(@_)` |
+/// | 45 | `MinutesSeconds` | `mm:ss` |
+/// | 46 | `ElapsedHours` | `[h]:mm:ss` |
+/// | 47 | `MinutesSecondsTenths` | `mm:ss.0` |
+/// | 48 | `Engineering` | `##0.0E+0` |
+/// | 49 | `Text` | `@` |
+///
+/// Excel displays some of these formats according to the locale of the user: for example the currency symbol of the formats 5 to 8 and 42 and 44,
+/// and the order of the date formats 14 and 22. The indexes 23 to 36 are reserved for the formats of East Asian locales and aren't included.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NumFormat {
+    General = 0,
+    Integer = 1,
+    TwoDecimals = 2,
+    ThousandsInteger = 3,
+    ThousandsTwoDecimals = 4,
+    Currency = 5,
+    CurrencyNegativeRed = 6,
+    CurrencyTwoDecimals = 7,
+    CurrencyTwoDecimalsNegativeRed = 8,
+    Percent = 9,
+    PercentTwoDecimals = 10,
+    Scientific = 11,
+    Fraction = 12,
+    FractionTwoDigits = 13,
+    Date = 14,
+    DayMonthYear = 15,
+    DayMonth = 16,
+    MonthYear = 17,
+    Time12Hour = 18,
+    Time12HourSeconds = 19,
+    Time = 20,
+    TimeSeconds = 21,
+    DateTime = 22,
+    ThousandsNegativeParentheses = 37,
+    ThousandsNegativeParenthesesRed = 38,
+    ThousandsTwoDecimalsNegativeParentheses = 39,
+    ThousandsTwoDecimalsNegativeParenthesesRed = 40,
+    Accounting = 41,
+    AccountingCurrency = 42,
+    AccountingTwoDecimals = 43,
+    AccountingCurrencyTwoDecimals = 44,
+    MinutesSeconds = 45,
+    ElapsedHours = 46,
+    MinutesSecondsTenths = 47,
+    Engineering = 48,
+    Text = 49,
+}
+
+impl NumFormat {
+    /// Returns the index of the built-in format.
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+}
+
 /// This Format object has the functions and properties that are available for formatting cells in Excel.
 ///
 /// The properties of a cell that can be formatted include: fonts, colors, patterns, borders, alignment and number formatting.
@@ -281,23 +408,43 @@ pub struct Format<'a> {
 }
 
 impl<'a> Format<'a> {
+    /// This function sets the font of the cell. The font name is ignored if it contains a NUL byte.
     pub fn set_font_name(self, font_name: &str) -> Self {
-        unsafe {
-            libxlsxwriter_sys::format_set_font_name(
-                self.format,
-                CString::new(font_name).unwrap().as_c_str().as_ptr(),
-            );
+        if let Ok(font_name) = CString::new(font_name) {
+            unsafe {
+                libxlsxwriter_sys::format_set_font_name(self.format, font_name.as_c_str().as_ptr());
+            }
         }
         self
     }
 
-    pub fn set_font_size(self, font_size: f64) -> Self {
+    /// This function sets the font size of the cell in points:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-set_font_size-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let large = workbook.add_format().set_font_size(20.0)?.set_bold();
+    /// worksheet.write_string(0, 0, "Title", Some(&large))?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Excel only supports font sizes from 1 to 409 points. The error `LXW_ERROR_PARAMETER_VALIDATION` is returned for the other sizes,
+    /// which otherwise would produce a file repaired by Excel.
+    pub fn set_font_size(self, font_size: f64) -> Result<Self, XlsxError> {
+        if !(1.0..=409.0).contains(&font_size) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
         unsafe {
             libxlsxwriter_sys::format_set_font_size(self.format, font_size);
         }
-        self
+        Ok(self)
     }
 
+    /// Set the font color. `FormatColor::Automatic` resets the font color to the Excel "Automatic" color.
     pub fn set_font_color(self, font_color: FormatColor) -> Self {
         unsafe {
             libxlsxwriter_sys::format_set_font_color(self.format, font_color.value());
@@ -333,6 +480,22 @@ impl<'a> Format<'a> {
         self
     }
 
+    /// This function sets the outline property of the font. It is a legacy Mac font style: modern versions of Excel keep it in the file but don't display it.
+    pub fn set_font_outline(self) -> Self {
+        unsafe {
+            libxlsxwriter_sys::format_set_font_outline(self.format);
+        }
+        self
+    }
+
+    /// This function sets the shadow property of the font. It is a legacy Mac font style: modern versions of Excel keep it in the file but don't display it.
+    pub fn set_font_shadow(self) -> Self {
+        unsafe {
+            libxlsxwriter_sys::format_set_font_shadow(self.format);
+        }
+        self
+    }
+
     pub fn set_font_script(self, script: FormatScript) -> Self {
         unsafe {
             libxlsxwriter_sys::format_set_font_script(self.format, script.value());
@@ -340,16 +503,76 @@ impl<'a> Format<'a> {
         self
     }
 
+    /// This function sets the number format of the cell, such as `"0.00"` or `"d mmm yyyy"`. The number format is ignored if it contains a NUL byte.
     pub fn set_num_format(self, num_font: &str) -> Self {
+        if let Ok(num_font) = CString::new(num_font) {
+            unsafe {
+                libxlsxwriter_sys::format_set_num_format(self.format, num_font.as_c_str().as_ptr());
+            }
+        }
+        self
+    }
+
+    /// This function sets one of the built-in number formats of Excel, which are listed with their index in [NumFormat](enum.NumFormat.html):
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-set_num_format_preset-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let percent = workbook.add_format().set_num_format_preset(NumFormat::Percent);
+    /// worksheet.write_number(0, 0, 0.25, Some(&percent))?; // displayed as 25%
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// A built-in format is stored as its index, and some of them are displayed according to the locale of the user. Use
+    /// [Format.set_num_format()](struct.Format.html#method.set_num_format) for the same format string everywhere.
+    pub fn set_num_format_preset(self, preset: NumFormat) -> Self {
         unsafe {
-            libxlsxwriter_sys::format_set_num_format(
-                self.format,
-                CString::new(num_font).unwrap().as_c_str().as_ptr(),
-            );
+            libxlsxwriter_sys::format_set_num_format_index(self.format, preset.value());
         }
         self
     }
 
+    /// This function sets the cell as unlocked, so that it can be edited when the worksheet is protected with [Worksheet.protect()](struct.Worksheet.html#method.protect):
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-set_unlocked-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let unlocked = workbook.add_format().set_unlocked();
+    /// worksheet.protect(
+    ///     Some("password"),
+    ///     &Protection {
+    ///         no_select_unlocked_cells: false,
+    ///         ..Protection::new()
+    ///     },
+    /// )?;
+    /// worksheet.write_string(0, 0, "Locked", None)?;
+    /// worksheet.write_string(1, 0, "Editable", Some(&unlocked))?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Cells are locked by default, but the lock only has an effect when the worksheet is protected.
+    /// `Protection::new()` also prevents the selection of unlocked cells, so `no_select_unlocked_cells` must be `false` for the unlocked cells to be editable.
+    pub fn set_unlocked(self) -> Self {
+        unsafe {
+            libxlsxwriter_sys::format_set_unlocked(self.format);
+        }
+        self
+    }
+
+    /// This function sets the cell as locked, which is the default.
+    /// It can be used to make explicit that a cell can't be edited when the worksheet is protected.
+    pub fn set_locked(self) -> Self {
+        unsafe {
+            (*self.format).locked = 1;
+        }
+        self
+    }
+
+    /// Same as [Format.set_unlocked()](struct.Format.html#method.set_unlocked).
     pub fn set_font_unlocked(self) -> Self {
         unsafe {
             libxlsxwriter_sys::format_set_unlocked(self.format);
@@ -357,6 +580,28 @@ impl<'a> Format<'a> {
         self
     }
 
+    /// This function sets the cell as hidden, so that the formula of the cell isn't displayed in the formula bar when the worksheet is protected with [Worksheet.protect()](struct.Worksheet.html#method.protect):
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-set_hidden-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let hidden = workbook.add_format().set_hidden();
+    /// worksheet.protect(Some("password"), &Protection::new())?;
+    /// worksheet.write_formula(0, 0, "=1+2", Some(&hidden))?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The result of the formula is still displayed in the cell. The hidden property only has an effect when the worksheet is protected.
+    pub fn set_hidden(self) -> Self {
+        unsafe {
+            libxlsxwriter_sys::format_set_hidden(self.format);
+        }
+        self
+    }
+
+    /// Same as [Format.set_hidden()](struct.Format.html#method.set_hidden).
     pub fn set_font_hidden(self) -> Self {
         unsafe {
             libxlsxwriter_sys::format_set_hidden(self.format);
@@ -455,6 +700,27 @@ impl<'a> Format<'a> {
         self
     }
 
+    /// This function sets the color of the four borders of the cell. The color of one side is set with
+    /// [Format.set_border_top_color()](struct.Format.html#method.set_border_top_color),
+    /// [Format.set_border_bottom_color()](struct.Format.html#method.set_border_bottom_color),
+    /// [Format.set_border_left_color()](struct.Format.html#method.set_border_left_color) and
+    /// [Format.set_border_right_color()](struct.Format.html#method.set_border_right_color), which override this color for their side:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-set_border_color-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let format = workbook
+    ///     .add_format()
+    ///     .set_border(FormatBorder::Thin)
+    ///     .set_border_top_color(FormatColor::Red)
+    ///     .set_border_bottom_color(FormatColor::Blue);
+    /// worksheet.write_string(1, 1, "Total", Some(&format))?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// A border color is only displayed for the sides which have a border style.
     pub fn set_border_color(self, color: FormatColor) -> Self {
         unsafe {
             libxlsxwriter_sys::format_set_border_color(self.format, color.value());
@@ -489,4 +755,124 @@ impl<'a> Format<'a> {
         }
         self
     }
+
+    /// This function sets the diagonal border of a cell:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-set_diagonal-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let diagonal = workbook.add_format().set_diagonal(
+    ///     FormatDiagonalType::UpDown,
+    ///     FormatBorder::Thin,
+    ///     FormatColor::Red,
+    /// );
+    /// worksheet.write_string(1, 1, "Void", Some(&diagonal))?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The diagonal type is one of:
+    /// * `FormatDiagonalType::Up`: from the bottom left to the top right of the cell.
+    /// * `FormatDiagonalType::Down`: from the top left to the bottom right of the cell.
+    /// * `FormatDiagonalType::UpDown`: both diagonals, which draws a cross through the cell.
+    pub fn set_diagonal(
+        self,
+        diag_type: FormatDiagonalType,
+        style: FormatBorder,
+        color: FormatColor,
+    ) -> Self {
+        unsafe {
+            libxlsxwriter_sys::format_set_diag_type(self.format, diag_type.value());
+            libxlsxwriter_sys::format_set_diag_border(self.format, style.value());
+            libxlsxwriter_sys::format_set_diag_color(self.format, color.value());
+        }
+        self
+    }
+
+    /// This function creates a new format combining the properties of this format and `other`:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-merge-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let bold = workbook.add_format().set_bold();
+    /// worksheet.write_string(0, 0, "Total", Some(&bold))?;
+    ///
+    /// let border = workbook.add_format().set_border(FormatBorder::Thin);
+    /// if let Some(format) = worksheet.get_cell_format(0, 0) {
+    ///     let bold_with_border = format.merge(&border);
+    ///     worksheet.write_string(0, 0, "Total", Some(&bold_with_border))?;
+    /// }
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The properties set in `other`, which are the properties that differ from a new format, take precedence over the properties of this format.
+    /// Neither format is modified.
+    pub fn merge(&self, other: &Format) -> Format<'a> {
+        let merged = self._workbook.add_format();
+        macro_rules! merge_fields {
+            ($($field:ident),*) => {
+                unsafe {
+                    $(
+                        (*merged.format).$field = if (*other.format).$field != (*merged.format).$field {
+                            (*other.format).$field
+                        } else {
+                            (*self.format).$field
+                        };
+                    )*
+                }
+            };
+        }
+        merge_fields!(
+            num_format,
+            num_format_index,
+            font_name,
+            font_scheme,
+            font_size,
+            bold,
+            italic,
+            font_color,
+            underline,
+            font_strikeout,
+            font_outline,
+            font_shadow,
+            font_script,
+            font_family,
+            font_charset,
+            font_condense,
+            font_extend,
+            theme,
+            hyperlink,
+            hidden,
+            locked,
+            text_h_align,
+            text_wrap,
+            text_v_align,
+            text_justlast,
+            rotation,
+            fg_color,
+            bg_color,
+            pattern,
+            bottom,
+            diag_border,
+            diag_type,
+            left,
+            right,
+            top,
+            bottom_color,
+            diag_color,
+            left_color,
+            right_color,
+            top_color,
+            indent,
+            shrink,
+            reading_order,
+            just_distrib,
+            color_indexed,
+            font_only
+        );
+        merged
+    }
 }
@@ -0,0 +1,173 @@
+use super::{WorksheetCol, WorksheetRow};
+use std::fmt;
+
+/// A formula string which can be written with [Worksheet.write_formula()](struct.Worksheet.html#method.write_formula).
+///
+/// The constructors build the cell references of the formula, which avoids writing error prone strings such as `=SUM(Sheet1!$A$1:$A$10)` by hand:
+/// ```rust
+/// # use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-formula-1.xlsx");
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// let sum = Formula::sum("Sheet1", 0, 0, 9, 0);
+/// assert_eq!(sum.as_str(), "=SUM(Sheet1!$A$1:$A$10)");
+/// worksheet.write_formula(10, 0, sum.as_str(), None)?;
+/// let average = Formula::average("Sheet1", 0, 0, 9, 0);
+/// worksheet.write_formula(11, 0, average.as_str(), None)?;
+/// # workbook.close()?;
+/// # Ok(())
+/// # }
+/// ```
+/// The supported constructors are:
+/// * `Formula::sum()`: `=SUM(range)`
+/// * `Formula::average()`: `=AVERAGE(range)`
+/// * `Formula::raw()`: any formula, used as is.
+///
+/// Sheet names which contain spaces or other special characters are quoted, e.g. `='Sales Data'!$A$1`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Formula {
+    formula: String,
+}
+
+impl Formula {
+    /// Create a formula from a string, such as `"=A1+B1"`. The string is used as is.
+    pub fn raw(formula: &str) -> Formula {
+        Formula {
+            formula: formula.to_string(),
+        }
+    }
+
+    /// Create a formula which sums the cells of a range of the sheet `sheet`.
+    pub fn sum(
+        sheet: &str,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+    ) -> Formula {
+        Formula::function("SUM", sheet, first_row, first_col, last_row, last_col)
+    }
+
+    /// Create a formula which averages the cells of a range of the sheet `sheet`.
+    pub fn average(
+        sheet: &str,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+    ) -> Formula {
+        Formula::function("AVERAGE", sheet, first_row, first_col, last_row, last_col)
+    }
+
+    /// Returns the formula string.
+    pub fn as_str(&self) -> &str {
+        &self.formula
+    }
+
+    fn function(
+        name: &str,
+        sheet: &str,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+    ) -> Formula {
+        Formula {
+            formula: format!(
+                "={}({})",
+                name,
+                range_ref(sheet, first_row, first_col, last_row, last_col)
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.formula)
+    }
+}
+
+impl From<Formula> for String {
+    fn from(formula: Formula) -> String {
+        formula.formula
+    }
+}
+
+/// Returns the name of a column, e.g. `A` for 0 and `AA` for 26.
+pub(crate) fn col_name(col: WorksheetCol) -> String {
+    let mut col = u32::from(col) + 1;
+    let mut name = Vec::new();
+    while col > 0 {
+        let remainder = (col - 1) % 26;
+        name.push(b'A' + remainder as u8);
+        col = (col - 1) / 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Returns the absolute reference of a cell, e.g. `$A$1` for `(0, 0)`.
+pub(crate) fn cell_ref(row: WorksheetRow, col: WorksheetCol) -> String {
+    format!("${}${}", col_name(col), row + 1)
+}
+
+/// Returns the absolute reference of a range in a sheet, e.g. `Sheet1!$A$1:$B$2`.
+pub(crate) fn range_ref(
+    sheet: &str,
+    first_row: WorksheetRow,
+    first_col: WorksheetCol,
+    last_row: WorksheetRow,
+    last_col: WorksheetCol,
+) -> String {
+    format!(
+        "{}!{}:{}",
+        quote_sheet_name(sheet),
+        cell_ref(first_row, first_col),
+        cell_ref(last_row, last_col)
+    )
+}
+
+fn quote_sheet_name(sheet: &str) -> String {
+    let plain = sheet.chars().all(|x| x.is_ascii_alphanumeric() || x == '_')
+        && !sheet.starts_with(|x: char| x.is_ascii_digit());
+    if plain {
+        sheet.to_string()
+    } else {
+        format!("'{}'", sheet.replace('\'', "''"))
+    }
+}
+
+/// Best-effort check of the syntax of a formula: the parentheses, braces and brackets must be balanced and correctly nested,
+/// string literals and quoted sheet names must be closed, and the formula must not end with a binary operator.
+///
+/// This isn't a parser, so a formula which passes the check can still be rejected by Excel.
+pub(crate) fn is_plausible_formula(formula: &str) -> bool {
+    let mut closing = Vec::new();
+    let mut chars = formula.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' | '\'' => loop {
+                match chars.next() {
+                    // A doubled quote is an escaped quote inside of the literal.
+                    Some(x) if x == c && chars.peek() == Some(&c) => {
+                        chars.next();
+                    }
+                    Some(x) if x == c => break,
+                    Some(_) => {}
+                    None => return false,
+                }
+            },
+            '(' => closing.push(')'),
+            '{' => closing.push('}'),
+            '[' => closing.push(']'),
+            ')' | '}' | ']' if closing.pop() != Some(c) => return false,
+            _ => {}
+        }
+    }
+    let trimmed = formula.trim_end();
+    closing.is_empty()
+        && !trimmed.is_empty()
+        && trimmed != "="
+        && !trimmed.ends_with(|x| "+-*/^&=<>,:".contains(x))
+}
@@ -0,0 +1,159 @@
+use super::{ImageOptions, XlsxError};
+use std::fs::File;
+use std::io::Read;
+
+/// Returns the natural `(width, height)` of an image in pixels.
+///
+/// The dimensions are read from the image file header in the same way libxlsxwriter reads them when the image is inserted with
+/// [Worksheet.insert_image()](struct.Worksheet.html#method.insert_image). PNG, JPEG, GIF and BMP images are supported.
+/// ```rust
+/// # use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// let (width, height) = image_dimensions("../images/simple1.png")?;
+/// assert_eq!((width, height), (425, 165));
+/// # Ok(())
+/// # }
+/// ```
+/// The size of the inserted image is the natural size multiplied by `x_scale` and `y_scale` of [ImageOptions](struct.ImageOptions.html).
+pub fn image_dimensions(filename: &str) -> Result<(u32, u32), XlsxError> {
+    let mut data = Vec::new();
+    File::open(filename)
+        .and_then(|mut file| file.read_to_end(&mut data))
+        .map_err(|_| XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION))?;
+    image_buffer_dimensions(&data)
+}
+
+/// Returns the natural `(width, height)` of an image buffer in pixels.
+///
+/// This is the buffer equivalent of [image_dimensions()](fn.image_dimensions.html) for images inserted with
+/// [Worksheet.insert_image_buffer()](struct.Worksheet.html#method.insert_image_buffer).
+pub fn image_buffer_dimensions(data: &[u8]) -> Result<(u32, u32), XlsxError> {
+    let dimensions = if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        png_dimensions(data)
+    } else if data.starts_with(&[0xff, 0xd8]) {
+        jpeg_dimensions(data)
+    } else if data.starts_with(b"GIF8") {
+        gif_dimensions(data)
+    } else if data.starts_with(b"BM") {
+        bmp_dimensions(data)
+    } else {
+        None
+    };
+    dimensions
+        .ok_or_else(|| XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION))
+}
+
+/// Returns true if the data looks like an SVG image, which libxlsxwriter can't insert.
+pub(crate) fn is_svg(data: &[u8]) -> bool {
+    let data = data.strip_prefix(b"\xef\xbb\xbf").unwrap_or(data);
+    let head = String::from_utf8_lossy(&data[..data.len().min(1024)]);
+    let head = head.trim_start();
+    head.starts_with("<svg") || (head.starts_with("<?xml") && head.contains("<svg"))
+}
+
+/// Returns true if the file looks like an SVG image. Files which can't be read are left to libxlsxwriter.
+pub(crate) fn is_svg_file(filename: &str) -> bool {
+    let mut head = Vec::new();
+    File::open(filename)
+        .and_then(|file| file.take(1024).read_to_end(&mut head))
+        .map(|_| is_svg(&head))
+        .unwrap_or(false)
+}
+
+/// Returns the JPEG data recompressed to the quality of `options`, or `None` to embed the original data.
+///
+/// The original data is kept when no quality is set, when it isn't a JPEG image or when the recompressed image isn't smaller.
+pub(crate) fn recompressed_jpeg(
+    data: &[u8],
+    options: &ImageOptions,
+) -> Result<Option<Vec<u8>>, XlsxError> {
+    let quality = match options.jpeg_quality {
+        Some(quality) => quality,
+        None => return Ok(None),
+    };
+    if !(1..=100).contains(&quality) {
+        return Err(XlsxError::new(
+            libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+        ));
+    }
+    if !data.starts_with(&[0xff, 0xd8]) {
+        return Ok(None);
+    }
+    let recompressed = recompress_jpeg(data, quality)?;
+    Ok(Some(recompressed).filter(|recompressed| recompressed.len() < data.len()))
+}
+
+#[cfg(feature = "image")]
+fn recompress_jpeg(data: &[u8], quality: u8) -> Result<Vec<u8>, XlsxError> {
+    let invalid_image =
+        |_| XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION);
+    let image = ::image::load_from_memory_with_format(data, ::image::ImageFormat::Jpeg)
+        .map_err(invalid_image)?;
+    let mut recompressed = Vec::new();
+    ::image::codecs::jpeg::JpegEncoder::new_with_quality(&mut recompressed, quality)
+        .encode_image(&image.to_rgb8())
+        .map_err(invalid_image)?;
+    Ok(recompressed)
+}
+
+#[cfg(not(feature = "image"))]
+fn recompress_jpeg(_data: &[u8], _quality: u8) -> Result<Vec<u8>, XlsxError> {
+    Err(XlsxError::new(
+        libxlsxwriter_sys::lxw_error_LXW_ERROR_FEATURE_NOT_SUPPORTED,
+    ))
+}
+
+fn read_u16_be(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u32::from(bytes[0]) << 8 | u32::from(bytes[1]))
+}
+
+fn read_u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    Some(read_u16_be(data, offset)? << 16 | read_u16_be(data, offset + 2)?)
+}
+
+fn read_u16_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u32::from(bytes[1]) << 8 | u32::from(bytes[0]))
+}
+
+fn read_u32_le(data: &[u8], offset: usize) -> Option<u32> {
+    Some(read_u16_le(data, offset + 2)? << 16 | read_u16_le(data, offset)?)
+}
+
+fn png_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    // The IHDR chunk is always the first chunk after the 8 byte signature.
+    if data.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    Some((read_u32_be(data, 16)?, read_u32_be(data, 20)?))
+}
+
+fn jpeg_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let mut offset = 2;
+    while offset + 4 <= data.len() {
+        if data[offset] != 0xff {
+            return None;
+        }
+        let marker = data[offset + 1];
+        let length = read_u16_be(data, offset + 2)? as usize;
+        // Start of frame markers, excluding DHT, JPG and DAC.
+        if (0xc0..=0xcf).contains(&marker) && marker != 0xc4 && marker != 0xc8 && marker != 0xcc {
+            let height = read_u16_be(data, offset + 5)?;
+            let width = read_u16_be(data, offset + 7)?;
+            return Some((width, height));
+        }
+        offset += length + 2;
+    }
+    None
+}
+
+fn gif_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    Some((read_u16_le(data, 6)?, read_u16_le(data, 8)?))
+}
+
+fn bmp_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let width = read_u32_le(data, 18)? as i32;
+    let height = read_u32_le(data, 22)? as i32;
+    Some((width.unsigned_abs(), height.unsigned_abs()))
+}
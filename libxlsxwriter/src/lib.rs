@@ -13,6 +13,7 @@
 //! * Merged cells.
 //! * Autofilters.
 //! * Data validation and drop down lists.
+//! * Conditional formatting.
 //! * Worksheet PNG/JPEG images.
 //! * Cell comments.
 //!
@@ -67,29 +68,49 @@
 extern crate libxlsxwriter_sys;
 
 mod chart;
+mod chartsheet;
+mod conditional_format;
+#[cfg(feature = "debug")]
+mod debug;
 mod error;
 mod format;
+mod formula;
+mod image;
 mod validation;
 mod workbook;
 mod worksheet;
+mod writer;
 
 pub use chart::{
-    Chart, ChartDashType, ChartFill, ChartLine, ChartMarkerType, ChartPattern, ChartPatternType,
-    ChartSeries, ChartType,
+    Chart, ChartAxis, ChartAxisDisplayUnits, ChartAxisLabelAlignment, ChartDashType,
+    ChartErrorBarCap, ChartErrorBarDirection, ChartErrorBarType, ChartErrorBars, ChartFill,
+    ChartFont, ChartLabelPosition, ChartLegend, ChartLegendPosition, ChartLine, ChartMarkerType,
+    ChartPattern, ChartPatternType, ChartPoint, ChartSeries, ChartStyle, ChartTrendline,
+    ChartTrendlineType, ChartType,
 };
+pub use chartsheet::Chartsheet;
+pub use conditional_format::{
+    ConditionalFormat, ConditionalFormatCriteria, ConditionalFormatRuleType, ConditionalFormatType,
+};
+#[cfg(feature = "debug")]
+pub use debug::xlsx_part_xml;
 pub use error::XlsxError;
 pub use format::{
-    Format, FormatAlignment, FormatBorder, FormatColor, FormatPatterns, FormatScript,
-    FormatUnderline,
+    Format, FormatAlignment, FormatBorder, FormatColor, FormatDiagonalType, FormatPatterns,
+    FormatScript, FormatUnderline, NumFormat,
 };
+pub use formula::Formula;
+pub use image::{image_buffer_dimensions, image_dimensions};
 pub use validation::{
     DataValidation, DataValidationCriteria, DataValidationErrorType, DataValidationType,
 };
-pub use workbook::Workbook;
+pub use workbook::{DocProperties, TempPath, Workbook, WorkbookOptions, WorksheetInitOptions};
 pub use worksheet::{
-    CommentOptions, DateTime, GridLines, HeaderFooterOptions, ImageOptions, PaperType, Protection,
-    RowColOptions, Worksheet, WorksheetCol, WorksheetRow,
+    CellRange, CellWrite, CommentOptions, DateTime, GridLines, HeaderFooterOptions, IgnoreError,
+    ImageOptions, ObjectPosition, OutlineSettings, PaperType, PrintPreset, Protection,
+    RowColOptions, Worksheet, WorksheetCol, WorksheetRow, MAX_COL, MAX_ROW,
 };
+pub use writer::{ExcelWriter, RowWriter};
 
 use std::ffi::CString;
 
@@ -102,9 +123,11 @@ fn convert_bool(value: bool) -> u8 {
     result as u8
 }
 
-fn convert_str(value: &str) -> Vec<u8> {
-    CString::new(value).unwrap().as_bytes_with_nul().to_vec()
+fn convert_str(value: &str) -> Result<Vec<u8>, XlsxError> {
+    Ok(CString::new(value)?.into_bytes_with_nul())
 }
 
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod test_util;
@@ -83,3 +83,3262 @@ fn test_sample1() -> Result<(), XlsxError> {
     workbook.close()?;
     Ok(())
 }
+
+#[test]
+fn test_show_comments() -> Result<(), XlsxError> {
+    let path = "../target/show_comments.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.set_comments_author("xlsxwriter-rs")?;
+    sheet.write_comment(0, 0, "Visible comment")?;
+    sheet.write_comment(1, 0, "Another visible comment")?;
+    sheet.show_comments();
+    workbook.close()?;
+
+    let vml = test_util::read_xlsx_entry(path, "xl/drawings/vmlDrawing1.vml");
+    assert_eq!(vml.matches("visibility:visible").count(), 2);
+    assert_eq!(vml.matches("<x:Visible/>").count(), 2);
+    let comments_xml = test_util::read_xlsx_entry(path, "xl/comments1.xml");
+    assert!(comments_xml.contains("<authors><author>xlsxwriter-rs</author></authors>"));
+    assert_eq!(comments_xml.matches(r#"authorId="0""#).count(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_header_footer() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/header_footer.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.set_header("&LReport&CQuarterly figures&RPage &P of &N")?;
+    sheet.set_footer_opt("&CFooter", &HeaderFooterOptions { margin: 0.5 })?;
+    for i in 0..200 {
+        sheet.write_number(i, 0, i.into(), None)?;
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_axis_crossing() -> Result<(), XlsxError> {
+    let path = "../target/chart_axis_crossing.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut column_chart = workbook.add_chart(ChartType::Column);
+    column_chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    column_chart.x_axis().set_crossing_max();
+    column_chart.y_axis().set_crossing_min();
+    sheet.insert_chart(0, 3, &column_chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(path, "xl/charts/chart1.xml");
+    assert!(chart_xml.contains(r#"<c:crosses val="max"/>"#));
+    assert!(chart_xml.contains(r#"<c:crosses val="min"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_worksheets_iterator() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/worksheets_iterator.xlsx");
+    workbook.add_worksheet(None)?;
+    workbook.add_worksheet(Some("Second"))?;
+    workbook.add_worksheet(Some("Third"))?;
+    assert_eq!(workbook.worksheets().count(), 3);
+    for mut sheet in workbook.worksheets() {
+        sheet.write_string(0, 0, "Hello", None)?;
+        sheet.set_footer("&CPage &P")?;
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_add_series_error() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/add_series_error.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, i.into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Scatter);
+    assert!(chart.add_series(None, Some("=Sheet1!$A$1:$A$5")).is_err());
+    chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$A$1:$A$5"))?;
+    sheet.insert_chart(0, 2, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_labels() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_labels.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_string(i, 0, &format!("Q{}", i + 1), None)?;
+        sheet.write_number(i, 1, (i * 1250).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    series.set_labels();
+    series.set_labels_options(false, false, true);
+    series.set_labels_num_format("$#,##0")?;
+    series.set_labels_position(ChartLabelPosition::OutsideEnd);
+    sheet.insert_chart(0, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_hidden_column_width() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/hidden_column_width.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let mut options = RowColOptions {
+        hidden: 1,
+        level: 0,
+        collapsed: 0,
+    };
+    sheet.set_column_opt(1, 1, 25.0, None, &mut options)?;
+    sheet.write_string(0, 1, "Hidden but sized", None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_image_dimensions() -> Result<(), XlsxError> {
+    assert_eq!(image_dimensions("../images/simple1.png")?, (425, 165));
+    assert!(image_dimensions("../images/not-found.png").is_err());
+    assert!(image_buffer_dimensions(b"not an image").is_err());
+
+    let workbook = Workbook::new("../target/image_dimensions.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let options = ImageOptions {
+        x_offset: 0,
+        y_offset: 0,
+        x_scale: 0.5,
+        y_scale: 0.5,
+        ..ImageOptions::default()
+    };
+    let (width, height) = image_dimensions("../images/simple1.png")?;
+    sheet.insert_image_opt(0, 0, "../images/simple1.png", &options)?;
+    sheet.write_string(
+        0,
+        5,
+        &format!(
+            "{}x{}",
+            width as f64 * options.x_scale,
+            height as f64 * options.y_scale
+        ),
+        None,
+    )?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_hyperlink_base() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/hyperlink_base.xlsx");
+    workbook.set_properties(&DocProperties {
+        title: Some("Hyperlink base".to_string()),
+        hyperlink_base: Some("https://intranet/".to_string()),
+        ..DocProperties::default()
+    })?;
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_url(0, 0, "reports/index.html", None)?;
+    sheet.write_url(1, 0, "reports/2020/summary.html", None)?;
+    workbook.close()?;
+
+    let app_xml = test_util::read_xlsx_entry("../target/hyperlink_base.xlsx", "docProps/app.xml");
+    assert!(app_xml.contains("<HyperlinkBase>https://intranet/</HyperlinkBase>"));
+    let rels_xml = test_util::read_xlsx_entry(
+        "../target/hyperlink_base.xlsx",
+        "xl/worksheets/_rels/sheet1.xml.rels",
+    );
+    assert!(rels_xml.contains(r#"Target="reports/index.html""#));
+    assert!(rels_xml.contains(r#"Target="reports/2020/summary.html""#));
+    Ok(())
+}
+
+#[test]
+fn test_conditional_format() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/conditional_format.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..10 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+        sheet.write_number(i, 1, (i * 5).into(), None)?;
+    }
+    let mut scale = ConditionalFormat::two_color_scale();
+    scale.min_color = Some(FormatColor::Yellow);
+    scale.max_color = Some(FormatColor::Green);
+    sheet.conditional_format_range(0, 0, 9, 0, &scale)?;
+
+    let format = workbook.add_format().set_bg_color(FormatColor::Red);
+    sheet.conditional_format_range(
+        0,
+        1,
+        9,
+        1,
+        &ConditionalFormat::cell_between(10., 30., &format),
+    )?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_conditional_format_text() -> Result<(), XlsxError> {
+    let path = "../target/conditional_format_text.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let messages = ["OK", "ERROR: disk full", "OK", "warning", "ERROR: timeout"];
+    for (i, message) in messages.iter().enumerate() {
+        sheet.write_string(i as WorksheetRow, 0, message, None)?;
+    }
+    let red = workbook.add_format().set_font_color(FormatColor::Red);
+    sheet.conditional_format_range(
+        0,
+        0,
+        4,
+        0,
+        &ConditionalFormat::text(ConditionalFormatCriteria::TextContaining, "ERROR", &red),
+    )?;
+    sheet.conditional_format_range(
+        0,
+        1,
+        4,
+        1,
+        &ConditionalFormat::text(ConditionalFormatCriteria::TextBeginsWith, "OK", &red),
+    )?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"operator="containsText" text="ERROR""#));
+    assert!(sheet_xml.contains(r#"NOT(ISERROR(SEARCH("ERROR",A1)))"#));
+    assert!(sheet_xml.contains(r#"operator="beginsWith" text="OK""#));
+    Ok(())
+}
+
+#[test]
+fn test_conditional_format_formula() -> Result<(), XlsxError> {
+    let path = "../target/conditional_format_formula.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let statuses = ["Paid", "Overdue", "Paid", "Overdue"];
+    for (i, status) in statuses.iter().enumerate() {
+        let row = i as WorksheetRow + 1;
+        sheet.write_number(row, 0, f64::from(row), None)?;
+        sheet.write_string(row, 1, status, None)?;
+    }
+    let overdue = workbook.add_format().set_bg_color(FormatColor::Red);
+    sheet.conditional_format_range(
+        1,
+        0,
+        4,
+        1,
+        &ConditionalFormat::formula(r#"=$B2="Overdue""#, &overdue),
+    )?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<conditionalFormatting sqref="A2:B5">"#));
+    assert!(sheet_xml.contains(r#"<cfRule type="expression""#));
+    assert!(sheet_xml.contains(r#"<formula>$B2="Overdue"</formula>"#));
+    Ok(())
+}
+
+#[test]
+fn test_data_validation_list() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/data_validation_list.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let mut validation = DataValidation::new(
+        DataValidationType::List,
+        DataValidationCriteria::Between,
+        DataValidationErrorType::Stop,
+    );
+    validation.value_list = Some(vec![
+        "apple".to_string(),
+        "banana".to_string(),
+        "cherry".to_string(),
+    ]);
+    validation.error_title = Some("Invalid fruit".to_string());
+    validation.error_message = Some("Select a fruit from the list".to_string());
+    sheet.data_validation_range(0, 0, 9, 0, &validation)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_pie_chart() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/pie_chart.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let values = [45., 30., 15., 6., 3., 1.];
+    for (i, value) in values.iter().enumerate() {
+        sheet.write_string(i as WorksheetRow, 0, &format!("Slice {}", i + 1), None)?;
+        sheet.write_number(i as WorksheetRow, 1, *value, None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Pie);
+    chart.add_series(Some("=Sheet1!$A$1:$A$6"), Some("=Sheet1!$B$1:$B$6"))?;
+    sheet.insert_chart(0, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_pie_chart_point_label_text() -> Result<(), XlsxError> {
+    let path = "../target/pie_chart_point_label_text.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let values = [45., 30., 25.];
+    for (i, value) in values.iter().enumerate() {
+        sheet.write_string(i as WorksheetRow, 0, &format!("Slice {}", i + 1), None)?;
+        sheet.write_number(i as WorksheetRow, 1, *value, None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Pie);
+    let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$3"), Some("=Sheet1!$B$1:$B$3"))?;
+    let other = ChartPoint {
+        fill: Some(ChartFill {
+            color: FormatColor::Gray,
+            ..ChartFill::new()
+        }),
+        label_text: Some("Other".to_string()),
+        ..ChartPoint::new()
+    };
+    series.set_points(&[ChartPoint::new(), ChartPoint::new(), other])?;
+    series.set_labels_font(&ChartFont {
+        bold: true,
+        ..ChartFont::new()
+    })?;
+    sheet.insert_chart(0, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(path, "xl/charts/chart1.xml");
+    assert!(chart_xml.contains("<c:dPt><c:idx val=\"2\"/>"));
+    assert!(chart_xml.contains("<a:t>Other</a:t>"));
+    Ok(())
+}
+
+#[test]
+fn test_chart_labels_font() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_labels_font.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10 + 10).into(), None)?;
+    }
+    let font = ChartFont {
+        bold: true,
+        color: Some(FormatColor::White),
+        ..ChartFont::default()
+    };
+    let mut chart = workbook.add_chart(ChartType::Column);
+    let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    assert!(series.set_labels_font(&font).is_err());
+    series.set_labels();
+    series.set_labels_position(ChartLabelPosition::InsideEnd);
+    series.set_labels_font(&font)?;
+    sheet.insert_chart(0, 2, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_format_range() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/format_range.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "Header", None)?;
+    sheet.write_number(2, 2, 42., None)?;
+    sheet.write_formula(4, 4, "=C3*2", None)?;
+    let border = workbook.add_format().set_border(FormatBorder::Thin);
+    sheet.format_range(&CellRange::new(0, 0, 4, 4), &border)?;
+    {
+        let cells = workbook.cells.borrow();
+        let cells = &cells[&sheet.worksheet];
+        assert_eq!(cells.len(), 25);
+        assert!(cells.values().all(|cell| cell.format == border.format));
+        match &cells[&(0, 0)].value {
+            crate::worksheet::CellValue::String(text) => assert_eq!(text, "Header"),
+            _ => panic!("string was overwritten"),
+        }
+        match cells[&(2, 2)].value {
+            crate::worksheet::CellValue::Number(number) => assert_eq!(number, 42.),
+            _ => panic!("number was overwritten"),
+        }
+        match &cells[&(4, 4)].value {
+            crate::worksheet::CellValue::Formula(formula) => assert_eq!(formula, "=C3*2"),
+            _ => panic!("formula was overwritten"),
+        }
+    }
+    workbook.close()?;
+
+    let workbook = Workbook::new_with_options(
+        "../target/format_range_constant_memory.xlsx",
+        &WorkbookOptions {
+            constant_memory: true,
+            ..WorkbookOptions::default()
+        },
+    );
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_number(0, 0, 42., None)?;
+    let border = workbook.add_format().set_border(FormatBorder::Thin);
+    assert!(sheet
+        .format_range(&CellRange::new(0, 0, 1, 1), &border)
+        .is_err());
+    assert_eq!(sheet.written_cell_count(), 1);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_merged_cell_xml() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/merged_cell_xml.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.merge_range(1, 1, 2, 3, "Merged", None)?;
+    workbook.close()?;
+
+    let sheet_xml =
+        test_util::read_xlsx_entry("../target/merged_cell_xml.xlsx", "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<mergeCells count="1"><mergeCell ref="B2:D3"/></mergeCells>"#));
+    Ok(())
+}
+
+#[test]
+fn test_merged_range_recorded_once() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/merged_range_recorded_once.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let bold = workbook.add_format().set_bold();
+    sheet.merge_range(0, 0, MAX_ROW, 0, "Column", Some(&bold))?;
+    sheet.write_array_formula(1, 2, 0, 1, "{=A1:A2}", None)?;
+    assert_eq!(sheet.written_cell_count(), 1_048_576 + 4);
+    {
+        let cells = workbook.cells.borrow();
+        let cells = &cells[&sheet.worksheet];
+        assert_eq!(cells.len(), 2);
+        match &cells[&(0, 0)].value {
+            crate::worksheet::CellValue::String(text) => assert_eq!(text, "Column"),
+            _ => panic!("merged string was lost"),
+        }
+        assert!(cells.contains_key(&(0, 1)));
+    }
+    assert_eq!(
+        sheet.get_cell_format(MAX_ROW, 0).unwrap().format,
+        bold.format
+    );
+
+    // The cells of the ranges other than their top-left cell aren't written again.
+    let border = workbook.add_format().set_border(FormatBorder::Thin);
+    sheet.format_range(&CellRange::new(0, 0, 2, 3), &border)?;
+    assert_eq!(sheet.get_cell_format(0, 0).unwrap().format, bold.format);
+    assert!(sheet.get_cell_format(1, 2).is_none());
+    assert_eq!(sheet.get_cell_format(2, 3).unwrap().format, border.format);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_insert_image_buffer() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/insert_image_buffer.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let data = std::fs::read("../images/simple1.png").unwrap();
+    sheet.insert_image_buffer(0, 0, &data)?;
+    sheet.insert_image_buffer_opt(
+        10,
+        0,
+        &data,
+        &ImageOptions {
+            x_offset: 10,
+            y_offset: 10,
+            x_scale: 0.5,
+            y_scale: 0.5,
+            ..ImageOptions::default()
+        },
+    )?;
+    // The image data is copied on insertion.
+    drop(data);
+    workbook.close()?;
+
+    assert!(
+        std::fs::metadata("../target/insert_image_buffer.xlsx")
+            .unwrap()
+            .len()
+            > 0
+    );
+    let drawing_xml = test_util::read_xlsx_entry(
+        "../target/insert_image_buffer.xlsx",
+        "xl/drawings/drawing1.xml",
+    );
+    assert_eq!(drawing_xml.matches("<xdr:pic>").count(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_constant_memory() -> Result<(), XlsxError> {
+    let options = WorkbookOptions {
+        constant_memory: true,
+        tmpdir: Some("../target".to_string()),
+        ..WorkbookOptions::default()
+    };
+    let workbook = Workbook::new_with_options("../target/constant_memory.xlsx", &options);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for row in 0..100_000 {
+        for col in 0..10 {
+            sheet.write_number(row, col, f64::from(row) * f64::from(col), None)?;
+        }
+    }
+    // The cells are flushed to the temporary file instead of being kept in memory.
+    assert!(workbook.cells.borrow().is_empty());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_row_writer_constant_memory() -> Result<(), XlsxError> {
+    let options = WorkbookOptions {
+        constant_memory: true,
+        tmpdir: Some("../target".to_string()),
+        ..WorkbookOptions::default()
+    };
+    let workbook =
+        Workbook::new_with_options("../target/row_writer_constant_memory.xlsx", &options);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "Converted from CSV", None)?;
+    let mut rows = sheet.row_writer(1);
+    rows.write_row(&[&"id", &"name", &"value", &"valid"])?;
+    for i in 0..100_000u32 {
+        let name = format!("item {}", i);
+        rows.write_row(&[&i, &name, &(f64::from(i) / 4.0), &(i % 2 == 0)])?;
+    }
+    assert_eq!(rows.next_row(), 100_002);
+    assert!(workbook.cells.borrow().is_empty());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_scatter_error_bars() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/scatter_error_bars.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, i.into(), None)?;
+        sheet.write_number(i, 1, (i * i).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Scatter);
+    let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    series.set_y_error_bars(ChartErrorBarType::Fixed, 2.0);
+    series.set_x_error_bars(ChartErrorBarType::Fixed, 0.5);
+    let mut x_error_bars = series.x_error_bars();
+    x_error_bars.set_endcap(ChartErrorBarCap::NoCap);
+    x_error_bars.set_direction(ChartErrorBarDirection::Both);
+    sheet.insert_chart(0, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_formula() -> Result<(), XlsxError> {
+    let sum = Formula::sum("Sheet1", 0, 0, 9, 0);
+    assert_eq!(sum.as_str(), "=SUM(Sheet1!$A$1:$A$10)");
+    let average = Formula::average("Sales Data", 1, 26, 4, 27);
+    assert_eq!(average.as_str(), "=AVERAGE('Sales Data'!$AA$2:$AB$5)");
+    let raw = Formula::raw("=A1*2");
+    assert_eq!(raw.to_string(), "=A1*2");
+
+    let workbook = Workbook::new("../target/formula.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..10 {
+        sheet.write_number(i, 0, i.into(), None)?;
+    }
+    sheet.write_formula(10, 0, sum.as_str(), None)?;
+    sheet.write_formula(11, 0, Formula::average("Sheet1", 0, 0, 9, 0).as_str(), None)?;
+    sheet.write_formula(12, 0, raw.as_str(), None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_close_with_progress() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/close_with_progress.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for row in 0..200_000 {
+        sheet.write_number(row, 0, row.into(), None)?;
+        sheet.write_string(row, 1, &format!("Row {}", row), None)?;
+    }
+    let mut progress = Vec::new();
+    let size = workbook.close_with_progress(|bytes| progress.push(bytes))?;
+    assert!(!progress.is_empty());
+    assert!(progress.windows(2).all(|x| x[0] < x[1]));
+    assert_eq!(*progress.last().unwrap(), size);
+    assert_eq!(
+        size,
+        std::fs::metadata("../target/close_with_progress.xlsx")
+            .unwrap()
+            .len()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_close_with_progress_existing_file() -> Result<(), XlsxError> {
+    let path = "../target/close_with_progress_existing_file.xlsx";
+    std::fs::write(path, vec![0; 64 * 1024 * 1024]).unwrap();
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for row in 0..200_000 {
+        sheet.write_number(row, 0, row.into(), None)?;
+    }
+    let mut progress = Vec::new();
+    let size = workbook.close_with_progress(|bytes| progress.push(bytes))?;
+    assert!(size < 64 * 1024 * 1024);
+    assert!(progress.iter().all(|&bytes| bytes <= size));
+    assert_eq!(*progress.last().unwrap(), size);
+    Ok(())
+}
+
+#[test]
+fn test_header_footer_margins() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/header_footer_margins.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.set_margins(-1.0, -1.0, 0.5, 0.5);
+    sheet.set_header_opt("&CHeader", &HeaderFooterOptions { margin: 0.2 })?;
+    sheet.set_footer_opt("&CFooter", &HeaderFooterOptions { margin: 0.2 })?;
+    sheet.write_string(0, 0, "Data", None)?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(
+        "../target/header_footer_margins.xlsx",
+        "xl/worksheets/sheet1.xml",
+    );
+    assert!(sheet_xml.contains(
+        r#"<pageMargins left="0.7" right="0.7" top="0.5" bottom="0.5" header="0.2" footer="0.2"/>"#
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_rich_string() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/rich_string.xlsx");
+    let bold = workbook.add_format().set_bold();
+    let mut sheet = workbook.add_worksheet(None)?;
+    assert!(sheet.write_rich_string(0, 0, &[], None).is_err());
+    assert!(sheet
+        .write_rich_string(0, 0, &[("Total: ", None), ("", Some(&bold))], None)
+        .is_err());
+    sheet.write_rich_string(0, 0, &[("Total: ", None), ("42", Some(&bold))], None)?;
+    workbook.close()?;
+
+    let shared_strings =
+        test_util::read_xlsx_entry("../target/rich_string.xlsx", "xl/sharedStrings.xml");
+    assert!(shared_strings.contains(r#"<t xml:space="preserve">Total: </t>"#));
+    assert!(shared_strings.contains("<b/>"));
+    assert!(shared_strings.contains("<t>42</t>"));
+    Ok(())
+}
+
+fn scatter_chart_xml(chart_type: ChartType, filename: &str) -> Result<String, XlsxError> {
+    let workbook = Workbook::new(filename);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, i.into(), None)?;
+        sheet.write_number(i, 1, (i * i).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(chart_type);
+    chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    sheet.insert_chart(0, 3, &chart)?;
+    workbook.close()?;
+    Ok(test_util::read_xlsx_entry(filename, "xl/charts/chart1.xml"))
+}
+
+const NO_LINE: &str = r#"<a:ln w="28575"><a:noFill/></a:ln>"#;
+const NO_MARKER: &str = r#"<c:symbol val="none"/>"#;
+const SMOOTH: &str = r#"<c:smooth val="1"/>"#;
+
+#[test]
+fn test_scatter_markers_only() -> Result<(), XlsxError> {
+    let xml = scatter_chart_xml(ChartType::Scatter, "../target/scatter.xlsx")?;
+    assert!(xml.contains(r#"<c:scatterStyle val="lineMarker"/>"#));
+    assert!(xml.contains(NO_LINE));
+    assert!(!xml.contains(NO_MARKER));
+    assert!(!xml.contains(SMOOTH));
+    Ok(())
+}
+
+#[test]
+fn test_scatter_straight() -> Result<(), XlsxError> {
+    let xml = scatter_chart_xml(
+        ChartType::ScatterStraight,
+        "../target/scatter_straight.xlsx",
+    )?;
+    assert!(xml.contains(r#"<c:scatterStyle val="lineMarker"/>"#));
+    assert!(!xml.contains(NO_LINE));
+    assert!(xml.contains(NO_MARKER));
+    assert!(!xml.contains(SMOOTH));
+    Ok(())
+}
+
+#[test]
+fn test_scatter_straight_with_markers() -> Result<(), XlsxError> {
+    let xml = scatter_chart_xml(
+        ChartType::ScatterStraightWithMarkers,
+        "../target/scatter_straight_with_markers.xlsx",
+    )?;
+    assert!(xml.contains(r#"<c:scatterStyle val="lineMarker"/>"#));
+    assert!(!xml.contains(NO_LINE));
+    assert!(!xml.contains(NO_MARKER));
+    assert!(!xml.contains(SMOOTH));
+    Ok(())
+}
+
+#[test]
+fn test_scatter_smooth() -> Result<(), XlsxError> {
+    let xml = scatter_chart_xml(ChartType::ScatterSmooth, "../target/scatter_smooth.xlsx")?;
+    assert!(xml.contains(r#"<c:scatterStyle val="smoothMarker"/>"#));
+    assert!(!xml.contains(NO_LINE));
+    assert!(xml.contains(NO_MARKER));
+    assert!(xml.contains(SMOOTH));
+    Ok(())
+}
+
+#[test]
+fn test_scatter_smooth_with_markers() -> Result<(), XlsxError> {
+    let xml = scatter_chart_xml(
+        ChartType::ScatterSmoothWithMarkers,
+        "../target/scatter_smooth_with_markers.xlsx",
+    )?;
+    assert!(xml.contains(r#"<c:scatterStyle val="smoothMarker"/>"#));
+    assert!(!xml.contains(NO_LINE));
+    assert!(!xml.contains(NO_MARKER));
+    assert!(xml.contains(SMOOTH));
+    Ok(())
+}
+
+#[test]
+fn test_merge_range() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/merge_range.xlsx");
+    let center = workbook.add_format().set_align(FormatAlignment::Center);
+    let mut sheet = workbook.add_worksheet(None)?;
+    assert!(sheet
+        .merge_range(0, 0, 0, 0, "Single", Some(&center))
+        .is_err());
+    sheet.merge_range(1, 1, 1, 3, "Invoice", Some(&center))?;
+    workbook.close()?;
+
+    let sheet_xml =
+        test_util::read_xlsx_entry("../target/merge_range.xlsx", "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<mergeCell ref="B2:D2"/>"#));
+    assert!(!sheet_xml.contains(r#"<mergeCell ref="A1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_unlocked_cells() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/unlocked_cells.xlsx");
+    let locked = workbook.add_format().set_locked().set_bold();
+    let unlocked = workbook.add_format().set_unlocked();
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.protect(
+        Some("password"),
+        &Protection {
+            no_select_unlocked_cells: false,
+            ..Protection::new()
+        },
+    )?;
+    sheet.write_string(0, 0, "Label", Some(&locked))?;
+    for row in 1..10 {
+        sheet.write_blank(row, 1, Some(&unlocked))?;
+    }
+    workbook.close()?;
+
+    let styles_xml = test_util::read_xlsx_entry("../target/unlocked_cells.xlsx", "xl/styles.xml");
+    assert_eq!(styles_xml.matches(r#"<protection locked="0"/>"#).count(), 1);
+    let sheet_xml =
+        test_util::read_xlsx_entry("../target/unlocked_cells.xlsx", "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains("<sheetProtection"));
+    assert!(!sheet_xml.contains(r#"selectUnlockedCells="1""#));
+    Ok(())
+}
+
+#[test]
+fn test_hidden_formulas() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/hidden_formulas.xlsx");
+    let hidden = workbook.add_format().set_hidden();
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.protect(Some("password"), &Protection::new())?;
+    sheet.write_number(0, 0, 1., None)?;
+    sheet.write_number(1, 0, 2., None)?;
+    sheet.write_formula(2, 0, "=SUM(A1:A2)", Some(&hidden))?;
+    workbook.close()?;
+
+    let styles_xml = test_util::read_xlsx_entry("../target/hidden_formulas.xlsx", "xl/styles.xml");
+    assert!(styles_xml.contains(r#"<protection hidden="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_freeze_panes() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/freeze_panes.xlsx");
+    let mut sheet1 = workbook.add_worksheet(None)?;
+    sheet1.write_string(0, 0, "Header", None)?;
+    sheet1.freeze_panes(1, 0);
+    let mut sheet2 = workbook.add_worksheet(None)?;
+    sheet2.split_panes(15.0, 8.43);
+    sheet2.freeze_panes_opt(1, 0, 20, 0);
+    workbook.close()?;
+
+    let sheet1_xml =
+        test_util::read_xlsx_entry("../target/freeze_panes.xlsx", "xl/worksheets/sheet1.xml");
+    assert!(sheet1_xml
+        .contains(r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#));
+    let sheet2_xml =
+        test_util::read_xlsx_entry("../target/freeze_panes.xlsx", "xl/worksheets/sheet2.xml");
+    assert_eq!(sheet2_xml.matches("<pane ").count(), 1);
+    assert!(sheet2_xml.contains(r#"topLeftCell="A21""#));
+    assert!(sheet2_xml.contains(r#"state="frozen""#));
+    Ok(())
+}
+
+#[test]
+fn test_set_column_row_emu() -> Result<(), XlsxError> {
+    use crate::worksheet::{column_emu_to_width, row_emu_to_points};
+    assert_eq!(column_emu_to_width(914_400), 13.0);
+    assert_eq!(column_emu_to_width(9_525 * 6), 0.5);
+    assert_eq!(row_emu_to_points(914_400), 72.0);
+    assert_eq!(row_emu_to_points(12_700 * 15), 15.0);
+
+    let path = "../target/set_column_row_emu.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.set_column_emu(0, 0, 914_400, None)?;
+    sheet.set_row_emu(0, 457_200, None)?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<col min="1" max="1" width="13.7109375" customWidth="1"/>"#));
+    assert!(sheet_xml.contains(r#"<row r="1" ht="36" customHeight="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_set_row_pixels() -> Result<(), XlsxError> {
+    assert_eq!(crate::worksheet::row_pixels_to_points(40), 30.0);
+    assert_eq!(crate::worksheet::row_pixels_to_points(20), 15.0);
+
+    let workbook = Workbook::new("../target/set_row_pixels.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.set_row_pixels(0, 40, None)?;
+    let mut options = RowColOptions {
+        hidden: 1,
+        level: 1,
+        collapsed: 0,
+    };
+    sheet.set_row_opt_pixels(1, 40, None, &mut options)?;
+    workbook.close()?;
+
+    let sheet_xml =
+        test_util::read_xlsx_entry("../target/set_row_pixels.xlsx", "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<row r="1" ht="30" customHeight="1"/>"#));
+    assert!(
+        sheet_xml.contains(r#"<row r="2" ht="30" hidden="1" customHeight="1" outlineLevel="1"/>"#)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_comment_options() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/comment_options.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_number(0, 0, 42., None)?;
+    sheet.write_comment_opt(
+        0,
+        0,
+        "Checked",
+        &CommentOptions {
+            visible: Some(true),
+            author: Some("Reviewer".to_string()),
+            width: 200,
+            height: 100,
+            color: Some(FormatColor::Cyan),
+            ..CommentOptions::default()
+        },
+    )?;
+    sheet.write_comment_opt(
+        1,
+        0,
+        "Hidden",
+        &CommentOptions {
+            visible: Some(false),
+            ..CommentOptions::new()
+        },
+    )?;
+    workbook.close()?;
+
+    let comments_xml =
+        test_util::read_xlsx_entry("../target/comment_options.xlsx", "xl/comments1.xml");
+    assert!(comments_xml.contains("<author>Reviewer</author>"));
+    let sheet_xml =
+        test_util::read_xlsx_entry("../target/comment_options.xlsx", "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<c r="A1"><v>42</v></c>"#));
+    Ok(())
+}
+
+#[test]
+fn test_set_selection_once() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/set_selection_once.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.set_selection(0, 0, 1, 1);
+    sheet.set_selection(3, 3, 4, 4);
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(
+        "../target/set_selection_once.xlsx",
+        "xl/worksheets/sheet1.xml",
+    );
+    assert!(sheet_xml.contains(r#"<selection activeCell="A1" sqref="A1:B2"/>"#));
+    assert!(!sheet_xml.contains("D4:E5"));
+    Ok(())
+}
+
+#[test]
+fn test_protect_password() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/protect_password.xlsx");
+    let unlocked = workbook.add_format().set_unlocked();
+    let mut sheet1 = workbook.add_worksheet(None)?;
+    sheet1.write_string(0, 0, "Input:", None)?;
+    sheet1.write_blank(0, 1, Some(&unlocked))?;
+    sheet1.protect(
+        Some("password"),
+        &Protection {
+            no_select_unlocked_cells: false,
+            ..Protection::new()
+        },
+    )?;
+    let mut sheet2 = workbook.add_worksheet(None)?;
+    sheet2.protect(None, &Protection::new())?;
+    workbook.close()?;
+
+    let sheet1_xml = test_util::read_xlsx_entry(
+        "../target/protect_password.xlsx",
+        "xl/worksheets/sheet1.xml",
+    );
+    assert!(sheet1_xml.contains(r#"<sheetProtection password="83AF""#));
+    let sheet2_xml = test_util::read_xlsx_entry(
+        "../target/protect_password.xlsx",
+        "xl/worksheets/sheet2.xml",
+    );
+    assert!(sheet2_xml.contains("<sheetProtection"));
+    assert!(!sheet2_xml.contains("password="));
+    Ok(())
+}
+
+#[test]
+fn test_set_column_and_grouped_rows() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/set_column_and_grouped_rows.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.set_column(0, 1, 30.0, None)?;
+    let mut options = RowColOptions {
+        hidden: 1,
+        level: 1,
+        collapsed: 0,
+    };
+    sheet.set_row_opt(1, 15.0, None, &mut options)?;
+    sheet.write_string(1, 0, "grouped", None)?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(
+        "../target/set_column_and_grouped_rows.xlsx",
+        "xl/worksheets/sheet1.xml",
+    );
+    assert!(sheet_xml.contains(r#"<col min="1" max="2" width="30.7109375" customWidth="1"/>"#));
+    assert!(sheet_xml.contains(r#"<row r="2" spans="1:1" hidden="1" outlineLevel="1">"#));
+    Ok(())
+}
+
+#[test]
+fn test_write_row_option() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/write_row_option.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_row(0, 0, &[Some(1.0), None, Some(3.0)], None)?;
+    sheet.write_row(1, 0, &[Some("a"), None, Some("c")], None)?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(
+        "../target/write_row_option.xlsx",
+        "xl/worksheets/sheet1.xml",
+    );
+    assert!(sheet_xml.contains(r#"<c r="A1"><v>1</v></c><c r="C1"><v>3</v></c>"#));
+    assert!(!sheet_xml.contains(r#"r="B1""#));
+    assert!(!sheet_xml.contains(r#"r="B2""#));
+    Ok(())
+}
+
+#[test]
+fn test_nul_strings() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/nul_strings.xlsx");
+    let nul = "a\0b";
+    assert!(workbook.add_worksheet(Some(nul)).is_err());
+    assert!(workbook.get_worksheet(nul).is_none());
+    assert!(workbook
+        .set_properties(&DocProperties {
+            title: Some(nul.to_string()),
+            ..DocProperties::default()
+        })
+        .is_err());
+
+    let mut sheet = workbook.add_worksheet(None)?;
+    assert!(sheet.write_string(0, 0, nul, None).is_err());
+    assert!(sheet.write_formula(0, 0, nul, None).is_err());
+    assert!(sheet.write_array_formula(0, 0, 1, 0, nul, None).is_err());
+    assert!(sheet.write_formula_num(0, 0, nul, None, 1.).is_err());
+    assert!(sheet.write_formula_str(0, 0, "=A2", None, nul).is_err());
+    assert!(sheet.write_url(0, 0, nul, None).is_err());
+    assert!(sheet.write_rich_string(0, 0, &[(nul, None)], None).is_err());
+    assert!(sheet.write_comment(0, 0, nul).is_err());
+    assert!(sheet
+        .write_comment_opt(
+            0,
+            0,
+            "comment",
+            &CommentOptions {
+                author: Some(nul.to_string()),
+                ..CommentOptions::default()
+            }
+        )
+        .is_err());
+    assert!(sheet.set_comments_author(nul).is_err());
+    assert!(sheet.merge_range(0, 0, 1, 1, nul, None).is_err());
+    assert!(sheet.insert_image(0, 0, nul).is_err());
+    assert!(sheet.set_header(nul).is_err());
+    assert!(sheet.set_footer(nul).is_err());
+    assert!(sheet.set_vba_name(nul).is_err());
+    assert!(sheet.protect(Some(nul), &Protection::new()).is_err());
+    let mut validation = DataValidation::new(
+        DataValidationType::List,
+        DataValidationCriteria::Between,
+        DataValidationErrorType::Stop,
+    );
+    validation.value_list = Some(vec!["ok".to_string(), nul.to_string()]);
+    assert!(sheet.data_validation_cell(0, 0, &validation).is_err());
+    let format = workbook.add_format().set_bold();
+    let conditional_format = ConditionalFormat {
+        value_string: Some(nul.to_string()),
+        ..ConditionalFormat::cell(ConditionalFormatCriteria::EqualTo, 0., &format)
+    };
+    assert!(sheet
+        .conditional_format_cell(0, 0, &conditional_format)
+        .is_err());
+
+    let mut chart = workbook.add_chart(ChartType::Column);
+    assert!(chart.add_series(None, Some(nul)).is_err());
+    assert!(chart.set_title_name(nul).is_err());
+    assert!(chart.set_title_name_range(nul, 0, 0).is_err());
+    let font = ChartFont {
+        name: Some(nul.to_string()),
+        ..ChartFont::default()
+    };
+    assert!(chart.set_title_name_font(&font).is_err());
+    let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$2"))?;
+    assert!(series.set_categories(nul, 0, 0, 1, 0).is_err());
+    assert!(series.set_values(nul, 0, 0, 1, 0).is_err());
+    assert!(series.set_name(nul).is_err());
+    assert!(series.set_name_range(nul, 0, 0).is_err());
+    series.set_labels();
+    assert!(series.set_labels_num_format(nul).is_err());
+    assert!(series.set_labels_font(&font).is_err());
+
+    let _ = workbook.add_format().set_font_name(nul).set_num_format(nul);
+    workbook.close()?;
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_workbook_name() {
+    use std::os::unix::ffi::OsStrExt;
+    let path = std::path::Path::new(std::ffi::OsStr::from_bytes(b"../target/\xff.xlsx"));
+    let workbook = Workbook::new(path);
+    assert!(workbook
+        .add_worksheet(None)
+        .err()
+        .unwrap()
+        .is_non_utf8_path());
+    let error = workbook.close().unwrap_err();
+    assert!(error.is_non_utf8_path());
+    assert_eq!(error.to_string(), "File name is not valid UTF-8");
+    assert!(!path.exists());
+    assert!(!Workbook::new("a\0b.xlsx")
+        .close()
+        .unwrap_err()
+        .is_non_utf8_path());
+}
+
+#[test]
+fn test_nul_workbook_options() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("a\0b.xlsx");
+    assert!(workbook.add_worksheet(None).is_err());
+    assert!(workbook.add_chartsheet(None).is_err());
+    assert!(workbook.close().is_err());
+    // A workbook dropped without being closed is freed without writing a file.
+    drop(Workbook::new_with_options(
+        "a\0b.xlsx",
+        &WorkbookOptions::default(),
+    ));
+
+    let path = "../target/nul_tmpdir.xlsx";
+    let _ = std::fs::remove_file(path);
+    let workbook = Workbook::new_with_options(
+        path,
+        &WorkbookOptions {
+            tmpdir: Some("../target/a\0b".to_string()),
+            ..WorkbookOptions::default()
+        },
+    );
+    assert!(workbook.add_worksheet(None).is_err());
+    assert!(workbook.close_with_progress(|_| ()).is_err());
+    assert!(!std::path::Path::new(path).exists());
+
+    let mut writer = ExcelWriter::new("a\0b.xlsx");
+    assert!(writer.write(0, 0, "text").is_err());
+    assert!(writer.finish().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_chart_series_order() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_series_order.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+        sheet.write_number(i, 1, (i * 10 + 2).into(), None)?;
+        sheet.write_number(i, 2, (i * 10 + 4).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::ColumnStacked);
+    assert_eq!(chart.series_count(), 0);
+    chart.add_series(None, Some("=Sheet1!$C$1:$C$5"))?;
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    assert_eq!(chart.series_count(), 3);
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml =
+        test_util::read_xlsx_entry("../target/chart_series_order.xlsx", "xl/charts/chart1.xml");
+    let c = chart_xml.find("<c:f>Sheet1!$C$1:$C$5</c:f>").unwrap();
+    let a = chart_xml.find("<c:f>Sheet1!$A$1:$A$5</c:f>").unwrap();
+    let b = chart_xml.find("<c:f>Sheet1!$B$1:$B$5</c:f>").unwrap();
+    assert!(c < a && a < b);
+    Ok(())
+}
+
+#[test]
+fn test_chart_series_gap_overlap() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_series_gap_overlap.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+        sheet.write_number(i, 1, (i * 10 + 2).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    chart.set_series_gap(50)?;
+    chart.set_series_overlap(-20)?;
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_series_gap_overlap.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(chart_xml.contains(r#"<c:gapWidth val="50"/>"#));
+    assert!(chart_xml.contains(r#"<c:overlap val="-20"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_insert_svg_with_png_fallback() -> Result<(), XlsxError> {
+    let svg = r#"<?xml version="1.0" encoding="UTF-8"?>
+<svg xmlns="http://www.w3.org/2000/svg" width="100" height="40"><rect width="100" height="40" fill="red"/></svg>"#;
+    std::fs::write("../target/logo.svg", svg).unwrap();
+
+    let workbook = Workbook::new("../target/insert_svg.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    assert!(sheet.insert_image(0, 0, "../target/logo.svg").is_err());
+    assert!(sheet.insert_image_buffer(0, 0, svg.as_bytes()).is_err());
+    sheet.insert_image(0, 0, "../images/simple1.png")?;
+    workbook.close()?;
+
+    let drawing_xml =
+        test_util::read_xlsx_entry("../target/insert_svg.xlsx", "xl/drawings/drawing1.xml");
+    assert_eq!(drawing_xml.matches("<xdr:pic>").count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_comment_box_color() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/comment_box_color.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_comment_opt(
+        0,
+        0,
+        "Overdue",
+        &CommentOptions {
+            color: Some(FormatColor::Red),
+            font_name: Some("Arial".to_string()),
+            font_size: 10.,
+            ..CommentOptions::new()
+        },
+    )?;
+    workbook.close()?;
+
+    let vml = test_util::read_xlsx_entry(
+        "../target/comment_box_color.xlsx",
+        "xl/drawings/vmlDrawing1.vml",
+    );
+    assert!(vml.contains(r##"fillcolor="#ff0000""##));
+    let comments_xml =
+        test_util::read_xlsx_entry("../target/comment_box_color.xlsx", "xl/comments1.xml");
+    assert!(comments_xml.contains(r#"<rFont val="Arial"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_freeze_header() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/freeze_header.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "Header", None)?;
+    sheet.freeze_header();
+    workbook.close()?;
+
+    let sheet_xml =
+        test_util::read_xlsx_entry("../target/freeze_header.xlsx", "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml
+        .contains(r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#));
+    assert!(sheet_xml.contains(r#"<selection pane="bottomLeft" activeCell="A2" sqref="A2"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_merge_cell_format() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/merge_cell_format.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let bold = workbook.add_format().set_bold();
+    sheet.write_string(0, 0, "Total", Some(&bold))?;
+    sheet.write_string(1, 0, "Plain", None)?;
+    assert!(sheet.get_cell_format(1, 0).is_none());
+    assert!(sheet.get_cell_format(2, 0).is_none());
+
+    let border = workbook.add_format().set_border(FormatBorder::Thin);
+    let merged = sheet.get_cell_format(0, 0).unwrap().merge(&border);
+    unsafe {
+        assert_eq!((*merged.format).bold, 1);
+        assert_eq!(
+            (*merged.format).bottom,
+            libxlsxwriter_sys::lxw_format_borders_LXW_BORDER_THIN as u8
+        );
+        assert_eq!((*bold.format).bottom, 0);
+    }
+    sheet.write_string(0, 0, "Total", Some(&merged))?;
+    assert_eq!(sheet.get_cell_format(0, 0).unwrap().format, merged.format);
+    workbook.close()?;
+
+    let styles_xml =
+        test_util::read_xlsx_entry("../target/merge_cell_format.xlsx", "xl/styles.xml");
+    assert!(styles_xml.contains(r#"<xf numFmtId="0" fontId="1" fillId="0" borderId="1" xfId="0" applyFont="1" applyBorder="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_chart_style() -> Result<(), XlsxError> {
+    assert_eq!(ChartStyle::Simple1.value(), 1);
+    assert_eq!(ChartStyle::Simple2.value(), 2);
+    assert_eq!(ChartStyle::Outline1.value(), 9);
+    assert_eq!(ChartStyle::Intense8.value(), 40);
+    assert_eq!(ChartStyle::Dark2.value(), 42);
+    assert_eq!(ChartStyle::Dark8.value(), 48);
+
+    let workbook = Workbook::new("../target/chart_style.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.set_style(ChartStyle::Dark2);
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml =
+        test_util::read_xlsx_entry("../target/chart_style.xlsx", "xl/charts/chart1.xml");
+    assert!(chart_xml.contains(r#"<c:style val="42"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_write_percent() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/write_percent.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let bold = workbook.add_format().set_bold();
+    sheet.write_percent(0, 0, 0.25, 0, None)?;
+    sheet.write_percent(1, 0, 0.5, 0, None)?;
+    sheet.write_percent(2, 0, 0.1234, 2, Some(&bold))?;
+    assert_eq!(
+        sheet.get_cell_format(0, 0).unwrap().format,
+        sheet.get_cell_format(1, 0).unwrap().format
+    );
+    workbook.close()?;
+
+    let sheet_xml =
+        test_util::read_xlsx_entry("../target/write_percent.xlsx", "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<c r="A1" s="1"><v>0.25</v></c>"#));
+    let styles_xml = test_util::read_xlsx_entry("../target/write_percent.xlsx", "xl/styles.xml");
+    // The percent formats are written as custom number formats.
+    assert!(styles_xml.contains(r#"<numFmt numFmtId="164" formatCode="0%"/>"#));
+    assert!(styles_xml.contains(r#"<numFmt numFmtId="165" formatCode="0.00%"/>"#));
+    assert!(styles_xml.contains(
+        r#"<xf numFmtId="164" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/>"#
+    ));
+    assert!(styles_xml.contains(r#"<xf numFmtId="165" fontId="1" fillId="0" borderId="0" xfId="0" applyNumberFormat="1" applyFont="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_category_axis_num_format() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/category_axis_num_format.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (2016 + i).into(), None)?;
+        sheet.write_number(i, 1, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    chart.x_axis().set_num_format("0")?;
+    assert!(chart.x_axis().set_num_format("0\0").is_err());
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/category_axis_num_format.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(chart_xml.contains(r#"<c:numFmt formatCode="0" sourceLinked="0"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_insert_image_dedup() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/insert_image_dedup.xlsx");
+    let data = std::fs::read("../images/simple1.png").unwrap();
+    for _ in 0..3 {
+        let mut sheet = workbook.add_worksheet(None)?;
+        sheet.insert_image_buffer(0, 0, &data)?;
+    }
+    workbook
+        .get_worksheet("Sheet1")
+        .unwrap()
+        .insert_image(10, 0, "../images/simple1.png")?;
+    workbook.close()?;
+
+    let media = test_util::xlsx_entry_names("../target/insert_image_dedup.xlsx", "xl/media/");
+    assert_eq!(media, vec!["xl/media/image1.png".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn test_chart_number_format_all() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_number_format_all.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, f64::from(i) / 10., None)?;
+        sheet.write_number(i, 1, f64::from(i) / 20., None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart
+        .add_series(None, Some("=Sheet1!$A$1:$A$5"))?
+        .set_labels();
+    chart
+        .add_series(None, Some("=Sheet1!$B$1:$B$5"))?
+        .set_labels();
+    chart.set_number_format_all("0.0%")?;
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_number_format_all.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    // The data labels of both series and the value axis.
+    assert_eq!(
+        chart_xml
+            .matches(r#"<c:numFmt formatCode="0.0%" sourceLinked="0"/>"#)
+            .count(),
+        3
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chart_trendline_struct() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_trendline_struct.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..6 {
+        sheet.write_number(i, 0, (i + 1).into(), None)?;
+        sheet.write_number(i, 1, (i * 3 + i % 2).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Scatter);
+    let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$6"), Some("=Sheet1!$B$1:$B$6"))?;
+    let mut trendline = ChartTrendline::new(ChartTrendlineType::Linear);
+    trendline.name = Some("Fit".to_string());
+    trendline.display_equation = true;
+    trendline.display_r_squared = true;
+    trendline.line = Some(ChartLine {
+        color: FormatColor::Red,
+        dash_type: ChartDashType::Dash,
+        ..ChartLine::new()
+    });
+    series.set_trendline_struct(&trendline)?;
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_trendline_struct.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(chart_xml.contains("<c:name>Fit</c:name>"));
+    assert!(chart_xml.contains(r#"<c:trendlineType val="linear"/>"#));
+    assert!(chart_xml.contains(r#"<a:srgbClr val="FF0000"/>"#));
+    assert!(chart_xml.contains(r#"<a:prstDash val="dash"/>"#));
+    assert!(chart_xml.contains(r#"<c:dispRSqr val="1"/>"#));
+    assert!(chart_xml.contains(r#"<c:dispEq val="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_write_formula_str_result() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/write_formula_str_result.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_formula_str(0, 0, "=CONCATENATE(\"a\",\"b\")", None, "ab")?;
+    sheet.write_formula_str(1, 0, "=A1=\"ab\"", None, "TRUE")?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(
+        "../target/write_formula_str_result.xlsx",
+        "xl/worksheets/sheet1.xml",
+    );
+    assert!(sheet_xml.contains(r#"<c r="A1" t="str"><f>CONCATENATE("a","b")</f><v>ab</v></c>"#));
+    assert!(sheet_xml.contains(r#"<c r="A2" t="str"><f>A1="ab"</f><v>TRUE</v></c>"#));
+    Ok(())
+}
+
+#[test]
+fn test_parse_cell_ref() {
+    use crate::worksheet::parse_cell_ref;
+    assert_eq!(parse_cell_ref("A1").unwrap(), (0, 0));
+    assert_eq!(parse_cell_ref("B2").unwrap(), (1, 1));
+    assert_eq!(parse_cell_ref("$AA$10").unwrap(), (9, 26));
+    assert_eq!(parse_cell_ref("xfd1048576").unwrap(), (1_048_575, 16_383));
+    for invalid in &[
+        "", "A", "12", "A0", "1A", "A1B", "XFE1", "A1048577", "A-1", "A 1",
+    ] {
+        assert!(parse_cell_ref(invalid).is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_data_validation_cell_at() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/data_validation_cell_at.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let mut validation = DataValidation::new(
+        DataValidationType::List,
+        DataValidationCriteria::Between,
+        DataValidationErrorType::Stop,
+    );
+    validation.value_list = Some(vec!["open".to_string(), "closed".to_string()]);
+    sheet.data_validation_cell_at("B2", &validation)?;
+    assert!(sheet.data_validation_cell_at("2B", &validation).is_err());
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(
+        "../target/data_validation_cell_at.xlsx",
+        "xl/worksheets/sheet1.xml",
+    );
+    assert!(sheet_xml.contains(r#"sqref="B2""#));
+    assert!(sheet_xml.contains("<formula1>\"open,closed\"</formula1>"));
+    Ok(())
+}
+
+#[test]
+fn test_chart_axis_gridlines_only() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_axis_gridlines_only.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.y_axis().set_major_gridlines_visible(false);
+    chart.y_axis().set_gridlines_only();
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_axis_gridlines_only.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    let val_axis = &chart_xml[chart_xml.find("<c:valAx>").unwrap()..];
+    assert!(val_axis.contains(r#"<c:delete val="1"/>"#));
+    assert!(val_axis.contains("<c:majorGridlines/>"));
+    Ok(())
+}
+
+#[test]
+fn test_close_with_progress_small_workbook() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/close_with_progress_small.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "Hello", None)?;
+    let mut calls = 0;
+    let mut last = 0;
+    let size = workbook.close_with_progress(|bytes| {
+        calls += 1;
+        last = bytes;
+    })?;
+    assert!(calls >= 1);
+    assert_eq!(last, size);
+    assert_eq!(
+        size,
+        std::fs::metadata("../target/close_with_progress_small.xlsx")
+            .unwrap()
+            .len()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_font_outline_shadow() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/font_outline_shadow.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let legacy = workbook.add_format().set_font_outline().set_font_shadow();
+    sheet.write_string(0, 0, "Legacy", Some(&legacy))?;
+    workbook.close()?;
+
+    let styles_xml =
+        test_util::read_xlsx_entry("../target/font_outline_shadow.xlsx", "xl/styles.xml");
+    assert!(styles_xml.contains("<font><outline/><shadow/>"));
+    Ok(())
+}
+
+#[test]
+fn test_add_worksheet_opt() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/add_worksheet_opt.xlsx");
+    let mut first = workbook.add_worksheet(None)?;
+    first.write_string(0, 0, "Visible", None)?;
+    let mut sheet = workbook.add_worksheet_opt(
+        Some("Data"),
+        &WorksheetInitOptions {
+            zoom: Some(120),
+            right_to_left: true,
+            hidden: true,
+            ..WorksheetInitOptions::default()
+        },
+    )?;
+    sheet.write_string(0, 0, "Hidden", None)?;
+    workbook.close()?;
+
+    let workbook_xml =
+        test_util::read_xlsx_entry("../target/add_worksheet_opt.xlsx", "xl/workbook.xml");
+    assert!(workbook_xml.contains(r#"<sheet name="Data" sheetId="2" state="hidden" r:id="rId2"/>"#));
+    let sheet_xml = test_util::read_xlsx_entry(
+        "../target/add_worksheet_opt.xlsx",
+        "xl/worksheets/sheet2.xml",
+    );
+    assert!(sheet_xml.contains(
+        r#"<sheetView rightToLeft="1" zoomScale="120" zoomScaleNormal="120" workbookViewId="0"/>"#
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_border_side_colors() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/border_side_colors.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let format = workbook
+        .add_format()
+        .set_border(FormatBorder::Thin)
+        .set_border_top_color(FormatColor::Red)
+        .set_border_bottom_color(FormatColor::Blue);
+    sheet.write_string(1, 1, "Total", Some(&format))?;
+    workbook.close()?;
+
+    let styles_xml =
+        test_util::read_xlsx_entry("../target/border_side_colors.xlsx", "xl/styles.xml");
+    assert!(styles_xml.contains(r#"<top style="thin"><color rgb="FFFF0000"/></top>"#));
+    assert!(styles_xml.contains(r#"<bottom style="thin"><color rgb="FF0000FF"/></bottom>"#));
+    assert!(styles_xml.contains(r#"<left style="thin"><color auto="1"/></left>"#));
+    Ok(())
+}
+
+#[test]
+fn test_chart_series_gap_overlap_range() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_series_gap_overlap_range.xlsx");
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.set_series_gap(0)?;
+    chart.set_series_gap(500)?;
+    assert!(chart.set_series_gap(501).is_err());
+    chart.set_series_overlap(-100)?;
+    chart.set_series_overlap(100)?;
+    assert!(chart.set_series_overlap(-101).is_err());
+    assert!(chart.set_series_overlap(101).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_write_date_default_format() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/write_date_default_format.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_date(0, 0, &DateTime::date(2024, 3, 15), None)?;
+    sheet.write_date(1, 0, &DateTime::date(2024, 3, 16), None)?;
+    let first = sheet.get_cell_format(0, 0).unwrap();
+    assert_eq!(first.format, sheet.get_cell_format(1, 0).unwrap().format);
+    workbook.close()?;
+
+    let styles_xml =
+        test_util::read_xlsx_entry("../target/write_date_default_format.xlsx", "xl/styles.xml");
+    assert!(styles_xml.contains(r#"<numFmt numFmtId="164" formatCode="yyyy-mm-dd"/>"#));
+    let sheet_xml = test_util::read_xlsx_entry(
+        "../target/write_date_default_format.xlsx",
+        "xl/worksheets/sheet1.xml",
+    );
+    assert!(sheet_xml.contains(r#"<c r="A1" s="1"><v>45366</v></c>"#));
+    Ok(())
+}
+
+#[test]
+fn test_write_url_max_length() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/write_url_max_length.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let base = "https://example.com/";
+    let longest = format!("{}{}", base, "a".repeat(2079 - base.len()));
+    sheet.write_url(0, 0, &longest, None)?;
+    let too_long = format!("{}{}", base, "a".repeat(3000 - base.len()));
+    assert!(sheet.write_url(1, 0, &too_long, None).is_err());
+    let internal = format!("internal:'{}'!A1", "S".repeat(2074));
+    sheet.write_url(2, 0, &internal, None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chartsheet_zoom() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chartsheet_zoom.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    let mut chartsheet = workbook.add_chartsheet(Some("Chart"))?;
+    chartsheet.set_chart(&chart)?;
+    chartsheet.set_zoom(200);
+    assert!(workbook.add_chartsheet(Some("Chart")).is_err());
+    workbook.close()?;
+
+    let chartsheet_xml = test_util::read_xlsx_entry(
+        "../target/chartsheet_zoom.xlsx",
+        "xl/chartsheets/sheet1.xml",
+    );
+    assert!(chartsheet_xml.contains(r#"zoomScale="200""#));
+    Ok(())
+}
+
+#[test]
+fn test_chart_size() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_size.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    assert_eq!(chart.default_size(), (480, 288));
+    chart.set_size(960, 144);
+    assert_eq!(chart.default_size(), (960, 144));
+    sheet.insert_chart(0, 0, &chart)?;
+    workbook.close()?;
+
+    let drawing_xml =
+        test_util::read_xlsx_entry("../target/chart_size.xlsx", "xl/drawings/drawing1.xml");
+    // 960x144 pixels are 15 default columns of 64 pixels and 7.2 default rows of 20 pixels.
+    assert!(drawing_xml.contains(
+        "<xdr:to><xdr:col>15</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>7</xdr:row><xdr:rowOff>38100</xdr:rowOff></xdr:to>"
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_doc_properties_all_fields() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/doc_properties_all_fields.xlsx");
+    let properties = DocProperties::new()
+        .set_title("The title")
+        .set_subject("The subject")
+        .set_author("The author")
+        .set_manager("The manager")
+        .set_company("The company")
+        .set_category("The category")
+        .set_keywords("key, words")
+        .set_comments("The comments")
+        .set_status("The status")
+        .set_hyperlink_base("https://example.com/");
+    assert_eq!(properties.manager.as_deref(), Some("The manager"));
+    workbook.set_properties(&properties)?;
+    workbook.add_worksheet(None)?;
+    workbook.close()?;
+
+    let core_xml = test_util::read_xlsx_entry(
+        "../target/doc_properties_all_fields.xlsx",
+        "docProps/core.xml",
+    );
+    for expected in &[
+        "<dc:title>The title</dc:title>",
+        "<dc:subject>The subject</dc:subject>",
+        "<dc:creator>The author</dc:creator>",
+        "<cp:keywords>key, words</cp:keywords>",
+        "<dc:description>The comments</dc:description>",
+        "<cp:category>The category</cp:category>",
+        "<cp:contentStatus>The status</cp:contentStatus>",
+    ] {
+        assert!(core_xml.contains(expected), "{}", expected);
+    }
+    let app_xml = test_util::read_xlsx_entry(
+        "../target/doc_properties_all_fields.xlsx",
+        "docProps/app.xml",
+    );
+    for expected in &[
+        "<Manager>The manager</Manager>",
+        "<Company>The company</Company>",
+        "<HyperlinkBase>https://example.com/</HyperlinkBase>",
+    ] {
+        assert!(app_xml.contains(expected), "{}", expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merge_title() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/merge_title.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.merge_title(0, 0, 0, 5, "Sales report")?;
+    sheet.merge_title(2, 0, 3, 5, "Details")?;
+    assert_eq!(
+        sheet.get_cell_format(0, 0).unwrap().format,
+        sheet.get_cell_format(2, 0).unwrap().format
+    );
+    workbook.close()?;
+
+    let styles_xml = test_util::read_xlsx_entry("../target/merge_title.xlsx", "xl/styles.xml");
+    assert!(styles_xml.contains(r#"<alignment horizontal="center" vertical="center"/>"#));
+    let sheet_xml =
+        test_util::read_xlsx_entry("../target/merge_title.xlsx", "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<mergeCell ref="A1:F1"/>"#));
+    assert!(sheet_xml.contains(r#"<mergeCell ref="A3:F4"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_chart_smooth_with_markers() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_smooth_with_markers.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, i.into(), None)?;
+        sheet.write_number(i, 1, (i * i % 7).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::ScatterSmooth);
+    let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    series.set_smooth(true);
+    series.set_marker_type(ChartMarkerType::MarkerCircle);
+    series.set_marker_size(7);
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_smooth_with_markers.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(chart_xml.contains(r#"<c:scatterStyle val="smoothMarker"/>"#));
+    assert!(chart_xml.contains(r#"<c:marker><c:symbol val="circle"/><c:size val="7"/></c:marker>"#));
+    assert!(chart_xml.contains(r#"<c:smooth val="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_chart_axis_reverse_min_max() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_axis_reverse_min_max.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 20).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Line);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    let mut y_axis = chart.y_axis();
+    y_axis.set_reverse();
+    y_axis.set_min(0.0);
+    y_axis.set_max(100.0);
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_axis_reverse_min_max.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    let val_axis = &chart_xml[chart_xml.find("<c:valAx>").unwrap()..];
+    assert!(val_axis.contains(
+        r#"<c:scaling><c:orientation val="maxMin"/><c:max val="100"/><c:min val="0"/></c:scaling>"#
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_workbook_new_paths() -> Result<(), XlsxError> {
+    let path = std::path::PathBuf::from("../target").join("workbook_new_pathbuf.xlsx");
+    let workbook = Workbook::new(&path);
+    workbook.add_worksheet(None)?;
+    workbook.close()?;
+    assert!(path.exists());
+
+    let workbook = Workbook::new("../target/workbook_new_str.xlsx");
+    workbook.add_worksheet(None)?;
+    workbook.close()?;
+
+    let workbook = Workbook::new_with_options(
+        std::path::Path::new("../target/workbook_new_path_options.xlsx"),
+        &WorkbookOptions::default(),
+    );
+    workbook.add_worksheet(None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_set_column_auto() -> Result<(), XlsxError> {
+    use crate::worksheet::estimate_column_width;
+    assert_eq!(estimate_column_width(&[], 11.0), 1.0);
+    assert_eq!(estimate_column_width(&["abc", "abcdef"], 11.0), 7.0);
+    assert_eq!(estimate_column_width(&["abcdef"], 22.0), 13.0);
+    assert_eq!(estimate_column_width(&["ab\nabcd"], 11.0), 5.0);
+    assert_eq!(estimate_column_width(&[&"x".repeat(1000)], 11.0), 255.0);
+
+    let workbook = Workbook::new("../target/set_column_auto.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let labels = ["Region", "Customer name", "Total"];
+    for (row, label) in labels.iter().enumerate() {
+        sheet.write_string(row as WorksheetRow, 0, label, None)?;
+    }
+    sheet.set_column_auto(0, &labels, 11.0)?;
+    workbook.close()?;
+
+    let sheet_xml =
+        test_util::read_xlsx_entry("../target/set_column_auto.xlsx", "xl/worksheets/sheet1.xml");
+    // A width of 14 characters, written by libxlsxwriter with the padding of the cell.
+    assert!(sheet_xml.contains(r#"<col min="1" max="1" width="14.7109375" customWidth="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_chart_legend_position_none() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_legend_position_none.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+        sheet.write_number(i, 1, (i * 10 + 2).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    chart.delete_legend_series(&[0])?;
+    chart.set_legend_position(ChartLegendPosition::None);
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_legend_position_none.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(!chart_xml.contains("<c:legend>"));
+    assert!(!chart_xml.contains("<c:legendEntry>"));
+    Ok(())
+}
+
+#[test]
+fn test_chart_delete_all_legend_series() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_delete_all_legend_series.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+        sheet.write_number(i, 1, (i * 10 + 2).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    assert!(chart.delete_legend_series(&[-1]).is_err());
+    chart.delete_legend_series(&[0, 1])?;
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_delete_all_legend_series.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(chart_xml.contains("<c:legend>"));
+    assert!(
+        chart_xml.contains(r#"<c:legendEntry><c:idx val="0"/><c:delete val="1"/></c:legendEntry>"#)
+    );
+    assert!(
+        chart_xml.contains(r#"<c:legendEntry><c:idx val="1"/><c:delete val="1"/></c:legendEntry>"#)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chart_labels_num_format_linked() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_labels_num_format_linked.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let currency = workbook.add_format().set_num_format("$#,##0.00");
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 1250).into(), Some(&currency))?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    let mut linked = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    linked.set_labels();
+    linked.set_labels_num_format("0")?;
+    linked.set_labels_num_format_linked();
+    let mut custom = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    custom.set_labels();
+    custom.set_labels_num_format("$#,##0")?;
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_labels_num_format_linked.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(!chart_xml.contains(r#"formatCode="0""#));
+    assert_eq!(
+        chart_xml
+            .matches(r#"<c:numFmt formatCode="$#,##0" sourceLinked="0"/>"#)
+            .count(),
+        1
+    );
+    Ok(())
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_insert_image_jpeg_quality() -> Result<(), XlsxError> {
+    // A detailed image saved at the maximum quality, as exported by many design tools.
+    let pixels = ::image::RgbImage::from_fn(256, 256, |x, y| {
+        ::image::Rgb([(x * y % 256) as u8, (x ^ y) as u8, ((x + y) / 2) as u8])
+    });
+    let mut source = Vec::new();
+    ::image::codecs::jpeg::JpegEncoder::new_with_quality(&mut source, 100)
+        .encode_image(&pixels)
+        .unwrap();
+    std::fs::write("../target/jpeg_quality_source.jpg", &source).unwrap();
+
+    let workbook = Workbook::new("../target/insert_image_jpeg_quality.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.insert_image_opt(
+        0,
+        0,
+        "../target/jpeg_quality_source.jpg",
+        &ImageOptions {
+            jpeg_quality: Some(60),
+            ..ImageOptions::default()
+        },
+    )?;
+    assert!(sheet
+        .insert_image_buffer_opt(
+            20,
+            0,
+            &source,
+            &ImageOptions {
+                jpeg_quality: Some(0),
+                ..ImageOptions::default()
+            }
+        )
+        .is_err());
+    workbook.close()?;
+
+    let stored = test_util::read_xlsx_entry_bytes(
+        "../target/insert_image_jpeg_quality.xlsx",
+        "xl/media/image1.jpeg",
+    );
+    assert!(stored.starts_with(&[0xff, 0xd8]));
+    assert!(stored.len() < source.len());
+    Ok(())
+}
+
+#[cfg(not(feature = "image"))]
+#[test]
+fn test_insert_image_jpeg_quality_without_feature() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/insert_image_jpeg_quality_without_feature.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let options = ImageOptions {
+        jpeg_quality: Some(60),
+        ..ImageOptions::default()
+    };
+    // PNG images are never recompressed, JPEG images need the `image` feature.
+    sheet.insert_image_opt(0, 0, "../images/simple1.png", &options)?;
+    assert!(sheet
+        .insert_image_buffer_opt(10, 0, &[0xff, 0xd8, 0xff, 0xe0], &options)
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_default_comment_author() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new("../target/default_comment_author.xlsx");
+    assert!(workbook.set_default_comment_author("Ru\0by").is_err());
+    workbook.set_default_comment_author("Ruby")?;
+    let mut sheet1 = workbook.add_worksheet(None)?;
+    sheet1.write_comment(0, 0, "First sheet")?;
+    let mut sheet2 = workbook.add_worksheet(None)?;
+    sheet2.write_comment(1, 1, "Second sheet")?;
+    let mut sheet3 = workbook.add_worksheet(None)?;
+    sheet3.set_comments_author("Moss")?;
+    sheet3.write_comment(0, 0, "Third sheet")?;
+    workbook.close()?;
+
+    for comments in &["xl/comments1.xml", "xl/comments2.xml"] {
+        let comments_xml =
+            test_util::read_xlsx_entry("../target/default_comment_author.xlsx", comments);
+        assert!(comments_xml.contains("<author>Ruby</author>"));
+    }
+    let comments_xml =
+        test_util::read_xlsx_entry("../target/default_comment_author.xlsx", "xl/comments3.xml");
+    assert!(comments_xml.contains("<author>Moss</author>"));
+    assert!(!comments_xml.contains("<author>Ruby</author>"));
+    Ok(())
+}
+
+#[test]
+fn test_set_row_overrides_default_row() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/set_row_overrides_default_row.xlsx");
+    let mut default_first = workbook.add_worksheet(None)?;
+    default_first.set_default_row(24.0, false);
+    default_first.set_row(2, 40.0, None)?;
+    default_first.write_string(0, 0, "default first", None)?;
+    let mut row_first = workbook.add_worksheet(None)?;
+    row_first.set_row(2, 40.0, None)?;
+    row_first.set_default_row(24.0, false);
+    row_first.write_string(0, 0, "row first", None)?;
+    workbook.close()?;
+
+    for sheet in &["xl/worksheets/sheet1.xml", "xl/worksheets/sheet2.xml"] {
+        let sheet_xml =
+            test_util::read_xlsx_entry("../target/set_row_overrides_default_row.xlsx", sheet);
+        assert!(sheet_xml.contains(r#"<sheetFormatPr defaultRowHeight="24" customHeight="1"/>"#));
+        assert!(sheet_xml.contains(r#"<row r="3" spans="1:1" ht="40" customHeight="1"/>"#));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_chart_series_fill_none() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_series_fill_none.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Area);
+    let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    series.set_fill_none();
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_series_fill_none.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(chart_xml.contains("<c:spPr><a:noFill/></c:spPr>"));
+    Ok(())
+}
+
+#[test]
+fn test_excel_writer_report() -> Result<(), XlsxError> {
+    let mut writer = ExcelWriter::new("../target/excel_writer_report.xlsx");
+    writer.sheet("Report")?;
+    writer.header_row(&["Product", "Units sold"])?;
+    let rows = [("Apples", 120.0), ("Oranges", 80.0), ("Pears", 45.0)];
+    for (i, (product, units)) in rows.iter().enumerate() {
+        let row = i as WorksheetRow + 1;
+        writer.write(row, 0, *product)?;
+        writer.write(row, 1, units)?;
+    }
+    writer.sheet("Notes")?;
+    writer.write(0, 0, "Units are boxes")?;
+    // Selecting an existing worksheet doesn't add a new one.
+    writer.sheet("Report")?;
+    writer.write(4, 0, "Total")?;
+    assert_eq!(writer.workbook().worksheets().count(), 2);
+    writer.finish()?;
+
+    let path = "../target/excel_writer_report.xlsx";
+    let workbook_xml = test_util::read_xlsx_entry(path, "xl/workbook.xml");
+    assert!(workbook_xml.contains(r#"<sheet name="Report" sheetId="1" r:id="rId1"/>"#));
+    assert!(workbook_xml.contains(r#"<sheet name="Notes" sheetId="2" r:id="rId2"/>"#));
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<c r="B2"><v>120</v></c>"#));
+    assert!(sheet_xml.contains(r#"<c r="A5" t="s">"#));
+    assert!(sheet_xml.contains(r#"<col min="2" max="2" width="11.7109375" customWidth="1"/>"#));
+    let styles_xml = test_util::read_xlsx_entry(path, "xl/styles.xml");
+    assert!(styles_xml.contains("<b/>"));
+    Ok(())
+}
+
+#[test]
+fn test_rich_string_max_length() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/rich_string_max_length.xlsx");
+    let bold = workbook.add_format().set_bold();
+    let mut sheet = workbook.add_worksheet(None)?;
+    let head = "a".repeat(32760);
+    let error = sheet
+        .write_rich_string(0, 0, &[(&head, None), ("12345678", Some(&bold))], None)
+        .unwrap_err();
+    assert!(error.is_rich_string_too_long());
+    assert!(!error.is_too_many_fragments());
+    // A text of 32,767 characters is too long once the markup of the bold font is added
+    let error = sheet
+        .write_rich_string(0, 0, &[(&head, None), ("1234567", Some(&bold))], None)
+        .unwrap_err();
+    assert!(error.is_rich_string_too_long());
+    let head = "a".repeat(32000);
+    sheet.write_rich_string(0, 0, &[(&head, None), ("1234567", Some(&bold))], None)?;
+
+    let fragments: Vec<(&str, Option<&Format>)> = (0..255)
+        .map(|i| ("x", if i % 2 == 0 { None } else { Some(&bold) }))
+        .collect();
+    let error = sheet.write_rich_string(1, 0, &fragments, None).unwrap_err();
+    assert!(error.is_too_many_fragments());
+    assert!(!error.is_rich_string_too_long());
+    sheet.write_rich_string(1, 0, &fragments[..254], None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_axis_label_align_multi_level() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_axis_label_align_multi_level.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..8 {
+        if i % 4 == 0 {
+            sheet.write_string(i, 0, &(2020 + i / 4).to_string(), None)?;
+        }
+        sheet.write_string(i, 1, &format!("Q{}", i % 4 + 1), None)?;
+        sheet.write_number(i, 2, (i * 10 + 5).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(Some("=Sheet1!$A$1:$B$8"), Some("=Sheet1!$C$1:$C$8"))?;
+    chart
+        .x_axis()
+        .set_label_align(ChartAxisLabelAlignment::Right);
+    sheet.insert_chart(1, 4, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_axis_label_align_multi_level.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(chart_xml.contains("<c:f>Sheet1!$A$1:$B$8</c:f>"));
+    assert!(chart_xml.contains(r#"<c:lblAlgn val="r"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_workbook_new_no_overwrite() -> Result<(), XlsxError> {
+    let path = "../target/workbook_new_no_overwrite.xlsx";
+    let _ = std::fs::remove_file(path);
+    let workbook = Workbook::new_no_overwrite(path)?;
+    workbook.add_worksheet(None)?;
+    workbook.close()?;
+    assert!(std::path::Path::new(path).exists());
+
+    let error = Workbook::new_no_overwrite(path).err().unwrap();
+    assert!(error.is_file_exists());
+    assert_eq!(error.to_string(), "File already exists");
+    Ok(())
+}
+
+#[test]
+fn test_chart_axis_multi_line_name() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_axis_multi_line_name.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.x_axis().set_name("Fiscal\r\nquarter")?;
+    chart.y_axis().set_name("Revenue\n(millions)")?;
+    sheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(
+        "../target/chart_axis_multi_line_name.xlsx",
+        "xl/charts/chart1.xml",
+    );
+    assert!(chart_xml.contains("<a:t>Fiscal\nquarter</a:t>"));
+    assert!(chart_xml.contains("<a:t>Revenue\n(millions)</a:t>"));
+    Ok(())
+}
+
+#[test]
+fn test_print_debug_layout() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/print_debug_layout.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "Draft", None)?;
+    sheet.print_debug_layout();
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(
+        "../target/print_debug_layout.xlsx",
+        "xl/worksheets/sheet1.xml",
+    );
+    assert!(sheet_xml.contains(r#"<printOptions headings="1" gridLines="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_chart_width_keeps_aspect_ratio() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/chart_width_keeps_aspect_ratio.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.set_width(960);
+    assert_eq!(chart.default_size(), (960, 576));
+    chart.set_width(500);
+    assert_eq!(chart.default_size(), (500, 300));
+    sheet.insert_chart(0, 0, &chart)?;
+
+    let mut tall_chart = workbook.add_chart(ChartType::Line);
+    tall_chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    tall_chart.set_height(100);
+    assert_eq!(tall_chart.default_size(), (167, 100));
+    tall_chart.set_size(400, 400);
+    tall_chart.set_width(200);
+    assert_eq!(tall_chart.default_size(), (200, 400));
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_ignore_errors_column() -> Result<(), XlsxError> {
+    let path = "../target/ignore_errors_column.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(1, 1, "ID", None)?;
+    for i in 2..12 {
+        sheet.write_string(i, 1, &format!("{:05}", i), None)?;
+        sheet.write_number(i, 2, i.into(), None)?;
+    }
+    sheet.write_number(20, 2, 1.0, None)?;
+    sheet.ignore_errors_column(IgnoreError::NumberStoredAsText, 1)?;
+    sheet.ignore_errors_column(IgnoreError::EvalError, 5)?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"sqref="B2:B12" numberStoredAsText="1""#));
+    assert!(sheet_xml.contains(r#"sqref="F1:F1048576" evalError="1""#));
+    Ok(())
+}
+
+#[test]
+fn test_chart_font_color_only_keeps_default_size() -> Result<(), XlsxError> {
+    let path = "../target/chart_font_color_only.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10 + 10).into(), None)?;
+    }
+    let font = ChartFont::new().set_color(FormatColor::Red);
+    assert_eq!(font.size, 0.0);
+    assert_eq!(font.to_c_struct()?.chart_font.size, 0.0);
+    let mut chart = workbook.add_chart(ChartType::Column);
+    let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    series.set_labels();
+    series.set_labels_font(&font)?;
+    chart.set_title_name("Totals")?;
+    chart.set_title_name_font(&ChartFont::new().set_size(14.0).set_italic())?;
+    sheet.insert_chart(0, 2, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(path, "xl/charts/chart1.xml");
+    assert!(chart_xml.contains(r#"<a:srgbClr val="FF0000"/>"#));
+    assert!(chart_xml.contains(r#"sz="1400""#));
+    assert!(!chart_xml.contains(r#"sz="0""#));
+    Ok(())
+}
+
+#[test]
+fn test_font_color_automatic() -> Result<(), XlsxError> {
+    let path = "../target/font_color_automatic.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let dark = workbook
+        .add_format()
+        .set_bold()
+        .set_bg_color(FormatColor::Navy)
+        .set_font_color(FormatColor::Red)
+        .set_font_color(FormatColor::Automatic);
+    sheet.write_string(0, 0, "Automatic on dark", Some(&dark))?;
+    workbook.close()?;
+
+    let styles_xml = test_util::read_xlsx_entry(path, "xl/styles.xml");
+    assert!(styles_xml.contains(r#"<font><b/><sz val="11"/><color theme="1"/>"#));
+    assert!(!styles_xml.contains(r#"rgb="FFFF0000""#));
+    assert!(styles_xml.contains(r#"<fgColor rgb="FF000080"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_merge_range_rich_wrapped() -> Result<(), XlsxError> {
+    let path = "../target/merge_range_rich_wrapped.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let bold = workbook.add_format().set_bold();
+    assert!(sheet
+        .merge_range_rich_wrapped(0, 0, 1, 3, &[("", Some(&bold))], None)
+        .is_err());
+    sheet.set_row(0, 30., None)?;
+    sheet.merge_range_rich_wrapped(
+        0,
+        0,
+        1,
+        3,
+        &[("Annual report\n", Some(&bold)), ("2024", None)],
+        None,
+    )?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert_eq!(sheet_xml.matches("<mergeCell ").count(), 1);
+    assert!(sheet_xml.contains(r#"<mergeCell ref="A1:D2"/>"#));
+    let styles_xml = test_util::read_xlsx_entry(path, "xl/styles.xml");
+    assert!(styles_xml.contains(r#"<alignment wrapText="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_workbook_closed_once() -> Result<(), XlsxError> {
+    let close_calls = || workbook::CLOSE_CALLS.with(|x| x.get());
+    let before = close_calls();
+    let workbook = Workbook::new("../target/workbook_closed_once.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "closed", None)?;
+    // close() consumes the workbook, which is then dropped without being closed again.
+    workbook.close()?;
+    assert_eq!(close_calls(), before + 1);
+
+    let workbook = Workbook::new("../target/workbook_dropped_once.xlsx");
+    workbook.add_worksheet(None)?;
+    drop(workbook);
+    assert_eq!(close_calls(), before + 2);
+
+    let workbook = Workbook::new("../target/workbook_closed_with_progress_once.xlsx");
+    workbook.add_worksheet(None)?;
+    workbook.close_with_progress(|_| {})?;
+    assert_eq!(close_calls(), before + 3);
+    Ok(())
+}
+
+#[cfg(feature = "debug")]
+#[test]
+fn test_close_and_read_chart_part() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/close_and_read_chart_part.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Bar);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    sheet.insert_chart(0, 2, &chart)?;
+    let chart_xml = workbook.close_and_read_part("chart1.xml")?;
+    assert!(chart_xml.contains("<c:barChart>"));
+    assert!(chart_xml.contains("<c:f>Sheet1!$A$1:$A$5</c:f>"));
+
+    let xlsx = std::fs::read("../target/close_and_read_chart_part.xlsx").unwrap();
+    assert_eq!(xlsx_part_xml(&xlsx, "xl/charts/chart1.xml")?, chart_xml);
+    assert!(xlsx_part_xml(&xlsx, "chart2.xml").is_err());
+    assert!(xlsx_part_xml(b"not a zip file", "chart1.xml").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_merge_range_with_hidden_row() -> Result<(), XlsxError> {
+    let path = "../target/merge_range_with_hidden_row.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let mut options = RowColOptions {
+        hidden: 1,
+        level: 0,
+        collapsed: 0,
+    };
+    sheet.set_row_opt(2, 15., None, &mut options)?;
+    let center = workbook
+        .add_format()
+        .set_align(FormatAlignment::VerticalCenter);
+    sheet.merge_range(0, 0, 4, 0, "Merged", Some(&center))?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<mergeCell ref="A1:A5"/>"#));
+    assert!(sheet_xml.contains(r#"<row r="3" spans="1:1" hidden="1">"#));
+    // The hidden row keeps the blank cell of the merged range.
+    assert!(sheet_xml.contains(r#"<c r="A3" s="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_identical_formats_share_one_xf() -> Result<(), XlsxError> {
+    let path = "../target/identical_formats_share_one_xf.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..10_000u32 {
+        let format = workbook
+            .add_format()
+            .set_bold()
+            .set_font_color(FormatColor::Blue)
+            .set_num_format("#,##0.00");
+        sheet.write_number(i / 10, (i % 10) as WorksheetCol, i.into(), Some(&format))?;
+    }
+    workbook.close()?;
+
+    let styles_xml = test_util::read_xlsx_entry(path, "xl/styles.xml");
+    // The default format and the 10,000 identical formats.
+    assert!(styles_xml.contains(r#"<cellXfs count="2">"#));
+    assert!(styles_xml.contains(r#"<numFmts count="1">"#));
+    Ok(())
+}
+
+#[test]
+fn test_chart_series_set_from_config() -> Result<(), XlsxError> {
+    let path = "../target/chart_series_set_from_config.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(Some("Data"))?;
+    sheet.write_string(0, 2, "Revenue", None)?;
+    for i in 1..5 {
+        sheet.write_string(i, 1, &format!("Q{}", i), None)?;
+        sheet.write_number(i, 2, (i * 100).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Line);
+    let mut series = chart.add_series(None, None)?;
+    assert!(series
+        .set_from_config(
+            "Data",
+            &CellRange::new(0, 2, 1, 2),
+            &CellRange::new(1, 1, 4, 1),
+            &CellRange::new(1, 2, 4, 2),
+        )
+        .is_err());
+    series.set_from_config(
+        "Data",
+        &CellRange::new(0, 2, 0, 2),
+        &CellRange::new(1, 1, 4, 1),
+        &CellRange::new(1, 2, 4, 2),
+    )?;
+    sheet.insert_chart(6, 0, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(path, "xl/charts/chart1.xml");
+    assert!(chart_xml.contains("<c:f>Data!$C$1</c:f>"));
+    assert!(chart_xml.contains("<c:f>Data!$B$2:$B$5</c:f>"));
+    assert!(chart_xml.contains("<c:f>Data!$C$2:$C$5</c:f>"));
+    Ok(())
+}
+
+#[test]
+fn test_set_paper_other_code() -> Result<(), XlsxError> {
+    let path = "../target/set_paper_other_code.xlsx";
+    let workbook = Workbook::new(path);
+    let mut a4 = workbook.add_worksheet(None)?;
+    a4.set_paper(PaperType::A4);
+    let mut a2 = workbook.add_worksheet(None)?;
+    a2.set_paper(PaperType::Other(66));
+    workbook.close()?;
+
+    let a4_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(a4_xml.contains(r#"<pageSetup paperSize="9""#));
+    let a2_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet2.xml");
+    assert!(a2_xml.contains(r#"<pageSetup paperSize="66""#));
+    Ok(())
+}
+
+#[test]
+fn test_write_auto() -> Result<(), XlsxError> {
+    use crate::worksheet::{infer_auto_value, AutoValue};
+    assert_eq!(infer_auto_value("42"), AutoValue::Number(42.));
+    assert_eq!(infer_auto_value(" -3.5 "), AutoValue::Number(-3.5));
+    assert_eq!(infer_auto_value("1e6"), AutoValue::Number(1e6));
+    assert_eq!(infer_auto_value("00123"), AutoValue::Number(123.));
+    assert_eq!(infer_auto_value("inf"), AutoValue::String);
+    assert_eq!(infer_auto_value("NaN"), AutoValue::String);
+    assert_eq!(infer_auto_value("1e999"), AutoValue::String);
+    assert_eq!(infer_auto_value("-1e999"), AutoValue::String);
+    assert_eq!(infer_auto_value("1.2.3"), AutoValue::String);
+    assert_eq!(infer_auto_value("TRUE"), AutoValue::Boolean(true));
+    assert_eq!(infer_auto_value("false"), AutoValue::Boolean(false));
+    assert_eq!(
+        infer_auto_value("2024-02-29"),
+        AutoValue::Date(DateTime::date(2024, 2, 29))
+    );
+    assert_eq!(
+        infer_auto_value("2024-03-15T08:30"),
+        AutoValue::DateTime(DateTime::new(2024, 3, 15, 8, 30, 0.))
+    );
+    assert_eq!(
+        infer_auto_value("2024-03-15 23:59:30.5"),
+        AutoValue::DateTime(DateTime::new(2024, 3, 15, 23, 59, 30.5))
+    );
+    assert_eq!(infer_auto_value("2023-02-29"), AutoValue::String);
+    assert_eq!(infer_auto_value("2024-13-01"), AutoValue::String);
+    assert_eq!(infer_auto_value("1899-12-31"), AutoValue::String);
+    assert_eq!(infer_auto_value("2024-03-15T24:00"), AutoValue::String);
+    assert_eq!(infer_auto_value("2024-3-15"), AutoValue::String);
+    assert_eq!(infer_auto_value("North"), AutoValue::String);
+    assert_eq!(infer_auto_value(""), AutoValue::String);
+
+    let path = "../target/write_auto.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for (col, text) in [
+        "1250.5",
+        "2024-03-15",
+        "2024-03-15T08:30:00",
+        "TRUE",
+        "North",
+    ]
+    .iter()
+    .enumerate()
+    {
+        sheet.write_auto(0, col as WorksheetCol, text, None)?;
+    }
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<c r="A1"><v>1250.5</v></c>"#));
+    assert!(sheet_xml.contains(r#"<c r="B1" s="1"><v>45366</v></c>"#));
+    assert!(sheet_xml.contains(r#"<c r="C1" s="2"><v>45366.35416666666</v></c>"#));
+    assert!(sheet_xml.contains(r#"<c r="D1" t="b"><v>1</v></c>"#));
+    assert!(sheet_xml.contains(r#"<c r="E1" t="s"><v>0</v></c>"#));
+    Ok(())
+}
+
+#[test]
+fn test_outline_settings_symbols_above() -> Result<(), XlsxError> {
+    let path = "../target/outline_settings_symbols_above.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let mut options = RowColOptions {
+        hidden: 0,
+        level: 1,
+        collapsed: 0,
+    };
+    sheet.write_string(0, 0, "Total", None)?;
+    for row in 1..4 {
+        sheet.set_row_opt(row, 15.0, None, &mut options)?;
+        sheet.write_number(row, 0, row as f64, None)?;
+    }
+    sheet.set_outline_settings(&OutlineSettings::new().set_symbols_below(false));
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<outlinePr summaryBelow="0"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_close_returns_file_size() -> Result<(), XlsxError> {
+    let path = "../target/close_returns_file_size.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "Hello Excel", None)?;
+    let size = workbook.close()?;
+    assert!(size > 0);
+    assert_eq!(size, std::fs::metadata(path).unwrap().len());
+    Ok(())
+}
+
+#[test]
+fn test_chart_configure_axes_num_font() -> Result<(), XlsxError> {
+    let path = "../target/chart_configure_axes_num_font.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10 + 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    let font = ChartFont::new().set_size(13.0).set_bold();
+    chart.configure_axes(|axis| axis.set_num_font(&font))?;
+    sheet.insert_chart(0, 2, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(path, "xl/charts/chart1.xml");
+    assert_eq!(chart_xml.matches(r#"sz="1300" b="1""#).count(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_print_preset_one_page_wide_landscape() -> Result<(), XlsxError> {
+    let path = "../target/print_preset_one_page_wide_landscape.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "Wide report", None)?;
+    sheet.print_preset(PrintPreset::OnePageWideLandscape);
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<pageSetUpPr fitToPage="1"/>"#));
+    assert!(sheet_xml.contains(r#"fitToHeight="0""#));
+    assert!(!sheet_xml.contains("fitToWidth"));
+    assert!(sheet_xml.contains(r#"orientation="landscape""#));
+    assert!(sheet_xml.contains(r#"<pageMargins left="0.25" right="0.25" top="0.75" bottom="0.75""#));
+    Ok(())
+}
+
+#[test]
+fn test_format_font_size_limits() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/format_font_size_limits.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    let smallest = workbook.add_format().set_font_size(1.0)?;
+    let largest = workbook.add_format().set_font_size(409.0)?;
+    assert!(workbook.add_format().set_font_size(0.5).is_err());
+    assert!(workbook.add_format().set_font_size(410.0).is_err());
+    assert!(workbook.add_format().set_font_size(f64::NAN).is_err());
+    sheet.write_string(0, 0, "Smallest", Some(&smallest))?;
+    sheet.write_string(1, 0, "Largest", Some(&largest))?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_conditional_format_priorities_in_order() -> Result<(), XlsxError> {
+    let path = "../target/conditional_format_priorities_in_order.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for row in 0..10 {
+        sheet.write_number(row, 0, (row * 10).into(), None)?;
+    }
+    let red = workbook.add_format().set_font_color(FormatColor::Red);
+    let green = workbook.add_format().set_font_color(FormatColor::Green);
+    let bold = workbook.add_format().set_bold();
+    sheet.conditional_format_range(
+        0,
+        0,
+        9,
+        0,
+        &ConditionalFormat::cell(ConditionalFormatCriteria::GreaterThan, 70.0, &red),
+    )?;
+    sheet.conditional_format_range(
+        0,
+        0,
+        9,
+        0,
+        &ConditionalFormat::cell(ConditionalFormatCriteria::LessThan, 30.0, &green),
+    )?;
+    sheet.conditional_format_range(
+        0,
+        0,
+        4,
+        0,
+        &ConditionalFormat::cell_between(0.0, 90.0, &bold),
+    )?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    let priorities: Vec<usize> = ["priority=\"1\"", "priority=\"2\"", "priority=\"3\""]
+        .iter()
+        .map(|priority| sheet_xml.find(priority).expect(priority))
+        .collect();
+    assert!(priorities[0] < priorities[1] && priorities[1] < priorities[2]);
+    assert!(sheet_xml[priorities[0]..priorities[1]].contains(r#"operator="greaterThan""#));
+    assert!(!sheet_xml.contains("priority=\"4\""));
+    Ok(())
+}
+
+#[test]
+fn test_image_default_object_position() -> Result<(), XlsxError> {
+    assert_eq!(
+        ImageOptions::default().object_position,
+        ObjectPosition::MoveDontSize
+    );
+    let path = "../target/image_default_object_position.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.insert_image(0, 0, "../images/simple1.png")?;
+    sheet.insert_image_opt(10, 0, "../images/simple1.png", &ImageOptions::default())?;
+    sheet.insert_image_opt(
+        20,
+        0,
+        "../images/simple1.png",
+        &ImageOptions {
+            object_position: ObjectPosition::DontMoveDontSize,
+            ..ImageOptions::default()
+        },
+    )?;
+    workbook.close()?;
+
+    let drawing_xml = test_util::read_xlsx_entry(path, "xl/drawings/drawing1.xml");
+    assert_eq!(
+        drawing_xml
+            .matches(r#"<xdr:twoCellAnchor editAs="oneCell">"#)
+            .count(),
+        2
+    );
+    assert_eq!(
+        drawing_xml
+            .matches(r#"<xdr:twoCellAnchor editAs="absolute">"#)
+            .count(),
+        1
+    );
+    Ok(())
+}
+
+#[test]
+fn test_define_name_validation() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/define_name_validation.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_number(0, 0, 0.2, None)?;
+    workbook.define_name("Tax_Rate", "=Sheet1!$A$1")?;
+    workbook.define_name("Sheet1!_local.name", "=Sheet1!$A$1")?;
+    assert!(workbook.define_name("Tax Rate", "=Sheet1!$A$1").is_err());
+    assert!(workbook.define_name("AB12", "=Sheet1!$A$1").is_err());
+    assert!(workbook.define_name("R1C1", "=Sheet1!$A$1").is_err());
+    assert!(workbook.define_name("rc", "=Sheet1!$A$1").is_err());
+    assert!(workbook.define_name("1st", "=Sheet1!$A$1").is_err());
+    assert!(workbook
+        .define_name(&"N".repeat(129), "=Sheet1!$A$1")
+        .is_err());
+    assert!(workbook
+        .define_name(&format!("Sheet1!{}", "L".repeat(122)), "=Sheet1!$A$1")
+        .is_err());
+    assert!(workbook
+        .define_name("Long", &format!("={}", "1".repeat(128)))
+        .is_err());
+    workbook.define_name(&"N".repeat(128), "=Sheet1!$A$1")?;
+    workbook.define_name(&format!("Sheet1!{}", "L".repeat(121)), "=Sheet1!$A$1")?;
+    workbook.define_name("XFE1", "=Sheet1!$A$1")?;
+    workbook.define_name("Rate", "=Sheet1!$A$1")?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_multiline_wraps_text() -> Result<(), XlsxError> {
+    let path = "../target/write_multiline_wraps_text.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let bold = workbook.add_format().set_bold();
+    let wrapped = workbook.add_format().set_text_wrap();
+    sheet.write_multiline(0, 0, "First line\nSecond line", None)?;
+    sheet.write_multiline(1, 0, "Bold\nlines", Some(&bold))?;
+    sheet.write_multiline(2, 0, "Already\nwrapped", Some(&wrapped))?;
+    sheet.write_multiline(3, 0, "Reused\nformat", None)?;
+    sheet.write_multiline(4, 0, "Reused\nbold", Some(&bold))?;
+    for (row, other) in [(0, 3), (1, 4)] {
+        assert_eq!(
+            sheet.get_cell_format(row, 0).unwrap().format,
+            sheet.get_cell_format(other, 0).unwrap().format
+        );
+    }
+    assert_eq!(sheet.get_cell_format(2, 0).unwrap().format, wrapped.format);
+    workbook.close()?;
+
+    let styles_xml = test_util::read_xlsx_entry(path, "xl/styles.xml");
+    assert_eq!(
+        styles_xml.matches(r#"<alignment wrapText="1"/>"#).count(),
+        2
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chart_legend_position_overlay_and_font() -> Result<(), XlsxError> {
+    let path = "../target/chart_legend_position_overlay_and_font.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10 + 10).into(), None)?;
+    }
+    let mut bottom = workbook.add_chart(ChartType::Column);
+    bottom.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    let mut legend = bottom.legend();
+    legend.set_font(&ChartFont::new().set_bold())?;
+    legend.set_overlay(true);
+    legend.set_position(ChartLegendPosition::Bottom);
+    sheet.insert_chart(0, 2, &bottom)?;
+
+    let mut overlay = workbook.add_chart(ChartType::Column);
+    overlay.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    let mut legend = overlay.legend();
+    legend.set_position(ChartLegendPosition::Left);
+    legend.set_overlay(true);
+    sheet.insert_chart(20, 2, &overlay)?;
+    workbook.close()?;
+
+    let bottom_xml = test_util::read_xlsx_entry(path, "xl/charts/chart1.xml");
+    assert!(bottom_xml.contains(r#"<c:legendPos val="b"/>"#));
+    assert!(bottom_xml.contains(r#"b="1""#));
+    assert!(!bottom_xml.contains(r#"<c:overlay val="1"/>"#));
+    let overlay_xml = test_util::read_xlsx_entry(path, "xl/charts/chart2.xml");
+    assert!(overlay_xml.contains(r#"<c:legendPos val="l"/>"#));
+    assert!(overlay_xml.contains(r#"<c:overlay val="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_set_row_height_only_keeps_format() -> Result<(), XlsxError> {
+    let path = "../target/set_row_height_only_keeps_format.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let bold = workbook.add_format().set_bold();
+    sheet.set_row(0, 15.0, Some(&bold))?;
+    sheet.set_row_height_only(0, 30.0)?;
+    let mut options = RowColOptions {
+        hidden: 1,
+        level: 1,
+        collapsed: 0,
+    };
+    sheet.set_row_opt(1, 15.0, None, &mut options)?;
+    sheet.set_row_height_only(1, 20.0)?;
+    sheet.set_row_height_only(2, 25.0)?;
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains(r#"<row r="1" s="1" customFormat="1" ht="30" customHeight="1"/>"#));
+    assert!(
+        sheet_xml.contains(r#"<row r="2" ht="20" hidden="1" customHeight="1" outlineLevel="1"/>"#)
+    );
+    assert!(sheet_xml.contains(r#"<row r="3" ht="25" customHeight="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_new_tempfile_deleted_on_drop() -> Result<(), XlsxError> {
+    let (workbook, path) = Workbook::new_tempfile()?;
+    let path_buf = path.to_path_buf();
+    assert!(path_buf.exists());
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "Temporary", None)?;
+    workbook.close()?;
+
+    let strings_xml =
+        test_util::read_xlsx_entry(path_buf.to_str().unwrap(), "xl/sharedStrings.xml");
+    assert!(strings_xml.contains("Temporary"));
+    let (_, other) = Workbook::new_tempfile()?;
+    assert_ne!(path_buf, other.to_path_buf());
+    drop(path);
+    assert!(!path_buf.exists());
+    Ok(())
+}
+
+#[test]
+fn test_written_cell_count() -> Result<(), XlsxError> {
+    let options = WorkbookOptions {
+        constant_memory: true,
+        ..WorkbookOptions::default()
+    };
+    let workbook = Workbook::new_with_options("../target/written_cell_count.xlsx", &options);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let mut other = workbook.add_worksheet(None)?;
+    for row in 0..100 {
+        sheet.write_number(row, 0, row.into(), None)?;
+        sheet.write_string(row, 1, "value", None)?;
+    }
+    sheet.write_array_formula(100, 0, 101, 1, "{=A1:B2}", None)?;
+    other.write_boolean(0, 0, true, None)?;
+    assert_eq!(sheet.written_cell_count(), 204);
+    assert_eq!(other.written_cell_count(), 1);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_row_col_limits() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("../target/write_row_col_limits.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_number(MAX_ROW, MAX_COL, 1.0, None)?;
+    sheet.write_string(MAX_ROW, 0, "last row", None)?;
+    assert!(sheet
+        .write_number(MAX_ROW + 1, 0, 1.0, None)
+        .unwrap_err()
+        .is_row_or_column_out_of_bounds());
+    assert!(sheet
+        .write_string(0, MAX_COL + 1, "too far", None)
+        .unwrap_err()
+        .is_row_or_column_out_of_bounds());
+    assert!(sheet
+        .write_formula(MAX_ROW + 1, 0, "=1+1", None)
+        .unwrap_err()
+        .is_row_or_column_out_of_bounds());
+    assert!(sheet
+        .write_boolean(0, MAX_COL + 1, true, None)
+        .unwrap_err()
+        .is_row_or_column_out_of_bounds());
+    assert!(sheet
+        .write_blank(MAX_ROW + 1, MAX_COL + 1, None)
+        .unwrap_err()
+        .is_row_or_column_out_of_bounds());
+    assert!(sheet
+        .write_array_formula(MAX_ROW, 0, MAX_ROW + 1, 0, "{=1}", None)
+        .unwrap_err()
+        .is_row_or_column_out_of_bounds());
+    assert!(sheet
+        .write_datetime(MAX_ROW + 1, 0, &DateTime::date(2024, 1, 1), None)
+        .unwrap_err()
+        .is_row_or_column_out_of_bounds());
+    assert_eq!(sheet.written_cell_count(), 2);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_scatter_smooth_marker_none() -> Result<(), XlsxError> {
+    let path = "../target/scatter_smooth_marker_none.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, i.into(), None)?;
+        sheet.write_number(i, 1, (i * i).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::ScatterSmoothWithMarkers);
+    let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    series.set_marker_type(ChartMarkerType::MarkerNone);
+    sheet.insert_chart(0, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(path, "xl/charts/chart1.xml");
+    assert!(chart_xml.contains(r#"<c:marker><c:symbol val="none"/></c:marker>"#));
+    assert!(chart_xml.contains(r#"<c:smooth val="1"/>"#));
+    Ok(())
+}
+
+#[test]
+fn test_workbook_uses_1900_date_system() -> Result<(), XlsxError> {
+    let path = "../target/workbook_1900_date_system.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_datetime(0, 0, &DateTime::date(1904, 1, 1), None)?;
+    workbook.close()?;
+
+    let workbook_xml = test_util::read_xlsx_entry(path, "xl/workbook.xml");
+    assert!(!workbook_xml.contains("date1904"));
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains("<v>1462</v>"));
+    Ok(())
+}
+
+#[test]
+fn test_chart_axis_num_format_source_linked() -> Result<(), XlsxError> {
+    let path = "../target/chart_axis_num_format_source_linked.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 1000).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.x_axis().set_num_format("0.0")?;
+    chart.x_axis().set_num_format_source_linked(false);
+    let mut y_axis = chart.y_axis();
+    y_axis.set_num_format("$#,##0")?;
+    y_axis.set_num_format_source_linked(true);
+    sheet.insert_chart(0, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(path, "xl/charts/chart1.xml");
+    assert!(chart_xml.contains(r#"<c:numFmt formatCode="0.0" sourceLinked="0"/>"#));
+    assert!(chart_xml.contains(r#"<c:numFmt formatCode="General" sourceLinked="1"/>"#));
+    assert!(!chart_xml.contains("$#,##0"));
+    Ok(())
+}
+
+#[test]
+fn test_autofilter_at() -> Result<(), XlsxError> {
+    use crate::worksheet::parse_range_ref;
+    assert_eq!(parse_range_ref("A1:C10").unwrap(), (0, 0, 9, 2));
+    assert_eq!(parse_range_ref("$C$10:$A$1").unwrap(), (0, 0, 9, 2));
+    for invalid in &["A1", "A1:", ":C10", "A1:C10:D4", "A0:C10", "A1:XFE1"] {
+        assert!(parse_range_ref(invalid).is_err(), "{}", invalid);
+    }
+
+    let path = "../target/autofilter_at.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet1 = workbook.add_worksheet(None)?;
+    sheet1.autofilter_at("A1:C10")?;
+    assert!(sheet1.autofilter_at("A1:C").is_err());
+    let mut sheet2 = workbook.add_worksheet(None)?;
+    sheet2.autofilter(0, 0, 9, 2)?;
+    workbook.close()?;
+
+    // The worksheets differ elsewhere, e.g. only the first one is selected
+    let autofilter = |name| {
+        let xml = test_util::read_xlsx_entry(path, name);
+        let start = xml.find("<autoFilter").unwrap();
+        let end = start + xml[start..].find("/>").unwrap() + 2;
+        xml[start..end].to_string()
+    };
+    let sheet1_autofilter = autofilter("xl/worksheets/sheet1.xml");
+    assert_eq!(sheet1_autofilter, r#"<autoFilter ref="A1:C10"/>"#);
+    assert_eq!(sheet1_autofilter, autofilter("xl/worksheets/sheet2.xml"));
+    Ok(())
+}
+
+#[test]
+fn test_chart_configure_bars() -> Result<(), XlsxError> {
+    let path = "../target/chart_configure_bars.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+        sheet.write_number(i, 1, (i * 10 + 2).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.add_series(None, Some("=Sheet1!$B$1:$B$5"))?;
+    chart.configure_bars(100, 0)?;
+    assert!(chart.configure_bars(501, 0).is_err());
+    assert!(chart.configure_bars(50, 101).is_err());
+    sheet.insert_chart(0, 3, &chart)?;
+    workbook.close()?;
+
+    let chart_xml = test_util::read_xlsx_entry(path, "xl/charts/chart1.xml");
+    assert!(chart_xml.contains(r#"<c:gapWidth val="100"/>"#));
+    // An overlap of 0 is the default for clustered charts and isn't written.
+    assert!(!chart_xml.contains("<c:overlap"));
+    Ok(())
+}
+
+#[test]
+fn test_num_format_presets() -> Result<(), XlsxError> {
+    let presets = [
+        (NumFormat::General, 0),
+        (NumFormat::Integer, 1),
+        (NumFormat::TwoDecimals, 2),
+        (NumFormat::ThousandsInteger, 3),
+        (NumFormat::ThousandsTwoDecimals, 4),
+        (NumFormat::Currency, 5),
+        (NumFormat::CurrencyNegativeRed, 6),
+        (NumFormat::CurrencyTwoDecimals, 7),
+        (NumFormat::CurrencyTwoDecimalsNegativeRed, 8),
+        (NumFormat::Percent, 9),
+        (NumFormat::PercentTwoDecimals, 10),
+        (NumFormat::Scientific, 11),
+        (NumFormat::Fraction, 12),
+        (NumFormat::FractionTwoDigits, 13),
+        (NumFormat::Date, 14),
+        (NumFormat::DayMonthYear, 15),
+        (NumFormat::DayMonth, 16),
+        (NumFormat::MonthYear, 17),
+        (NumFormat::Time12Hour, 18),
+        (NumFormat::Time12HourSeconds, 19),
+        (NumFormat::Time, 20),
+        (NumFormat::TimeSeconds, 21),
+        (NumFormat::DateTime, 22),
+        (NumFormat::ThousandsNegativeParentheses, 37),
+        (NumFormat::ThousandsNegativeParenthesesRed, 38),
+        (NumFormat::ThousandsTwoDecimalsNegativeParentheses, 39),
+        (NumFormat::ThousandsTwoDecimalsNegativeParenthesesRed, 40),
+        (NumFormat::Accounting, 41),
+        (NumFormat::AccountingCurrency, 42),
+        (NumFormat::AccountingTwoDecimals, 43),
+        (NumFormat::AccountingCurrencyTwoDecimals, 44),
+        (NumFormat::MinutesSeconds, 45),
+        (NumFormat::ElapsedHours, 46),
+        (NumFormat::MinutesSecondsTenths, 47),
+        (NumFormat::Engineering, 48),
+        (NumFormat::Text, 49),
+    ];
+    for (preset, index) in presets.iter() {
+        assert_eq!(preset.value(), *index, "{:?}", preset);
+    }
+
+    let path = "../target/num_format_presets.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    let percent = workbook
+        .add_format()
+        .set_num_format_preset(NumFormat::Percent);
+    let text = workbook.add_format().set_num_format_preset(NumFormat::Text);
+    sheet.write_number(0, 0, 0.25, Some(&percent))?;
+    sheet.write_string(1, 0, "00123", Some(&text))?;
+    workbook.close()?;
+
+    let styles_xml = test_util::read_xlsx_entry(path, "xl/styles.xml");
+    assert!(styles_xml.contains(r#"numFmtId="9""#));
+    assert!(styles_xml.contains(r#"numFmtId="49""#));
+    // The built-in formats are referenced by index without a <numFmts> entry.
+    assert!(!styles_xml.contains("<numFmts"));
+    Ok(())
+}
+
+#[test]
+fn test_insert_charts_grid() -> Result<(), XlsxError> {
+    let path = "../target/insert_charts_grid.xlsx";
+    let workbook = Workbook::new(path);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        sheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut charts = Vec::new();
+    for _ in 0..4 {
+        let mut chart = workbook.add_chart(ChartType::Column);
+        chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+        charts.push(chart);
+    }
+    let charts: Vec<&Chart> = charts.iter().collect();
+    assert!(sheet.insert_charts_grid(1, 1, &charts, 0, 16, 9).is_err());
+    assert!(sheet
+        .insert_charts_grid(1_048_570, 1, &charts, 2, 16, 9)
+        .is_err());
+    sheet.insert_charts_grid(1, 1, &charts, 2, 16, 9)?;
+    workbook.close()?;
+
+    let drawing_xml = test_util::read_xlsx_entry(path, "xl/drawings/drawing1.xml");
+    let anchors: Vec<&str> = drawing_xml
+        .split("<xdr:from>")
+        .skip(1)
+        .map(|x| &x[..x.find("</xdr:from>").unwrap()])
+        .collect();
+    assert_eq!(
+        anchors,
+        [(1, 1), (1, 10), (17, 1), (17, 10)]
+            .iter()
+            .map(|(row, col)| format!(
+                "<xdr:col>{}</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>{}</xdr:row><xdr:rowOff>0</xdr:rowOff>",
+                col, row
+            ))
+            .collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_validate_formulas() -> Result<(), XlsxError> {
+    use crate::formula::is_plausible_formula;
+    for valid in &[
+        "=SUM(A1:A5)",
+        "{=SUM(B1:C1*B2:C2)}",
+        "=IF(A1>1,\"(yes\",\"no)\")",
+        "='Q1 (draft)'!A1*2",
+        "=Table1[[#This Row],[Price]]",
+        "=A1%",
+        "=\"say \"\"hi\"\"\"",
+    ] {
+        assert!(is_plausible_formula(valid), "{}", valid);
+    }
+    for invalid in &[
+        "=SUM(A1:A5",
+        "=SUM(A1:A5))",
+        "=SUM(A1:A5]",
+        "={1,2)",
+        "=A1+",
+        "=\"unclosed",
+        "=",
+        "",
+    ] {
+        assert!(!is_plausible_formula(invalid), "{}", invalid);
+    }
+
+    let options = WorkbookOptions {
+        validate_formulas: true,
+        ..WorkbookOptions::default()
+    };
+    let workbook = Workbook::new_with_options("../target/validate_formulas.xlsx", &options);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_formula(0, 0, "=SUM(A2:A5)", None)?;
+    assert!(sheet
+        .write_formula(1, 0, "=SUM(A2:A5", None)
+        .unwrap_err()
+        .is_invalid_formula());
+    assert!(sheet
+        .write_formula_num(1, 0, "=SUM(A2:A5", None, 1.0)
+        .unwrap_err()
+        .is_invalid_formula());
+    assert!(sheet
+        .write_array_formula(1, 0, 1, 0, "{=SUM(A2:A5}", None)
+        .unwrap_err()
+        .is_invalid_formula());
+    // Other errors are still reported as such.
+    assert!(!sheet
+        .write_formula(1, 0, "=SUM(A2:A5\0)", None)
+        .unwrap_err()
+        .is_invalid_formula());
+    workbook.close()?;
+
+    // Without the option the formula is written as is.
+    let workbook = Workbook::new("../target/validate_formulas_disabled.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_formula(0, 0, "=SUM(A2:A5", None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_constant_memory_rows_flushed_before_close() -> Result<(), XlsxError> {
+    let path = "../target/constant_memory_flush.xlsx";
+    let options = WorkbookOptions {
+        constant_memory: true,
+        tmpdir: Some("../target".to_string()),
+        ..WorkbookOptions::default()
+    };
+    let workbook = Workbook::new_with_options(path, &options);
+    let mut sheet = workbook.add_worksheet(None)?;
+    for row in 0..3 {
+        sheet.write_number(row, 0, f64::from(row) + 0.5, None)?;
+    }
+    // Writing a following row flushes rows 0 to 2 to the temporary file.
+    sheet.write_string(10, 0, "after flush", None)?;
+    // A flushed row can't be written any more.
+    let _ = sheet.write_number(1, 1, 99.0, None);
+    workbook.close()?;
+
+    let sheet_xml = test_util::read_xlsx_entry(path, "xl/worksheets/sheet1.xml");
+    for value in &["<v>0.5</v>", "<v>1.5</v>", "<v>2.5</v>"] {
+        assert!(sheet_xml.contains(value), "{}", value);
+    }
+    assert!(sheet_xml.contains(r#"<c r="A11" t="inlineStr"><is><t>after flush</t></is></c>"#));
+    assert!(!sheet_xml.contains("<v>99</v>"));
+    Ok(())
+}
+
+#[test]
+fn test_strict_merge_ranges() -> Result<(), XlsxError> {
+    let options = WorkbookOptions {
+        strict_merge_ranges: true,
+        ..WorkbookOptions::default()
+    };
+    let workbook = Workbook::new_with_options("../target/strict_merge_ranges.xlsx", &options);
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_string(0, 0, "kept", None)?;
+    sheet.write_blank(0, 1, Some(&workbook.add_format().set_bold()))?;
+    // The top-left value and blank cells aren't lost.
+    sheet.merge_range(0, 0, 0, 2, "Title", None)?;
+    // The cells of a merged range other than its top-left cell are lost too.
+    assert!(sheet.merge_range(0, 1, 1, 2, "Over", None).is_err());
+
+    sheet.write_number(3, 2, 42.0, None)?;
+    assert!(sheet.merge_range(2, 1, 4, 3, "Lost", None).is_err());
+    assert!(sheet.merge_title(2, 1, 4, 3, "Lost").is_err());
+    // The written cells outside of the range don't matter.
+    sheet.merge_range(2, 3, 4, 4, "Beside", None)?;
+    sheet.merge_range(5, 0, 6, 1, "Below", None)?;
+
+    let mut other = workbook.add_worksheet(None)?;
+    other.merge_range(3, 2, 4, 2, "Other sheet", None)?;
+    workbook.close()?;
+
+    // Without the option the data is overwritten like in Excel.
+    let workbook = Workbook::new("../target/strict_merge_ranges_disabled.xlsx");
+    let mut sheet = workbook.add_worksheet(None)?;
+    sheet.write_number(0, 1, 42.0, None)?;
+    sheet.merge_range(0, 0, 0, 2, "Title", None)?;
+    workbook.close()?;
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! Utilities to inspect the files written by the tests.
+
+use std::fs::File;
+use std::io::Read;
+
+/// Returns the content of the entry `name` of the xlsx file at `path`, such as `xl/worksheets/sheet1.xml`.
+///
+/// This panics if the file can't be opened or the entry doesn't exist, which fails the calling test.
+pub(crate) fn read_xlsx_entry(path: &str, name: &str) -> String {
+    String::from_utf8(read_xlsx_entry_bytes(path, name))
+        .unwrap_or_else(|e| panic!("cannot read {} in {}: {}", name, path, e))
+}
+
+/// Returns the raw content of the entry `name` of the xlsx file at `path`, such as `xl/media/image1.png`.
+pub(crate) fn read_xlsx_entry_bytes(path: &str, name: &str) -> Vec<u8> {
+    let file = File::open(path).unwrap_or_else(|e| panic!("cannot open {}: {}", path, e));
+    let mut archive =
+        zip::ZipArchive::new(file).unwrap_or_else(|e| panic!("cannot read {}: {}", path, e));
+    let mut entry = archive
+        .by_name(name)
+        .unwrap_or_else(|e| panic!("cannot find {} in {}: {}", name, path, e));
+    let mut content = Vec::new();
+    entry
+        .read_to_end(&mut content)
+        .unwrap_or_else(|e| panic!("cannot read {} in {}: {}", name, path, e));
+    content
+}
+
+/// Returns the names of the entries of the xlsx file at `path` which start with `prefix`, such as `xl/media/`.
+pub(crate) fn xlsx_entry_names(path: &str, prefix: &str) -> Vec<String> {
+    let file = File::open(path).unwrap_or_else(|e| panic!("cannot open {}: {}", path, e));
+    let mut archive =
+        zip::ZipArchive::new(file).unwrap_or_else(|e| panic!("cannot read {}: {}", path, e));
+    (0..archive.len())
+        .map(|i| {
+            archive
+                .by_index(i)
+                .unwrap_or_else(|e| panic!("cannot read {}: {}", path, e))
+                .name()
+                .to_string()
+        })
+        .filter(|name| name.starts_with(prefix))
+        .collect()
+}
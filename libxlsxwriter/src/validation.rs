@@ -1,5 +1,4 @@
-use super::{convert_bool, DateTime};
-use std::ffi::CString;
+use super::{convert_bool, convert_str, DateTime, XlsxError};
 use std::os::raw::c_char;
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
@@ -122,6 +121,49 @@ impl DataValidationErrorType {
     }
 }
 
+/// Data validation options used by [Worksheet.data_validation_cell()](struct.Worksheet.html#method.data_validation_cell) and
+/// [Worksheet.data_validation_range()](struct.Worksheet.html#method.data_validation_range).
+///
+/// Data validation is a feature of Excel which allows you to restrict the data that a user enters in a cell and to display help and warning messages.
+/// It also allows you to restrict input to values in a drop down list:
+/// ```rust
+/// # use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-data_validation-1.xlsx");
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// let mut validation = DataValidation::new(
+///     DataValidationType::List,
+///     DataValidationCriteria::Between,
+///     DataValidationErrorType::Stop,
+/// );
+/// validation.value_list = Some(vec!["open".to_string(), "high".to_string(), "close".to_string()]);
+/// validation.input_title = Some("Select a value".to_string());
+/// validation.input_message = Some("Choose one of the values in the list".to_string());
+/// worksheet.data_validation_range(0, 0, 9, 0, &validation)?;
+/// # workbook.close()?;
+/// # Ok(())
+/// # }
+/// ```
+/// Numeric limits are set with `minimum_number` and `maximum_number` for the `Between` and `NotBetween` criteria and with `value_number` for the other criteria:
+/// ```rust
+/// # use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-data_validation-2.xlsx");
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// let mut validation = DataValidation::new(
+///     DataValidationType::Integer,
+///     DataValidationCriteria::Between,
+///     DataValidationErrorType::Stop,
+/// );
+/// validation.minimum_number = 1.;
+/// validation.maximum_number = 10.;
+/// validation.error_title = Some("Invalid value".to_string());
+/// validation.error_message = Some("Enter an integer between 1 and 10".to_string());
+/// worksheet.data_validation_cell(0, 1, &validation)?;
+/// # workbook.close()?;
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct DataValidation {
     pub validate: DataValidationType,
@@ -147,13 +189,8 @@ pub struct DataValidation {
     pub error_message: Option<String>,
 }
 
-fn option_str_to_cstr_bytes(s: &Option<String>) -> Option<Vec<u8>> {
-    s.as_ref().map(|x| {
-        CString::new(x as &str)
-            .unwrap()
-            .into_bytes_with_nul()
-            .to_vec()
-    })
+fn option_str_to_cstr_bytes(s: &Option<String>) -> Result<Option<Vec<u8>>, XlsxError> {
+    s.as_deref().map(convert_str).transpose()
 }
 
 impl DataValidation {
@@ -207,18 +244,13 @@ impl DataValidation {
             error_message: None,
         }
     }
-    pub(crate) fn to_c_struct(&self) -> CDataValidation {
-        let mut value_formula = option_str_to_cstr_bytes(&self.value_formula);
-        let mut value_list: Option<Vec<Vec<u8>>> = self.value_list.as_ref().map(|x| {
-            x.iter()
-                .map(|y| {
-                    CString::new(y as &str)
-                        .unwrap()
-                        .into_bytes_with_nul()
-                        .to_vec()
-                })
-                .collect()
-        });
+    pub(crate) fn to_c_struct(&self) -> Result<CDataValidation, XlsxError> {
+        let mut value_formula = option_str_to_cstr_bytes(&self.value_formula)?;
+        let mut value_list: Option<Vec<Vec<u8>>> = self
+            .value_list
+            .as_ref()
+            .map(|x| x.iter().map(|y| convert_str(y)).collect())
+            .transpose()?;
         let mut value_list_ptr: Option<Vec<*mut c_char>> = value_list.as_mut().map(|x| {
             x.iter_mut()
                 .map(|y| y.as_mut_ptr() as *mut c_char)
@@ -227,14 +259,14 @@ impl DataValidation {
         if let Some(l) = value_list_ptr.as_mut() {
             l.push(std::ptr::null_mut());
         }
-        let mut minimum_formula = option_str_to_cstr_bytes(&self.minimum_formula);
-        let mut maximum_formula = option_str_to_cstr_bytes(&self.maximum_formula);
-        let mut input_title = option_str_to_cstr_bytes(&self.input_title);
-        let mut input_message = option_str_to_cstr_bytes(&self.input_message);
-        let mut error_title = option_str_to_cstr_bytes(&self.error_title);
-        let mut error_message = option_str_to_cstr_bytes(&self.error_message);
+        let mut minimum_formula = option_str_to_cstr_bytes(&self.minimum_formula)?;
+        let mut maximum_formula = option_str_to_cstr_bytes(&self.maximum_formula)?;
+        let mut input_title = option_str_to_cstr_bytes(&self.input_title)?;
+        let mut input_message = option_str_to_cstr_bytes(&self.input_message)?;
+        let mut error_title = option_str_to_cstr_bytes(&self.error_title)?;
+        let mut error_message = option_str_to_cstr_bytes(&self.error_message)?;
 
-        CDataValidation {
+        Ok(CDataValidation {
             data_validation: libxlsxwriter_sys::lxw_data_validation {
                 validate: self.validate.value(),
                 criteria: self.criteria.value(),
@@ -294,10 +326,11 @@ impl DataValidation {
             input_message,
             error_title,
             error_message,
-        }
+        })
     }
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub(crate) struct CDataValidation {
     value_formula: Option<Vec<u8>>,
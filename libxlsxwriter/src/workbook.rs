@@ -1,8 +1,189 @@
-use super::{error, Chart, ChartType, Format, Worksheet, XlsxError};
-use std::cell::RefCell;
+use super::worksheet::{parse_cell_ref, WorksheetCells, WorksheetRanges};
+use super::{
+    convert_bool, convert_str, error, Chart, ChartLegendPosition, ChartType, Chartsheet, Format,
+    FormatAlignment, FormatColor, RowColOptions, Worksheet, WorksheetRow, XlsxError,
+};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 use std::ffi::CString;
+use std::fs;
 use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// The maximum length of a defined name, with its worksheet prefix, and of its formula in libxlsxwriter.
+const MAX_DEFINED_NAME_LENGTH: usize = 128;
+
+/// Document properties of a workbook, which are visible in "File > Properties" in Excel.
+///
+/// This struct is used by [Workbook.set_properties()](struct.Workbook.html#method.set_properties). Properties which are `None` aren't written.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct DocProperties {
+    /// The title of the Excel Document.
+    pub title: Option<String>,
+    /// The subject of the Excel Document.
+    pub subject: Option<String>,
+    /// The author of the Excel Document.
+    pub author: Option<String>,
+    /// The manager field of the Excel Document.
+    pub manager: Option<String>,
+    /// The company field of the Excel Document.
+    pub company: Option<String>,
+    /// The category of the Excel Document.
+    pub category: Option<String>,
+    /// The keywords of the Excel Document.
+    pub keywords: Option<String>,
+    /// The comment field of the Excel Document.
+    pub comments: Option<String>,
+    /// The status of the Excel Document.
+    pub status: Option<String>,
+    /// The hyperlink base URL of the Excel Document. Relative hyperlinks in the workbook are resolved against it.
+    pub hyperlink_base: Option<String>,
+}
+
+impl DocProperties {
+    /// Create document properties with no property set. The properties are then set with the builder functions:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-doc_properties-new-1.xlsx");
+    /// let properties = DocProperties::new()
+    ///     .set_title("Quarterly report")
+    ///     .set_author("Finance")
+    ///     .set_status("Draft");
+    /// workbook.set_properties(&properties)?;
+    /// workbook.close()?;
+    /// Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        DocProperties::default()
+    }
+
+    /// Set the title of the document.
+    pub fn set_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Set the subject of the document.
+    pub fn set_subject(mut self, subject: &str) -> Self {
+        self.subject = Some(subject.to_string());
+        self
+    }
+
+    /// Set the author of the document.
+    pub fn set_author(mut self, author: &str) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    /// Set the manager of the document.
+    pub fn set_manager(mut self, manager: &str) -> Self {
+        self.manager = Some(manager.to_string());
+        self
+    }
+
+    /// Set the company of the document.
+    pub fn set_company(mut self, company: &str) -> Self {
+        self.company = Some(company.to_string());
+        self
+    }
+
+    /// Set the category of the document.
+    pub fn set_category(mut self, category: &str) -> Self {
+        self.category = Some(category.to_string());
+        self
+    }
+
+    /// Set the keywords of the document.
+    pub fn set_keywords(mut self, keywords: &str) -> Self {
+        self.keywords = Some(keywords.to_string());
+        self
+    }
+
+    /// Set the comment field of the document.
+    pub fn set_comments(mut self, comments: &str) -> Self {
+        self.comments = Some(comments.to_string());
+        self
+    }
+
+    /// Set the status of the document.
+    pub fn set_status(mut self, status: &str) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    /// Set the hyperlink base URL of the document.
+    pub fn set_hyperlink_base(mut self, hyperlink_base: &str) -> Self {
+        self.hyperlink_base = Some(hyperlink_base.to_string());
+        self
+    }
+}
+
+/// Options of a new workbook used by [Workbook.new_with_options()](struct.Workbook.html#method.new_with_options).
+///
+/// ### Note
+/// The workbooks always use the 1900 date system, the Excel default on all platforms. The version of libxlsxwriter used by this crate
+/// can't write the 1904 date system of some legacy Mac files, so there is no option to select it: the serial numbers written by
+/// [Worksheet.write_datetime()](struct.Worksheet.html#method.write_datetime) count the days since January 1, 1900, and a date stored as
+/// a number in the 1904 system must be shifted by 1462 days before it is written.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct WorkbookOptions {
+    /// Reduces the amount of data stored in memory so that large files can be written efficiently.
+    ///
+    /// In this mode each row is flushed to a temporary file as soon as a cell of a following row is written.
+    /// This means that rows must be written in top to bottom order, and that once a row is flushed its cells
+    /// can't be written or formatted any more: data written to a previous row is silently ignored.
+    ///
+    /// Cells of a row not yet flushed can still be written in any order.
+    ///
+    /// The flush of a row is the only intermediate write of libxlsxwriter: the last row of each worksheet is flushed by
+    /// [Workbook.close()](struct.Workbook.html#method.close), and the temporary files are only assembled into the xlsx file by `close()`.
+    pub constant_memory: bool,
+    /// The directory used for the temporary files created by libxlsxwriter. The system temporary directory is used when it is `None`.
+    ///
+    /// There is no need to clean this directory after closing the workbook, even when closing fails:
+    /// libxlsxwriter unlinks each of its temporary files as soon as it is created, so the files are removed by the
+    /// system when they are closed.
+    pub tmpdir: Option<String>,
+    /// Use ZIP64 extensions when writing the xlsx file. This is required for files larger than 4GB.
+    pub use_zip64: bool,
+    /// Check the formulas written with [Worksheet.write_formula()](struct.Worksheet.html#method.write_formula) and the other formula
+    /// functions, and return an error for the formulas which are obviously broken instead of writing a file repaired by Excel when it is opened.
+    /// [XlsxError.is_invalid_formula()](struct.XlsxError.html#method.is_invalid_formula) is `true` for this error.
+    ///
+    /// This is a best-effort check, not a full parser: it rejects unbalanced or badly nested parentheses, braces and brackets,
+    /// unclosed string literals or quoted sheet names, and formulas ending with an operator such as `=A1+`. Formulas which pass this check
+    /// can still be invalid, for example with an unknown function or a wrong number of arguments. The check is disabled by default.
+    pub validate_formulas: bool,
+    /// Return the error `LXW_ERROR_PARAMETER_VALIDATION` from [Worksheet.merge_range()](struct.Worksheet.html#method.merge_range) and the other merge
+    /// functions when a cell of the range other than its top-left cell already contains data, instead of losing the data of the cell.
+    ///
+    /// Blank cells with only a format aren't data. The cells written in the `constant_memory` mode aren't kept in memory, so they aren't checked.
+    /// The check is disabled by default.
+    pub strict_merge_ranges: bool,
+}
+
+/// Initial settings of a new worksheet used by [Workbook.add_worksheet_opt()](struct.Workbook.html#method.add_worksheet_opt).
+///
+/// Each setting is the same as calling the corresponding [Worksheet](struct.Worksheet.html) function after creating the worksheet.
+/// Settings which are `None` or `false` keep the Excel defaults.
+#[derive(Copy, Clone, PartialEq, Default)]
+pub struct WorksheetInitOptions {
+    /// The color of the worksheet tab, see [Worksheet.set_tab_color()](struct.Worksheet.html#method.set_tab_color).
+    pub tab_color: Option<FormatColor>,
+    /// The zoom factor of the worksheet in percent, see [Worksheet.set_zoom()](struct.Worksheet.html#method.set_zoom).
+    pub zoom: Option<u16>,
+    /// Display the worksheet from right to left, see [Worksheet.right_to_left()](struct.Worksheet.html#method.right_to_left).
+    pub right_to_left: bool,
+    /// Hide the worksheet, see [Worksheet.hide()](struct.Worksheet.html#method.hide).
+    pub hidden: bool,
+}
 
 /// The Workbook is the main object exposed by the libxlsxwriter library. It represents the entire spreadsheet as you see it in Excel and internally it represents the Excel file as it is written on disk.
 ///
@@ -12,19 +193,87 @@ use std::rc::Rc;
 ///     let workbook = Workbook::new("test-workbook.xlsx");
 ///     let mut worksheet = workbook.add_worksheet(None)?;
 ///     worksheet.write_string(0, 0, "Hello Excel", None)?;
-///     workbook.close()
+///     workbook.close()?;
+///     Ok(())
 /// }
 /// ```
+///
+/// ### Note
+/// libxlsxwriter doesn't support setting the tab ratio, the share of the horizontal scrollbar area used by the worksheet tabs,
+/// so there is no `set_tab_ratio()` function. Excel uses its default ratio of 600 (in tenths of a percent, so 60% of the area is used by the tabs).
 pub struct Workbook {
     workbook: *mut libxlsxwriter_sys::lxw_workbook,
     _workbook_name: CString,
     pub(crate) const_str: Rc<RefCell<Vec<Vec<u8>>>>,
+    worksheets: RefCell<Vec<*mut libxlsxwriter_sys::lxw_worksheet>>,
+    pub(crate) cells: RefCell<HashMap<*mut libxlsxwriter_sys::lxw_worksheet, WorksheetCells>>,
+    pub(crate) ranges: RefCell<HashMap<*mut libxlsxwriter_sys::lxw_worksheet, WorksheetRanges>>,
+    pub(crate) written_cells: RefCell<HashMap<*mut libxlsxwriter_sys::lxw_worksheet, u64>>,
+    pub(crate) constant_memory: bool,
+    pub(crate) validate_formulas: bool,
+    pub(crate) strict_merge_ranges: bool,
+    percent_formats: RefCell<PercentFormats>,
+    pub(crate) row_settings: RefCell<RowSettings>,
+    date_format: Cell<*mut libxlsxwriter_sys::lxw_format>,
+    datetime_format: Cell<*mut libxlsxwriter_sys::lxw_format>,
+    title_format: Cell<*mut libxlsxwriter_sys::lxw_format>,
+    wrap_format: Cell<*mut libxlsxwriter_sys::lxw_format>,
+    /// Formats created by `Worksheet.write_multiline()` adding the text wrap property to a format, keyed by that format.
+    wrapped_formats:
+        RefCell<HashMap<*mut libxlsxwriter_sys::lxw_format, *mut libxlsxwriter_sys::lxw_format>>,
+    default_comment_author: Option<String>,
+    /// Error of the file name or of the options of the workbook, returned by `add_worksheet()` and `close()`.
+    creation_error: Option<XlsxError>,
+}
+
+/// Converts the file name of a workbook to the C string expected by libxlsxwriter.
+fn path_to_cstring(path: &Path) -> Result<CString, XlsxError> {
+    let filename = path
+        .to_str()
+        .ok_or_else(|| XlsxError::new(error::NON_UTF8_PATH_ERROR_CODE))?;
+    Ok(CString::new(filename)?)
 }
 
+/// Percent formats created by `Worksheet.write_percent()`, keyed by the number of decimals and the base format or null.
+type PercentFormats =
+    HashMap<(u8, *mut libxlsxwriter_sys::lxw_format), *mut libxlsxwriter_sys::lxw_format>;
+
+/// Formats and options of the rows set with `Worksheet.set_row()` and `Worksheet.set_row_opt()`, keyed by the worksheet and the row.
+pub(crate) type RowSettings = HashMap<
+    (*mut libxlsxwriter_sys::lxw_worksheet, WorksheetRow),
+    (*mut libxlsxwriter_sys::lxw_format, RowColOptions),
+>;
+
 impl Workbook {
-    pub fn new(filename: &str) -> Workbook {
+    /// Creates a new workbook which is written to `filename` when it is closed. The file name can be a `&str`, a `String`, a `Path` or a `PathBuf`:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # use std::path::PathBuf;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let path = PathBuf::from("../target").join("test-workbook-new-1.xlsx");
+    /// let workbook = Workbook::new(&path);
+    /// workbook.add_worksheet(None)?;
+    /// workbook.close()?;
+    /// Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The workbook keeps a copy of the value and the format of each written cell, for functions such as
+    /// [Worksheet.get_cell_format()](struct.Worksheet.html#method.get_cell_format) and [Worksheet.format_range()](struct.Worksheet.html#method.format_range).
+    /// So its memory grows with the number of written cells, on top of the memory used by libxlsxwriter for the same cells.
+    /// Neither the workbook nor libxlsxwriter keep the written cells in the constant memory mode of [WorkbookOptions](struct.WorkbookOptions.html).
+    ///
+    /// libxlsxwriter takes the file name as a C string, so a file name which isn't valid UTF-8 or contains a NUL byte can't be used.
+    /// An error is then returned by [Workbook.add_worksheet()](struct.Workbook.html#method.add_worksheet),
+    /// [Workbook.add_chartsheet()](struct.Workbook.html#method.add_chartsheet) and [Workbook.close()](struct.Workbook.html#method.close), and no file is written:
+    /// [XlsxError.is_non_utf8_path()](struct.XlsxError.html#method.is_non_utf8_path) is `true` for a file name which isn't valid UTF-8,
+    /// and the error is `LXW_ERROR_PARAMETER_VALIDATION` for a file name with a NUL byte.
+    pub fn new<P: AsRef<Path>>(filename: P) -> Workbook {
         unsafe {
-            let workbook_name = CString::new(filename).expect("Null Error");
+            let (workbook_name, creation_error) = match path_to_cstring(filename.as_ref()) {
+                Ok(workbook_name) => (workbook_name, None),
+                Err(error) => (CString::default(), Some(error)),
+            };
             let raw_workbook = libxlsxwriter_sys::workbook_new(workbook_name.as_c_str().as_ptr());
             if raw_workbook.is_null() {
                 unreachable!()
@@ -33,14 +282,157 @@ impl Workbook {
                 workbook: raw_workbook,
                 _workbook_name: workbook_name,
                 const_str: Rc::new(RefCell::new(Vec::new())),
+                worksheets: RefCell::new(Vec::new()),
+                cells: RefCell::new(HashMap::new()),
+                ranges: RefCell::new(HashMap::new()),
+                written_cells: RefCell::new(HashMap::new()),
+                constant_memory: false,
+                validate_formulas: false,
+                strict_merge_ranges: false,
+                percent_formats: RefCell::new(HashMap::new()),
+                row_settings: RefCell::new(HashMap::new()),
+                date_format: Cell::new(std::ptr::null_mut()),
+                datetime_format: Cell::new(std::ptr::null_mut()),
+                title_format: Cell::new(std::ptr::null_mut()),
+                wrap_format: Cell::new(std::ptr::null_mut()),
+                wrapped_formats: RefCell::new(HashMap::new()),
+                default_comment_author: None,
+                creation_error,
             }
         }
     }
+
+    /// This function is the same as [Workbook.new()](struct.Workbook.html#method.new) except that it takes additional options:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let options = WorkbookOptions {
+    ///     constant_memory: true,
+    ///     tmpdir: Some("../target".to_string()),
+    ///     ..WorkbookOptions::default()
+    /// };
+    /// let workbook = Workbook::new_with_options("test-workbook-new_with_options-1.xlsx", &options);
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// for row in 0..1000 {
+    ///     worksheet.write_number(row, 0, row.into(), None)?;
+    ///     worksheet.write_string(row, 1, "constant memory", None)?;
+    /// }
+    /// workbook.close()?;
+    /// Ok(())
+    /// # }
+    /// ```
+    /// See [WorkbookOptions](struct.WorkbookOptions.html) for the restrictions of the constant memory mode.
+    /// [Worksheet.format_range()](struct.Worksheet.html#method.format_range) can't be used in this mode because the written cells are not kept.
+    ///
+    /// Like for the file name, a `tmpdir` option which contains a NUL byte is reported by the error `LXW_ERROR_PARAMETER_VALIDATION`
+    /// returned by [Workbook.add_worksheet()](struct.Workbook.html#method.add_worksheet) and [Workbook.close()](struct.Workbook.html#method.close).
+    pub fn new_with_options<P: AsRef<Path>>(filename: P, options: &WorkbookOptions) -> Workbook {
+        unsafe {
+            let (workbook_name, mut creation_error) = match path_to_cstring(filename.as_ref()) {
+                Ok(workbook_name) => (workbook_name, None),
+                Err(error) => (CString::default(), Some(error)),
+            };
+            let tmpdir = match options.tmpdir.as_deref().map(convert_str).transpose() {
+                Ok(tmpdir) => tmpdir,
+                Err(error) => {
+                    creation_error = creation_error.or(Some(error));
+                    None
+                }
+            };
+            let mut workbook_options = libxlsxwriter_sys::lxw_workbook_options {
+                constant_memory: convert_bool(options.constant_memory),
+                tmpdir: tmpdir
+                    .as_ref()
+                    .map(|x| x.as_ptr() as *mut c_char)
+                    .unwrap_or(std::ptr::null_mut()),
+                use_zip64: convert_bool(options.use_zip64),
+            };
+            let raw_workbook = libxlsxwriter_sys::workbook_new_opt(
+                workbook_name.as_c_str().as_ptr(),
+                &mut workbook_options,
+            );
+            if raw_workbook.is_null() {
+                unreachable!()
+            }
+            Workbook {
+                workbook: raw_workbook,
+                _workbook_name: workbook_name,
+                const_str: Rc::new(RefCell::new(tmpdir.into_iter().collect())),
+                worksheets: RefCell::new(Vec::new()),
+                cells: RefCell::new(HashMap::new()),
+                ranges: RefCell::new(HashMap::new()),
+                written_cells: RefCell::new(HashMap::new()),
+                constant_memory: options.constant_memory,
+                validate_formulas: options.validate_formulas,
+                strict_merge_ranges: options.strict_merge_ranges,
+                percent_formats: RefCell::new(HashMap::new()),
+                row_settings: RefCell::new(HashMap::new()),
+                date_format: Cell::new(std::ptr::null_mut()),
+                datetime_format: Cell::new(std::ptr::null_mut()),
+                title_format: Cell::new(std::ptr::null_mut()),
+                wrap_format: Cell::new(std::ptr::null_mut()),
+                wrapped_formats: RefCell::new(HashMap::new()),
+                default_comment_author: None,
+                creation_error,
+            }
+        }
+    }
+
+    /// This function is the same as [Workbook.new()](struct.Workbook.html#method.new) except that it returns an error instead of overwriting an existing file:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let _ = std::fs::remove_file("test-workbook-new_no_overwrite-1.xlsx");
+    /// let workbook = Workbook::new_no_overwrite("test-workbook-new_no_overwrite-1.xlsx")?;
+    /// workbook.add_worksheet(None)?;
+    /// workbook.close()?;
+    /// # std::fs::write("test-workbook-new_no_overwrite-2.xlsx", b"").unwrap();
+    /// let error = Workbook::new_no_overwrite("test-workbook-new_no_overwrite-2.xlsx").err().unwrap();
+    /// assert!(error.is_file_exists());
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [XlsxError.is_file_exists()](struct.XlsxError.html#method.is_file_exists) is `true` for the error returned when the file exists.
+    ///
+    /// The file is checked when the workbook is created, and libxlsxwriter only creates it when the workbook is closed.
+    /// A file created by another program in the meantime is still overwritten.
+    pub fn new_no_overwrite<P: AsRef<Path>>(filename: P) -> Result<Workbook, XlsxError> {
+        if filename.as_ref().exists() {
+            return Err(XlsxError::new(error::FILE_EXISTS_ERROR_CODE));
+        }
+        Ok(Workbook::new(filename))
+    }
+
+    /// This function creates a workbook which is written to a new temporary file, for the APIs which need the path of an xlsx file but not a permanent file:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let (workbook, path) = Workbook::new_tempfile()?;
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Hello Excel", None)?;
+    /// workbook.close()?;
+    /// let xlsx = std::fs::read(&path).unwrap(); // e.g. attach the file to an email
+    /// # assert!(!xlsx.is_empty());
+    /// drop(path); // deletes the file
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The file is created empty in the temporary directory of the system, with a unique name, and written when the workbook is closed.
+    /// It is deleted when the returned [TempPath](struct.TempPath.html) is dropped, so the path must be kept as long as the file is used,
+    /// including after the workbook is closed. Errors while deleting the file are ignored.
+    ///
+    /// The error `LXW_ERROR_CREATING_TMPFILE` is returned if the file can't be created.
+    pub fn new_tempfile() -> Result<(Workbook, TempPath), XlsxError> {
+        let path = TempPath::create()?;
+        Ok((Workbook::new(&path), path))
+    }
+
     pub fn add_worksheet<'a>(
         &'a self,
         sheet_name: Option<&str>,
     ) -> Result<Worksheet<'a>, XlsxError> {
-        let name_vec = sheet_name.map(|x| CString::new(x).unwrap().as_bytes_with_nul().to_vec());
+        self.check_creation()?;
+        let name_vec = sheet_name.map(convert_str).transpose()?;
         unsafe {
             if let Some(sheet_name) = name_vec.as_ref() {
                 let result = libxlsxwriter_sys::workbook_validate_sheet_name(
@@ -67,22 +459,134 @@ impl Workbook {
             if worksheet.is_null() {
                 return Err(XlsxError::new(error::UNKNOWN_ERROR_CODE));
             }
+            self.worksheets.borrow_mut().push(worksheet);
 
-            Ok(Worksheet {
+            let mut worksheet = Worksheet {
                 _workbook: self,
                 worksheet,
+            };
+            if let Some(author) = self.default_comment_author.as_deref() {
+                worksheet.set_comments_author(author)?;
+            }
+            Ok(worksheet)
+        }
+    }
+
+    /// This function is like [Workbook.add_worksheet()](struct.Workbook.html#method.add_worksheet) except that it also applies initial settings to the worksheet:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-workbook-add_worksheet_opt-1.xlsx");
+    /// # let _summary = workbook.add_worksheet(None)?;
+    /// let mut worksheet = workbook.add_worksheet_opt(
+    ///     Some("Data"),
+    ///     &WorksheetInitOptions {
+    ///         tab_color: Some(FormatColor::Green),
+    ///         zoom: Some(120),
+    ///         right_to_left: true,
+    ///         hidden: true,
+    ///     },
+    /// )?;
+    /// worksheet.write_string(0, 0, "Hidden data", None)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_worksheet_opt<'a>(
+        &'a self,
+        sheet_name: Option<&str>,
+        options: &WorksheetInitOptions,
+    ) -> Result<Worksheet<'a>, XlsxError> {
+        let mut worksheet = self.add_worksheet(sheet_name)?;
+        if let Some(color) = options.tab_color {
+            worksheet.set_tab_color(color);
+        }
+        if let Some(zoom) = options.zoom {
+            worksheet.set_zoom(zoom);
+        }
+        if options.right_to_left {
+            worksheet.right_to_left();
+        }
+        if options.hidden {
+            worksheet.hide();
+        }
+        Ok(worksheet)
+    }
+
+    /// This function is used to set the default author of the cell comments of the worksheets added after it is called:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let mut workbook = Workbook::new("test-workbook-set_default_comment_author-1.xlsx");
+    /// workbook.set_default_comment_author("Ruby")?;
+    /// let mut summary = workbook.add_worksheet(Some("Summary"))?;
+    /// summary.write_comment(0, 0, "Written by Ruby")?;
+    /// let mut details = workbook.add_worksheet(Some("Details"))?;
+    /// details.set_comments_author("Moss")?;
+    /// details.write_comment(0, 0, "Written by Moss")?;
+    /// workbook.close()?;
+    /// Ok(())
+    /// # }
+    /// ```
+    /// Each new worksheet starts with this author as if [Worksheet.set_comments_author()](struct.Worksheet.html#method.set_comments_author) was called on it.
+    /// The author of a comment is, from the highest priority to the lowest: the author of its [CommentOptions](struct.CommentOptions.html),
+    /// the author set with `Worksheet.set_comments_author()`, and this default author. Worksheets added before this function is called keep their author.
+    pub fn set_default_comment_author(&mut self, author: &str) -> Result<(), XlsxError> {
+        convert_str(author)?;
+        self.default_comment_author = Some(author.to_string());
+        Ok(())
+    }
+
+    /// Adds a chartsheet to the workbook. A chartsheet is a sheet which only contains a chart, see [Chartsheet](struct.Chartsheet.html).
+    ///
+    /// The default name is "Chart1", "Chart2" and so on. The name is validated like the name of a worksheet.
+    pub fn add_chartsheet<'a>(
+        &'a self,
+        sheet_name: Option<&str>,
+    ) -> Result<Chartsheet<'a>, XlsxError> {
+        self.check_creation()?;
+        let name_vec = sheet_name.map(convert_str).transpose()?;
+        unsafe {
+            if let Some(sheet_name) = name_vec.as_ref() {
+                let result = libxlsxwriter_sys::workbook_validate_sheet_name(
+                    self.workbook,
+                    sheet_name.as_ptr() as *const c_char,
+                );
+                if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                    return Err(XlsxError::new(result));
+                }
+            }
+
+            let chartsheet = libxlsxwriter_sys::workbook_add_chartsheet(
+                self.workbook,
+                name_vec
+                    .as_ref()
+                    .map(|x| x.as_ptr() as *const c_char)
+                    .unwrap_or(std::ptr::null()),
+            );
+
+            if let Some(name) = name_vec {
+                self.const_str.borrow_mut().push(name);
+            }
+
+            if chartsheet.is_null() {
+                return Err(XlsxError::new(error::UNKNOWN_ERROR_CODE));
+            }
+
+            Ok(Chartsheet {
+                _workbook: self,
+                chartsheet,
             })
         }
     }
 
+    /// Returns the worksheet named `sheet_name`, or `None` if there is no such worksheet.
     pub fn get_worksheet<'a>(&'a self, sheet_name: &str) -> Option<Worksheet<'a>> {
+        let sheet_name = CString::new(sheet_name).ok()?;
         unsafe {
             let worksheet = libxlsxwriter_sys::workbook_get_worksheet_by_name(
                 self.workbook,
-                CString::new(sheet_name)
-                    .expect("Null Error")
-                    .as_c_str()
-                    .as_ptr(),
+                sheet_name.as_c_str().as_ptr(),
             );
             if worksheet.is_null() {
                 None
@@ -95,6 +599,178 @@ impl Workbook {
         }
     }
 
+    /// Returns an iterator over the worksheets of this workbook in the order they were added.
+    ///
+    /// This is useful to apply the same settings to every worksheet:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-worksheets-1.xlsx");
+    /// workbook.add_worksheet(Some("Summary"))?;
+    /// workbook.add_worksheet(Some("Details"))?;
+    /// for mut worksheet in workbook.worksheets() {
+    ///     worksheet.set_footer("&CPage &P of &N")?;
+    /// }
+    /// workbook.close()?;
+    /// Ok(())
+    /// # }
+    /// ```
+    pub fn worksheets<'a>(&'a self) -> impl Iterator<Item = Worksheet<'a>> {
+        self.worksheets
+            .borrow()
+            .clone()
+            .into_iter()
+            .map(move |worksheet| Worksheet {
+                _workbook: self,
+                worksheet,
+            })
+    }
+
+    /// This function is used to set the document properties such as Title, Author etc. for an Excel file created using libxlsxwriter:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-set_properties-1.xlsx");
+    /// let properties = DocProperties {
+    ///     title: Some("This is an example spreadsheet".to_string()),
+    ///     author: Some("John McNamara".to_string()),
+    ///     hyperlink_base: Some("https://intranet/".to_string()),
+    ///     ..DocProperties::default()
+    /// };
+    /// workbook.set_properties(&properties)?;
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_url(0, 0, "reports/2020.html", None)?;
+    /// workbook.close()?;
+    /// Ok(())
+    /// # }
+    /// ```
+    /// The `hyperlink_base` property is used by Excel to resolve relative hyperlinks written with [Worksheet.write_url()](struct.Worksheet.html#method.write_url).
+    pub fn set_properties(&self, properties: &DocProperties) -> Result<(), XlsxError> {
+        let title = properties.title.as_deref().map(convert_str).transpose()?;
+        let subject = properties.subject.as_deref().map(convert_str).transpose()?;
+        let author = properties.author.as_deref().map(convert_str).transpose()?;
+        let manager = properties.manager.as_deref().map(convert_str).transpose()?;
+        let company = properties.company.as_deref().map(convert_str).transpose()?;
+        let category = properties
+            .category
+            .as_deref()
+            .map(convert_str)
+            .transpose()?;
+        let keywords = properties
+            .keywords
+            .as_deref()
+            .map(convert_str)
+            .transpose()?;
+        let comments = properties
+            .comments
+            .as_deref()
+            .map(convert_str)
+            .transpose()?;
+        let status = properties.status.as_deref().map(convert_str).transpose()?;
+        let hyperlink_base = properties
+            .hyperlink_base
+            .as_deref()
+            .map(convert_str)
+            .transpose()?;
+        let to_ptr = |x: &Option<Vec<u8>>| {
+            x.as_ref()
+                .map(|y| y.as_ptr() as *mut c_char)
+                .unwrap_or(std::ptr::null_mut())
+        };
+        let mut doc_properties = libxlsxwriter_sys::lxw_doc_properties {
+            title: to_ptr(&title),
+            subject: to_ptr(&subject),
+            author: to_ptr(&author),
+            manager: to_ptr(&manager),
+            company: to_ptr(&company),
+            category: to_ptr(&category),
+            keywords: to_ptr(&keywords),
+            comments: to_ptr(&comments),
+            status: to_ptr(&status),
+            hyperlink_base: to_ptr(&hyperlink_base),
+            created: 0,
+        };
+        unsafe {
+            let result =
+                libxlsxwriter_sys::workbook_set_properties(self.workbook, &mut doc_properties);
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// This function defines a name which can be used in the formulas of the workbook instead of a cell range, a constant or a formula:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-define_name-1.xlsx");
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// workbook.define_name("Sales", "=Sheet1!$A$1:$A$10")?;
+    /// workbook.define_name("Sheet1!Rate", "=0.2")?; // only defined in Sheet1
+    /// worksheet.write_formula(10, 0, "=SUM(Sales)*Rate", None)?;
+    /// workbook.close()?;
+    /// Ok(())
+    /// # }
+    /// ```
+    /// A name prefixed with a worksheet name and `!` is only defined in that worksheet. The name itself must follow the rules of Excel:
+    /// it starts with a letter, an underscore or a backslash, it only contains letters, digits, underscores, periods and backslashes, so no spaces,
+    /// and it doesn't look like a cell reference such as `"A1"`, `"XFD1048576"`, `"R1C1"`, `"R"` or `"C"`.
+    /// The error `LXW_ERROR_PARAMETER_VALIDATION` is returned for the other names, which Excel would reject when opening the file.
+    ///
+    /// libxlsxwriter limits the name, including its worksheet prefix, and the formula to 128 characters each.
+    /// The error `LXW_ERROR_128_STRING_LENGTH_EXCEEDED` is returned for longer ones.
+    pub fn define_name(&self, name: &str, formula: &str) -> Result<(), XlsxError> {
+        if name.chars().count() > MAX_DEFINED_NAME_LENGTH
+            || formula.chars().count() > MAX_DEFINED_NAME_LENGTH
+        {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_128_STRING_LENGTH_EXCEEDED,
+            ));
+        }
+        let local_name = name.rsplit('!').next().unwrap_or(name);
+        if !is_valid_defined_name(local_name) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        let name_vec = convert_str(name)?;
+        let formula_vec = convert_str(formula)?;
+        unsafe {
+            let result = libxlsxwriter_sys::workbook_define_name(
+                self.workbook,
+                name_vec.as_ptr() as *const c_char,
+                formula_vec.as_ptr() as *const c_char,
+            );
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// This function creates a new [Format](struct.Format.html) to format the cells of the worksheets:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-workbook-add_format-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// for row in 0..100 {
+    ///     // Each format has the same properties, so they are written as one format in the file.
+    ///     let total = workbook.add_format().set_bold().set_num_format("0.00");
+    ///     worksheet.write_number(row, 0, row.into(), Some(&total))?;
+    /// }
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The formats with the same properties share a single cell format (XF) record of the xlsx file: libxlsxwriter looks the properties of a format up
+    /// in a hash table the first time it is used by a cell, and reuses the record of an identical format. Creating a format per cell therefore doesn't
+    /// increase the size of the file or the number of cell formats seen by Excel, and no cache is needed for that.
+    ///
+    /// Each call still allocates a small libxlsxwriter format, which is freed when the workbook is closed, so a format reused for many cells saves memory.
     pub fn add_format(&self) -> Format {
         unsafe {
             let format = libxlsxwriter_sys::workbook_add_format(self.workbook);
@@ -109,6 +785,111 @@ impl Workbook {
         }
     }
 
+    /// Returns the format used to write percentages with `decimals` decimal places on top of `base`.
+    /// The formats are created once and reused.
+    pub(crate) fn percent_format(&self, decimals: u8, base: Option<&Format>) -> Format<'_> {
+        let key = (
+            decimals,
+            base.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
+        );
+        if let Some(&format) = self.percent_formats.borrow().get(&key) {
+            return Format {
+                _workbook: self,
+                format,
+            };
+        }
+        let num_format = if decimals == 0 {
+            "0%".to_string()
+        } else {
+            format!("0.{}%", "0".repeat(decimals.into()))
+        };
+        let percent = self.add_format().set_num_format(&num_format);
+        let format = match base {
+            Some(base) => base.merge(&percent).format,
+            None => percent.format,
+        };
+        self.percent_formats.borrow_mut().insert(key, format);
+        Format {
+            _workbook: self,
+            format,
+        }
+    }
+
+    /// Returns the `yyyy-mm-dd` format used by `Worksheet.write_date()` when no format is given. The format is created once and reused.
+    pub(crate) fn default_date_format(&self) -> Format<'_> {
+        if self.date_format.get().is_null() {
+            self.date_format
+                .set(self.add_format().set_num_format("yyyy-mm-dd").format);
+        }
+        Format {
+            _workbook: self,
+            format: self.date_format.get(),
+        }
+    }
+
+    /// Returns the `yyyy-mm-dd hh:mm:ss` format used by `Worksheet.write_auto()` for the dates with a time. The format is created once and reused.
+    pub(crate) fn default_datetime_format(&self) -> Format<'_> {
+        if self.datetime_format.get().is_null() {
+            self.datetime_format.set(
+                self.add_format()
+                    .set_num_format("yyyy-mm-dd hh:mm:ss")
+                    .format,
+            );
+        }
+        Format {
+            _workbook: self,
+            format: self.datetime_format.get(),
+        }
+    }
+
+    /// Returns the format used by `Worksheet.write_multiline()` to wrap the text, combining `base` with the text wrap property.
+    /// The formats are created once and reused.
+    pub(crate) fn wrapped_format(&self, base: Option<&Format>) -> Format<'_> {
+        if self.wrap_format.get().is_null() {
+            self.wrap_format
+                .set(self.add_format().set_text_wrap().format);
+        }
+        let wrap = Format {
+            _workbook: self,
+            format: self.wrap_format.get(),
+        };
+        let base = match base {
+            Some(base) => base,
+            None => return wrap,
+        };
+        if let Some(&format) = self.wrapped_formats.borrow().get(&base.format) {
+            return Format {
+                _workbook: self,
+                format,
+            };
+        }
+        let format = base.merge(&wrap).format;
+        self.wrapped_formats
+            .borrow_mut()
+            .insert(base.format, format);
+        Format {
+            _workbook: self,
+            format,
+        }
+    }
+
+    /// Returns the bold and centered format used by `Worksheet.merge_title()`. The format is created once and reused.
+    pub(crate) fn default_title_format(&self) -> Format<'_> {
+        if self.title_format.get().is_null() {
+            self.title_format.set(
+                self.add_format()
+                    .set_bold()
+                    .set_align(FormatAlignment::Center)
+                    .set_align(FormatAlignment::VerticalCenter)
+                    .format,
+            );
+        }
+        Format {
+            _workbook: self,
+            format: self.title_format.get(),
+        }
+    }
+
     pub fn add_chart(&self, chart_type: ChartType) -> Chart {
         unsafe {
             let chart = libxlsxwriter_sys::workbook_add_chart(self.workbook, chart_type.value());
@@ -119,28 +900,288 @@ impl Workbook {
             Chart {
                 _workbook: self,
                 chart,
+                series: Vec::new(),
+                width: None,
+                height: None,
+                legend_position: ChartLegendPosition::Right,
+                legend_overlay: false,
             }
         }
     }
 
-    pub fn close(mut self) -> Result<(), XlsxError> {
-        unsafe {
-            let result = libxlsxwriter_sys::workbook_close(self.workbook);
-            self.workbook = std::ptr::null_mut();
-            match result {
-                libxlsxwriter_sys::lxw_error_LXW_NO_ERROR => Ok(()),
-                _ => Err(XlsxError::new(result)),
+    /// This function writes the workbook to its file and frees the memory of the workbook.
+    ///
+    /// A workbook which isn't closed is written when it is dropped, but the errors are ignored. libxlsxwriter frees the workbook when it is closed,
+    /// so the workbook is closed only once: `close()` consumes the workbook, and dropping a closed workbook doesn't close it again.
+    ///
+    /// The size in bytes of the written file is returned, for example to log it or to check a quota:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-close-1.xlsx");
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Hello Excel", None)?;
+    /// let size = workbook.close()?;
+    /// println!("{} bytes written", size);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The error `LXW_ERROR_CREATING_XLSX_FILE` is returned if the size of the written file can't be read.
+    ///
+    /// ### Note
+    /// libxlsxwriter writes the whole file when the workbook is closed and doesn't support flushing a partial file, so there is no `flush()` function:
+    /// the xlsx file doesn't exist before `close()` returns. To limit the memory used by a long-running generation, use the `constant_memory` option of
+    /// [WorkbookOptions](struct.WorkbookOptions.html), which flushes each row to a temporary file as soon as a following row is written.
+    pub fn close(mut self) -> Result<u64, XlsxError> {
+        let filename = PathBuf::from(self._workbook_name.to_string_lossy().into_owned());
+        let workbook = match self.take_workbook() {
+            Some(workbook) => workbook,
+            None => return Ok(0),
+        };
+        self.close_workbook(workbook)?;
+        fs::metadata(&filename)
+            .map(|metadata| metadata.len())
+            .map_err(|_| XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_CREATING_XLSX_FILE))
+    }
+
+    /// This function is the same as [Workbook.close()](struct.Workbook.html#method.close) except that it reports the progress of writing the file:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-close_with_progress-1.xlsx");
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// for row in 0..10000 {
+    ///     worksheet.write_number(row, 0, row.into(), None)?;
+    /// }
+    /// let size = workbook.close_with_progress(|bytes| println!("{} bytes written", bytes))?;
+    /// println!("{} bytes in total", size);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// libxlsxwriter has no progress hook, so the xlsx file is written by another thread while the size of the file
+    /// is checked every 100 milliseconds. The callback is called on the calling thread with the number of bytes written
+    /// so far each time the size has grown, and a last time with the final size of the file, which is also returned like `close()` does.
+    /// The size of a file which already exists with the same name isn't reported before the file is written again.
+    ///
+    /// The callback is therefore called at least once when the file is written, even for a small workbook which is written in less than 100 milliseconds.
+    /// The number of bytes is the size of the compressed xlsx file, so it can't be compared with the number of cells written.
+    pub fn close_with_progress<F: FnMut(u64)>(mut self, mut callback: F) -> Result<u64, XlsxError> {
+        let filename = PathBuf::from(self._workbook_name.to_string_lossy().into_owned());
+        let workbook = match self.take_workbook() {
+            Some(workbook) => CloseWorkbook(workbook),
+            None => return Ok(0),
+        };
+        if self.creation_error.is_some() {
+            return self.close_workbook(workbook.0).map(|_| 0);
+        }
+        // A file left by a previous run keeps its size until libxlsxwriter truncates it, so it isn't reported.
+        let file_state = |metadata: &fs::Metadata| (metadata.len(), metadata.modified().ok());
+        let previous = fs::metadata(&filename).ok().map(|x| file_state(&x));
+
+        let (sender, receiver) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            let workbook = workbook;
+            let result = unsafe { libxlsxwriter_sys::workbook_close(workbook.0) };
+            let _ = sender.send(result);
+        });
+
+        let mut reported = 0;
+        let result = loop {
+            match receiver.recv_timeout(Duration::from_millis(100)) {
+                Ok(result) => break result,
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if let Ok(metadata) = fs::metadata(&filename) {
+                        if Some(file_state(&metadata)) != previous && metadata.len() > reported {
+                            reported = metadata.len();
+                            callback(reported);
+                        }
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => break error::UNKNOWN_ERROR_CODE,
+            }
+        };
+        let _ = handle.join();
+        if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+            return Err(XlsxError::new(result));
+        }
+
+        let size = fs::metadata(&filename)
+            .map(|metadata| metadata.len())
+            .map_err(|_| {
+                XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_CREATING_XLSX_FILE)
+            })?;
+        if size != reported {
+            callback(size);
+        }
+        Ok(size)
+    }
+
+    /// This function closes the workbook like [Workbook.close()](struct.Workbook.html#method.close) and returns the XML of the part `name` of the written file,
+    /// such as `"chart1.xml"` or `"xl/worksheets/sheet1.xml"`. It is only available with the `debug` feature:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-close_and_read_part-1.xlsx");
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Hello", None)?;
+    /// let sheet_xml = workbook.close_and_read_part("sheet1.xml")?;
+    /// assert!(sheet_xml.contains(r#"<c r="A1" t="s">"#));
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// See [xlsx_part_xml()](fn.xlsx_part_xml.html) for the part names and the errors.
+    #[cfg(feature = "debug")]
+    pub fn close_and_read_part(self, name: &str) -> Result<String, XlsxError> {
+        let filename = PathBuf::from(self._workbook_name.to_string_lossy().into_owned());
+        self.close()?;
+        let xlsx = fs::read(&filename).map_err(|_| {
+            XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_CREATING_XLSX_FILE)
+        })?;
+        super::xlsx_part_xml(&xlsx, name)
+    }
+
+    /// Returns the libxlsxwriter workbook to close, or `None` if it is already closed. The workbook is then marked as closed,
+    /// so that `workbook_close()` is never called twice on the same workbook, which would be a use after free.
+    fn take_workbook(&mut self) -> Option<*mut libxlsxwriter_sys::lxw_workbook> {
+        if self.workbook.is_null() {
+            return None;
+        }
+        #[cfg(test)]
+        CLOSE_CALLS.with(|x| x.set(x.get() + 1));
+        Some(std::mem::replace(&mut self.workbook, std::ptr::null_mut()))
+    }
+
+    /// Returns the error of the file name or of the options given when the workbook was created.
+    fn check_creation(&self) -> Result<(), XlsxError> {
+        match &self.creation_error {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Closes a workbook returned by `take_workbook()`. A workbook with an invalid file name or options is freed without writing a file.
+    fn close_workbook(
+        &self,
+        workbook: *mut libxlsxwriter_sys::lxw_workbook,
+    ) -> Result<(), XlsxError> {
+        if let Err(error) = self.check_creation() {
+            unsafe { libxlsxwriter_sys::lxw_workbook_free(workbook) };
+            return Err(error);
+        }
+        match unsafe { libxlsxwriter_sys::workbook_close(workbook) } {
+            libxlsxwriter_sys::lxw_error_LXW_NO_ERROR => Ok(()),
+            result => Err(XlsxError::new(result)),
+        }
+    }
+}
+
+/// Path of a temporary xlsx file created by [Workbook.new_tempfile()](struct.Workbook.html#method.new_tempfile). The file is deleted when the path is dropped.
+#[derive(Debug)]
+pub struct TempPath {
+    path: PathBuf,
+}
+
+impl TempPath {
+    /// Number of temporary files created by the process, to give them unique names.
+    fn next_id() -> usize {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Creates a new empty file with a unique name in the temporary directory of the system.
+    fn create() -> Result<TempPath, XlsxError> {
+        let error = || XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_CREATING_TMPFILE);
+        let tmpdir = std::env::temp_dir();
+        for _ in 0..100 {
+            let path = tmpdir.join(format!(
+                "xlsxwriter-{}-{}.xlsx",
+                std::process::id(),
+                TempPath::next_id()
+            ));
+            if !matches!(path.to_str(), Some(x) if !x.contains('\0')) {
+                return Err(error());
+            }
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => return Ok(TempPath { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(_) => return Err(error()),
             }
         }
+        Err(error())
     }
 }
 
+impl std::ops::Deref for TempPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for TempPath {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Checks the rules of Excel for a defined name without its worksheet prefix, see [Workbook.define_name()](struct.Workbook.html#method.define_name).
+pub(crate) fn is_valid_defined_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let starts_well = match chars.next() {
+        Some(first) => first.is_alphabetic() || first == '_' || first == '\\',
+        None => false,
+    };
+    starts_well
+        && chars.all(|x| x.is_alphanumeric() || x == '_' || x == '.' || x == '\\')
+        && parse_cell_ref(name).is_err()
+        && !is_r1c1_ref(name)
+}
+
+/// Returns `true` for the R1C1 style references such as `"R1C1"`, `"RC2"`, `"R3"` or `"C"`, which can't be used as defined names.
+fn is_r1c1_ref(name: &str) -> bool {
+    let upper = name.to_ascii_uppercase();
+    let skip_digits = |x: &str| {
+        x.trim_start_matches(|y: char| y.is_ascii_digit())
+            .to_string()
+    };
+    let after_row = match upper.strip_prefix('R') {
+        Some(rest) => skip_digits(rest),
+        None => upper.clone(),
+    };
+    let after_col = match after_row.strip_prefix('C') {
+        Some(rest) => skip_digits(rest),
+        None => after_row,
+    };
+    !upper.is_empty() && after_col.is_empty()
+}
+
+/// Raw workbook pointer moved to the thread which closes the workbook in `Workbook.close_with_progress()`.
+struct CloseWorkbook(*mut libxlsxwriter_sys::lxw_workbook);
+
+// The workbook is only used by the closing thread until it is joined.
+unsafe impl Send for CloseWorkbook {}
+
+#[cfg(test)]
+thread_local! {
+    /// Number of workbooks closed by the current thread, to check that a workbook is closed only once.
+    pub(crate) static CLOSE_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
 impl Drop for Workbook {
     fn drop(&mut self) {
-        unsafe {
-            if !self.workbook.is_null() {
-                libxlsxwriter_sys::workbook_close(self.workbook);
-            }
+        if let Some(workbook) = self.take_workbook() {
+            let _ = self.close_workbook(workbook);
         }
     }
 }
@@ -1,4 +1,11 @@
-use super::{convert_bool, Chart, DataValidation, Format, FormatColor, Workbook, XlsxError};
+use super::chart::DEFAULT_CHART_SIZE;
+use super::formula::{col_name, is_plausible_formula};
+use super::image::{is_svg, is_svg_file, recompressed_jpeg};
+use super::{
+    convert_bool, convert_str, error, Chart, ConditionalFormat, DataValidation, Format,
+    FormatColor, Formula, RowWriter, Workbook, XlsxError,
+};
+use std::collections::BTreeMap;
 use std::ffi::CString;
 use std::os::raw::c_char;
 
@@ -23,6 +30,11 @@ impl DateTime {
             second,
         }
     }
+
+    /// Creates a date at midnight, to be written with [Worksheet.write_date()](struct.Worksheet.html#method.write_date).
+    pub fn date(year: i16, month: i8, day: i8) -> DateTime {
+        DateTime::new(year, month, day, 0, 0, 0.)
+    }
 }
 
 impl From<&DateTime> for libxlsxwriter_sys::lxw_datetime {
@@ -38,6 +50,39 @@ impl From<&DateTime> for libxlsxwriter_sys::lxw_datetime {
     }
 }
 
+/// Positioning of an image relative to the cells below it, when the rows and columns of these cells are resized, hidden, inserted or deleted.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum ObjectPosition {
+    /// Move and size the image with the cells.
+    MoveAndSize,
+    /// Move the image with the cells but keep its size. This is the default of Excel for images, so that logos aren't stretched when a column is resized.
+    MoveDontSize,
+    /// Don't move or size the image with the cells.
+    DontMoveDontSize,
+    /// Move and size the image with the cells, with the Excel "Move and size with cells" option unset in the user interface.
+    MoveAndSizeAfter,
+}
+
+impl ObjectPosition {
+    fn value(self) -> u8 {
+        let value = match self {
+            ObjectPosition::MoveAndSize => {
+                libxlsxwriter_sys::lxw_object_position_LXW_OBJECT_MOVE_AND_SIZE
+            }
+            ObjectPosition::MoveDontSize => {
+                libxlsxwriter_sys::lxw_object_position_LXW_OBJECT_MOVE_DONT_SIZE
+            }
+            ObjectPosition::DontMoveDontSize => {
+                libxlsxwriter_sys::lxw_object_position_LXW_OBJECT_DONT_MOVE_DONT_SIZE
+            }
+            ObjectPosition::MoveAndSizeAfter => {
+                libxlsxwriter_sys::lxw_object_position_LXW_OBJECT_MOVE_AND_SIZE_AFTER
+            }
+        };
+        value as u8
+    }
+}
+
 /// Options for modifying images inserted via [Worksheet.insert_image_opt()](struct.Worksheet.html#method.insert_image_opt).
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct ImageOptions {
@@ -49,6 +94,28 @@ pub struct ImageOptions {
     pub x_scale: f64,
     /// Y scale of the image as a decimal.
     pub y_scale: f64,
+    /// Quality, from 1 to 100, to recompress JPEG images to before they are embedded. `None` embeds the images unchanged.
+    ///
+    /// This requires the `image` feature, otherwise inserting a JPEG image with a quality returns an error.
+    /// The recompressed image is only embedded if it is smaller than the original one, and other image formats are always embedded unchanged.
+    /// Recompression drops the metadata of the image, so its resolution is reset to the Excel default of 96 DPI.
+    pub jpeg_quality: Option<u8>,
+    /// Positioning of the image when the cells below it are resized. The default is [ObjectPosition::MoveDontSize](enum.ObjectPosition.html#variant.MoveDontSize),
+    /// the default of Excel for the inserted images.
+    pub object_position: ObjectPosition,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        ImageOptions {
+            x_offset: 0,
+            y_offset: 0,
+            x_scale: 1.,
+            y_scale: 1.,
+            jpeg_quality: None,
+            object_position: ObjectPosition::MoveDontSize,
+        }
+    }
 }
 
 impl From<&ImageOptions> for libxlsxwriter_sys::lxw_image_options {
@@ -61,12 +128,20 @@ impl From<&ImageOptions> for libxlsxwriter_sys::lxw_image_options {
             description: std::ptr::null_mut(),
             url: std::ptr::null_mut(),
             tip: std::ptr::null_mut(),
-            object_position: 0,
+            object_position: options.object_position.value(),
             decorative: 0,
         }
     }
 }
 
+/// Paper size of the printed pages of a worksheet, set with [Worksheet.set_paper()](struct.Worksheet.html#method.set_paper).
+///
+/// `Other` sets any other paper size by its Excel paper code, for example 66 for A2 or 70 for A6.
+///
+/// ### Note
+/// libxlsxwriter only writes the Excel paper code, so custom page dimensions such as 200 x 300 mm can't be set.
+/// Choose the closest larger paper size and fit the worksheet to it with [Worksheet.fit_to_pages()](struct.Worksheet.html#method.fit_to_pages)
+/// or [Worksheet.set_print_scale()](struct.Worksheet.html#method.set_print_scale).
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub enum PaperType {
     PrinterDefault,
@@ -109,8 +184,27 @@ impl PaperType {
     }
 }
 
+/// Common print configuration of a worksheet, applied with [Worksheet.print_preset()](struct.Worksheet.html#method.print_preset).
+///
+/// A preset sets the orientation, the fit to pages and the margins of the printed pages in one call.
+/// The narrow margins are the "Narrow" margins of Excel: 0.25 inch on the left and the right, 0.75 inch on the top and the bottom.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum PrintPreset {
+    /// Landscape orientation with narrow margins, the width of the worksheet fitted to one page and as many pages as needed for the height.
+    /// Suited to wide tables with many rows.
+    OnePageWideLandscape,
+    /// Portrait orientation with the default margins, the width of the worksheet fitted to one page and as many pages as needed for the height.
+    OnePageWidePortrait,
+    /// Portrait orientation with the default margins, the whole worksheet fitted to a single page.
+    OnePagePortrait,
+}
+
+/// Options of a header or a footer used by [Worksheet.set_header_opt()](struct.Worksheet.html#method.set_header_opt) and [Worksheet.set_footer_opt()](struct.Worksheet.html#method.set_footer_opt).
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct HeaderFooterOptions {
+    /// The distance in inches between the header and the top edge of the page, or between the footer and the bottom edge of the page. The default is 0.3 inch.
+    ///
+    /// This margin is independent of the page margins set with [Worksheet.set_margins()](struct.Worksheet.html#method.set_margins).
     pub margin: f64,
 }
 
@@ -149,28 +243,105 @@ impl GridLines {
     }
 }
 
+/// Type of the Excel warnings ignored with [Worksheet.ignore_errors()](struct.Worksheet.html#method.ignore_errors).
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum IgnoreError {
+    /// Numbers stored as text.
+    NumberStoredAsText,
+    /// Formula evaluation errors such as `#DIV/0!`.
+    EvalError,
+    /// Formulas inconsistent with the formulas of the neighbouring cells.
+    FormulaDiffers,
+    /// Formulas which omit the adjacent cells of a range.
+    FormulaRange,
+    /// Unlocked cells containing formulas.
+    FormulaUnlocked,
+    /// Formulas referring to empty cells.
+    EmptyCellReference,
+    /// Values which don't match the data validation list of the cell.
+    ListDataValidation,
+    /// Formulas inconsistent with the calculated column formula of a table.
+    CalculatedColumn,
+    /// Dates stored as text with a two digit year.
+    TwoDigitTextYear,
+}
+
+impl IgnoreError {
+    fn value(self) -> u8 {
+        let value = match self {
+            IgnoreError::NumberStoredAsText => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_NUMBER_STORED_AS_TEXT
+            }
+            IgnoreError::EvalError => libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_EVAL_ERROR,
+            IgnoreError::FormulaDiffers => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_FORMULA_DIFFERS
+            }
+            IgnoreError::FormulaRange => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_FORMULA_RANGE
+            }
+            IgnoreError::FormulaUnlocked => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_FORMULA_UNLOCKED
+            }
+            IgnoreError::EmptyCellReference => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_EMPTY_CELL_REFERENCE
+            }
+            IgnoreError::ListDataValidation => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_LIST_DATA_VALIDATION
+            }
+            IgnoreError::CalculatedColumn => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_CALCULATED_COLUMN
+            }
+            IgnoreError::TwoDigitTextYear => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_TWO_DIGIT_TEXT_YEAR
+            }
+        };
+        value as u8
+    }
+}
+
+/// Options of the worksheet protection set with [Worksheet.protect()](struct.Worksheet.html#method.protect).
+///
+/// The `no_*` fields forbid an action which is allowed by default, and the other fields allow an action which is forbidden by default.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Protection {
+    /// Forbid the selection of locked cells.
     pub no_select_locked_cells: bool,
+    /// Forbid the selection of unlocked cells.
     pub no_select_unlocked_cells: bool,
+    /// Allow formatting cells.
     pub format_cells: bool,
+    /// Allow formatting columns.
     pub format_columns: bool,
+    /// Allow formatting rows.
     pub format_rows: bool,
+    /// Allow inserting columns.
     pub insert_columns: bool,
+    /// Allow inserting rows.
     pub insert_rows: bool,
+    /// Allow inserting hyperlinks.
     pub insert_hyperlinks: bool,
+    /// Allow deleting columns.
     pub delete_columns: bool,
+    /// Allow deleting rows.
     pub delete_rows: bool,
+    /// Allow sorting data.
     pub sort: bool,
+    /// Allow using autofilters.
     pub autofilter: bool,
+    /// Allow using pivot tables.
     pub pivot_tables: bool,
+    /// Allow editing scenarios.
     pub scenarios: bool,
+    /// Allow editing objects.
     pub objects: bool,
+    /// Protect the content of the worksheet.
     pub no_content: bool,
+    /// Protect the objects of the worksheet.
     pub no_objects: bool,
 }
 
 impl Protection {
+    /// Returns the default options, which also forbid selecting locked and unlocked cells.
     pub fn new() -> Protection {
         Protection {
             no_select_locked_cells: true,
@@ -224,6 +395,68 @@ impl From<&Protection> for libxlsxwriter_sys::lxw_protection {
     }
 }
 
+/// Settings of the outline symbols of the grouped rows and columns, set with [Worksheet.set_outline_settings()](struct.Worksheet.html#method.set_outline_settings).
+///
+/// By default the outline symbols are visible, with the summary rows below the groups and the summary columns to the right of them,
+/// and the automatic outline styles are disabled.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct OutlineSettings {
+    visible: bool,
+    symbols_below: bool,
+    symbols_right: bool,
+    auto_style: bool,
+}
+
+impl OutlineSettings {
+    /// Returns the default settings of Excel.
+    pub fn new() -> OutlineSettings {
+        OutlineSettings {
+            visible: true,
+            symbols_below: true,
+            symbols_right: true,
+            auto_style: false,
+        }
+    }
+
+    /// Shows or hides the outline symbols and levels.
+    pub fn set_visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Places the summary row and its symbol below the grouped rows if `true`, or above them if `false`.
+    pub fn set_symbols_below(mut self, symbols_below: bool) -> Self {
+        self.symbols_below = symbols_below;
+        self
+    }
+
+    /// Places the summary column and its symbol to the right of the grouped columns if `true`, or to the left of them if `false`.
+    pub fn set_symbols_right(mut self, symbols_right: bool) -> Self {
+        self.symbols_right = symbols_right;
+        self
+    }
+
+    /// Enables the automatic styles of the summary rows and columns.
+    pub fn set_auto_style(mut self, auto_style: bool) -> Self {
+        self.auto_style = auto_style;
+        self
+    }
+}
+
+impl Default for OutlineSettings {
+    fn default() -> Self {
+        OutlineSettings::new()
+    }
+}
+
+/// The maximum length of a hyperlink URL in Excel.
+const MAX_URL_LENGTH: usize = 2079;
+
+/// The maximum length of a string cell in Excel.
+const MAX_STRING_LENGTH: usize = 32767;
+
+/// The maximum number of fragments of a rich string. libxlsxwriter counts the fragments and their terminating null pointer with a `u8`.
+const MAX_RICH_STRING_FRAGMENTS: usize = 254;
 
 /// Integer data type to represent a column value. Equivalent to `u16`.
 ///
@@ -235,9 +468,544 @@ pub type WorksheetCol = libxlsxwriter_sys::lxw_col_t;
 /// The maximum row in Excel is 1,048,576.
 pub type WorksheetRow = libxlsxwriter_sys::lxw_row_t;
 
-pub type CommentOptions = libxlsxwriter_sys::lxw_comment_options;
+/// A rectangular range of cells, from `(first_row, first_col)` to `(last_row, last_col)` inclusive.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CellRange {
+    pub first_row: WorksheetRow,
+    pub first_col: WorksheetCol,
+    pub last_row: WorksheetRow,
+    pub last_col: WorksheetCol,
+}
+
+impl CellRange {
+    pub fn new(
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+    ) -> CellRange {
+        CellRange {
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+        }
+    }
+
+    /// Returns true if the cell `(row, col)` is inside of the range.
+    pub(crate) fn contains(&self, row: WorksheetRow, col: WorksheetCol) -> bool {
+        (self.first_row..=self.last_row).contains(&row)
+            && (self.first_col..=self.last_col).contains(&col)
+    }
+
+    /// Returns an iterator over the `(row, col)` of every cell of the range in row major order.
+    pub fn cells(&self) -> impl Iterator<Item = (WorksheetRow, WorksheetCol)> {
+        let (first_col, last_col) = (self.first_col, self.last_col);
+        (self.first_row..=self.last_row)
+            .flat_map(move |row| (first_col..=last_col).map(move |col| (row, col)))
+    }
+}
+
+/// The last row of a worksheet, row 1,048,576 in Excel.
+pub const MAX_ROW: WorksheetRow = 1_048_575;
+/// The last column of a worksheet, column XFD in Excel.
+pub const MAX_COL: WorksheetCol = 16_383;
+
+/// Checks that a cell is inside of the 1,048,576 rows and 16,384 columns of a worksheet.
+fn check_cell(row: WorksheetRow, col: WorksheetCol) -> Result<(), XlsxError> {
+    if row > MAX_ROW || col > MAX_COL {
+        return Err(XlsxError::new(
+            error::ROW_OR_COLUMN_OUT_OF_BOUNDS_ERROR_CODE,
+        ));
+    }
+    Ok(())
+}
+
+/// Converts an A1 style cell reference such as `"B2"` or `"$B$2"` into a zero indexed `(row, col)` pair.
+///
+/// Lowercase column letters are accepted. References outside of the 1,048,576 rows and 16,384 columns of a worksheet are errors.
+pub(crate) fn parse_cell_ref(cell: &str) -> Result<(WorksheetRow, WorksheetCol), XlsxError> {
+    let invalid = || XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION);
+    let cell = cell.strip_prefix('$').unwrap_or(cell);
+    let letters = cell.bytes().take_while(|x| x.is_ascii_alphabetic()).count();
+    let (col_part, row_part) = cell.split_at(letters);
+    let row_part = row_part.strip_prefix('$').unwrap_or(row_part);
+    if col_part.is_empty()
+        || col_part.len() > 3
+        || row_part.is_empty()
+        || !row_part.bytes().all(|x| x.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let col = col_part.bytes().fold(0u32, |col, x| {
+        col * 26 + u32::from(x.to_ascii_uppercase() - b'A') + 1
+    });
+    let row: u32 = row_part.parse().map_err(|_| invalid())?;
+    if col == 0 || col > 16_384 || row == 0 || row > 1_048_576 {
+        return Err(invalid());
+    }
+    Ok((row - 1, (col - 1) as WorksheetCol))
+}
+
+/// Converts an A1 style range reference such as `"A1:C10"` into its zero indexed `(first_row, first_col, last_row, last_col)` corners.
+///
+/// Both cells are parsed like `parse_cell_ref()`, and the corners are ordered so `"C10:A1"` is the same range as `"A1:C10"`.
+pub(crate) fn parse_range_ref(
+    range: &str,
+) -> Result<(WorksheetRow, WorksheetCol, WorksheetRow, WorksheetCol), XlsxError> {
+    let (first, last) = match range.find(':') {
+        Some(index) => (&range[..index], &range[index + 1..]),
+        None => {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ))
+        }
+    };
+    let (first_row, first_col) = parse_cell_ref(first)?;
+    let (last_row, last_col) = parse_cell_ref(last)?;
+    Ok((
+        first_row.min(last_row),
+        first_col.min(last_col),
+        first_row.max(last_row),
+        first_col.max(last_col),
+    ))
+}
+
+/// Returns the options to insert an image which was already recompressed.
+fn uncompressed_options(options: &ImageOptions) -> ImageOptions {
+    ImageOptions {
+        jpeg_quality: None,
+        ..options.clone()
+    }
+}
+
+/// Estimates the width in characters of the default 11 point font of a column displaying `texts` in a font of `font_size` points.
+pub(crate) fn estimate_column_width(texts: &[&str], font_size: f64) -> f64 {
+    let longest = texts
+        .iter()
+        .flat_map(|text| text.lines())
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    (longest as f64 * font_size / 11.0 + 1.0).min(255.0)
+}
+
+/// Checks the fragments of a rich string before it is written, see [Worksheet.write_rich_string()](struct.Worksheet.html#method.write_rich_string).
+fn check_rich_string(text: &[(&str, Option<&Format>)]) -> Result<(), XlsxError> {
+    if text.is_empty() || text.iter().any(|x| x.0.is_empty()) {
+        return Err(XlsxError::new(
+            libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+        ));
+    }
+    if text.len() > MAX_RICH_STRING_FRAGMENTS {
+        return Err(XlsxError::new(error::TOO_MANY_FRAGMENTS_ERROR_CODE));
+    }
+    // The XML checked by libxlsxwriter is longer than the text, so a longer text is always rejected.
+    if text.iter().map(|x| x.0.chars().count()).sum::<usize>() > MAX_STRING_LENGTH {
+        return Err(XlsxError::new(error::RICH_STRING_TOO_LONG_ERROR_CODE));
+    }
+    Ok(())
+}
+
+/// Value of a text converted by [Worksheet.write_auto()](struct.Worksheet.html#method.write_auto).
+#[derive(Debug, PartialEq)]
+pub(crate) enum AutoValue {
+    Number(f64),
+    Boolean(bool),
+    Date(DateTime),
+    DateTime(DateTime),
+    String,
+}
+
+/// Converts a text like a spreadsheet import does, see [Worksheet.write_auto()](struct.Worksheet.html#method.write_auto).
+pub(crate) fn infer_auto_value(text: &str) -> AutoValue {
+    let trimmed = text.trim();
+    if trimmed.eq_ignore_ascii_case("TRUE") {
+        return AutoValue::Boolean(true);
+    }
+    if trimmed.eq_ignore_ascii_case("FALSE") {
+        return AutoValue::Boolean(false);
+    }
+    // `f64::from_str` also accepts "inf" and "NaN", which are texts for a spreadsheet, and parses "1e999" as infinity,
+    // which can't be written to a cell.
+    if trimmed.bytes().any(|x| x.is_ascii_digit())
+        && trimmed
+            .bytes()
+            .all(|x| x.is_ascii_digit() || b"+-.eE".contains(&x))
+    {
+        if let Ok(number) = trimmed.parse::<f64>() {
+            if number.is_finite() {
+                return AutoValue::Number(number);
+            }
+        }
+    }
+    parse_iso_datetime(trimmed).unwrap_or(AutoValue::String)
+}
+
+/// Parses an ISO 8601 date `YYYY-MM-DD`, optionally followed by a time `HH:MM` or `HH:MM:SS[.fff]` separated by `T` or a space.
+fn parse_iso_datetime(text: &str) -> Option<AutoValue> {
+    let number = |x: &str| -> Option<u32> {
+        if !x.is_empty() && x.bytes().all(|x| x.is_ascii_digit()) {
+            x.parse().ok()
+        } else {
+            None
+        }
+    };
+    let (date, time) = match text.find(['T', ' ']) {
+        Some(index) => (&text[..index], Some(&text[index + 1..])),
+        None => (text, None),
+    };
+    let mut date_parts = date.split('-');
+    let (year, month, day) = match (
+        date_parts.next(),
+        date_parts.next(),
+        date_parts.next(),
+        date_parts.next(),
+    ) {
+        (Some(year), Some(month), Some(day), None)
+            if year.len() == 4 && month.len() == 2 && day.len() == 2 =>
+        {
+            (number(year)?, number(month)?, number(day)?)
+        }
+        _ => return None,
+    };
+    let leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap_year => 29,
+        2 => 28,
+        _ => return None,
+    };
+    // Excel dates start in 1900.
+    if year < 1900 || day == 0 || day > days_in_month {
+        return None;
+    }
+    let date = DateTime::date(year as i16, month as i8, day as i8);
+    let time = match time {
+        Some(time) => time,
+        None => return Some(AutoValue::Date(date)),
+    };
+    let mut time_parts = time.split(':');
+    let (hour, min, second) = match (
+        time_parts.next(),
+        time_parts.next(),
+        time_parts.next(),
+        time_parts.next(),
+    ) {
+        (Some(hour), Some(min), second, None) if hour.len() == 2 && min.len() == 2 => {
+            let second = match second {
+                Some(second)
+                    if second.len() >= 2
+                        && second.as_bytes()[..2].iter().all(|x| x.is_ascii_digit()) =>
+                {
+                    second.parse::<f64>().ok()?
+                }
+                Some(_) => return None,
+                None => 0.,
+            };
+            (number(hour)?, number(min)?, second)
+        }
+        _ => return None,
+    };
+    if hour > 23 || min > 59 || !(0. ..60.).contains(&second) {
+        return None;
+    }
+    Some(AutoValue::DateTime(DateTime::new(
+        date.year, date.month, date.day, hour as i8, min as i8, second,
+    )))
+}
+
+/// Value of a cell written through a [Worksheet](struct.Worksheet.html).
+///
+/// libxlsxwriter can't change the format of a cell once it is written, so the values are kept to be able to write them again with another format.
+/// `Other` is used for cells which can't be written again, such as URLs, rich strings and array formulas.
+#[derive(Clone)]
+pub(crate) enum CellValue {
+    Number(f64),
+    String(String),
+    Formula(String),
+    FormulaNum(String, f64),
+    FormulaStr(String, String),
+    DateTime(DateTime),
+    Boolean(bool),
+    Blank,
+    Other,
+}
+
+/// A cell written to a worksheet, with the format it was written with or null.
+#[derive(Clone)]
+pub(crate) struct CellRecord {
+    pub(crate) value: CellValue,
+    pub(crate) format: *mut libxlsxwriter_sys::lxw_format,
+}
+
+/// Cells written to a worksheet, keyed by `(row, col)`.
+pub(crate) type WorksheetCells = BTreeMap<(WorksheetRow, WorksheetCol), CellRecord>;
+
+/// Merged ranges and array formulas written to a worksheet, with the format they were written with or null.
+/// Only their top-left cell is kept in the cells of the worksheet.
+pub(crate) type WorksheetRanges = Vec<(CellRange, *mut libxlsxwriter_sys::lxw_format)>;
+
+/// Converts a row height in pixels to points.
+pub(crate) fn row_pixels_to_points(pixels: u32) -> f64 {
+    f64::from(pixels) * 0.75
+}
+
+/// EMUs (English Metric Units) per inch, the unit of the positions and sizes of the drawings of a worksheet.
+pub(crate) const EMU_PER_INCH: u32 = 914_400;
+
+/// EMUs per pixel at the 96 pixels per inch used by Excel.
+pub(crate) const EMU_PER_PIXEL: u32 = EMU_PER_INCH / 96;
+
+/// EMUs per point, with 72 points per inch.
+pub(crate) const EMU_PER_POINT: u32 = EMU_PER_INCH / 72;
+
+/// Converts a row height in EMUs to points.
+pub(crate) fn row_emu_to_points(emu: u32) -> f64 {
+    f64::from(emu) / f64::from(EMU_PER_POINT)
+}
+
+/// Converts a column width in EMUs to characters of the default font, the unit of `Worksheet.set_column()`.
+///
+/// Excel displays a column of `width` characters with `width * 7 + 5` pixels, or `width * 12` pixels for a width under one character.
+pub(crate) fn column_emu_to_width(emu: u32) -> f64 {
+    let pixels = f64::from(emu) / f64::from(EMU_PER_PIXEL);
+    if pixels <= 12.0 {
+        pixels / 12.0
+    } else {
+        (pixels - 5.0) / 7.0
+    }
+}
+
+/// Options for cell comments written with [Worksheet.write_comment_opt()](struct.Worksheet.html#method.write_comment_opt).
+///
+/// The fields which are `None` or `0` use the libxlsxwriter defaults.
+///
+/// libxlsxwriter only supports the font name and size of the comment text: the text color, bold and italic text
+/// and the line color of the comment box can't be set. The box can be highlighted with its background `color` instead.
+#[derive(Clone, PartialEq, PartialOrd, Default)]
+pub struct CommentOptions {
+    /// `Some(true)` shows the comment when the worksheet is opened, `Some(false)` shows it only when the cell is hovered.
+    /// `None` follows [Worksheet.show_comments()](struct.Worksheet.html#method.show_comments).
+    pub visible: Option<bool>,
+    /// The author of the comment. The default is the author set with [Worksheet.set_comments_author()](struct.Worksheet.html#method.set_comments_author).
+    pub author: Option<String>,
+    /// The width of the comment box in pixels. The default is 128.
+    pub width: u16,
+    /// The height of the comment box in pixels. The default is 74.
+    pub height: u16,
+    /// The horizontal scale of the comment box. It is ignored if `width` is set.
+    pub x_scale: f64,
+    /// The vertical scale of the comment box. It is ignored if `height` is set.
+    pub y_scale: f64,
+    /// The background color of the comment box. The default is a pale yellow.
+    pub color: Option<FormatColor>,
+    /// The font of the comment. The default is "Tahoma".
+    pub font_name: Option<String>,
+    /// The font size of the comment. The default is 8.
+    pub font_size: f64,
+    /// The font family of the comment.
+    pub font_family: u8,
+    /// The row of the top left corner of the comment box. Used with `start_col` to move the comment box.
+    pub start_row: WorksheetRow,
+    /// The column of the top left corner of the comment box. Used with `start_row` to move the comment box.
+    pub start_col: WorksheetCol,
+    /// The horizontal offset of the comment box in pixels.
+    pub x_offset: i32,
+    /// The vertical offset of the comment box in pixels.
+    pub y_offset: i32,
+}
+
+impl CommentOptions {
+    pub fn new() -> CommentOptions {
+        CommentOptions::default()
+    }
+
+    pub(crate) fn to_c_struct(&self) -> Result<CCommentOptions, XlsxError> {
+        let mut author = self.author.as_deref().map(convert_str).transpose()?;
+        let mut font_name = self.font_name.as_deref().map(convert_str).transpose()?;
+        let visible = match self.visible {
+            None => libxlsxwriter_sys::lxw_comment_display_types_LXW_COMMENT_DISPLAY_DEFAULT,
+            Some(false) => libxlsxwriter_sys::lxw_comment_display_types_LXW_COMMENT_DISPLAY_HIDDEN,
+            Some(true) => libxlsxwriter_sys::lxw_comment_display_types_LXW_COMMENT_DISPLAY_VISIBLE,
+        };
+        let comment_options = libxlsxwriter_sys::lxw_comment_options {
+            visible: visible as u8,
+            author: author
+                .as_mut()
+                .map(|x| x.as_mut_ptr())
+                .unwrap_or(std::ptr::null_mut()) as *mut c_char,
+            width: self.width,
+            height: self.height,
+            x_scale: self.x_scale,
+            y_scale: self.y_scale,
+            color: self.color.map(|x| x.value()).unwrap_or(0),
+            font_name: font_name
+                .as_mut()
+                .map(|x| x.as_mut_ptr())
+                .unwrap_or(std::ptr::null_mut()) as *mut c_char,
+            font_size: self.font_size,
+            font_family: self.font_family,
+            start_row: self.start_row,
+            start_col: self.start_col,
+            x_offset: self.x_offset,
+            y_offset: self.y_offset,
+        };
+        Ok(CCommentOptions {
+            author,
+            font_name,
+            comment_options,
+        })
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) struct CCommentOptions {
+    author: Option<Vec<u8>>,
+    font_name: Option<Vec<u8>>,
+    pub(crate) comment_options: libxlsxwriter_sys::lxw_comment_options,
+}
+
 pub type RowColOptions = libxlsxwriter_sys::lxw_row_col_options;
 
+/// A value which can be written to a cell with [Worksheet.write()](struct.Worksheet.html#method.write) and [Worksheet.write_row()](struct.Worksheet.html#method.write_row).
+///
+/// `None` writes a blank cell when a format is given, and nothing otherwise.
+pub trait CellWrite {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError>;
+}
+
+impl CellWrite for f64 {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        worksheet.write_number(row, col, *self, format)
+    }
+}
+
+impl CellWrite for i32 {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        worksheet.write_number(row, col, f64::from(*self), format)
+    }
+}
+
+impl CellWrite for u32 {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        worksheet.write_number(row, col, f64::from(*self), format)
+    }
+}
+
+impl CellWrite for bool {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        worksheet.write_boolean(row, col, *self, format)
+    }
+}
+
+impl CellWrite for str {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        worksheet.write_string(row, col, self, format)
+    }
+}
+
+impl CellWrite for String {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        worksheet.write_string(row, col, self, format)
+    }
+}
+
+impl CellWrite for DateTime {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        worksheet.write_datetime(row, col, self, format)
+    }
+}
+
+impl CellWrite for Formula {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        worksheet.write_formula(row, col, self.as_str(), format)
+    }
+}
+
+impl<T: CellWrite + ?Sized> CellWrite for &T {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        (**self).write_cell(worksheet, row, col, format)
+    }
+}
+
+impl<T: CellWrite> CellWrite for Option<T> {
+    fn write_cell(
+        &self,
+        worksheet: &mut Worksheet,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match self {
+            Some(value) => value.write_cell(worksheet, row, col, format),
+            None if format.is_some() => worksheet.write_blank(row, col, format),
+            None => Ok(()),
+        }
+    }
+}
+
 /// The Worksheet object represents an Excel worksheet. It handles operations such as writing data to cells or formatting worksheet layout.
 ///
 /// A Worksheet object isn't created directly. Instead a worksheet is created by calling the `workbook.add_worksheet()` function from a [Workbook](struct.Workbook.html) object:
@@ -247,11 +1015,17 @@ pub type RowColOptions = libxlsxwriter_sys::lxw_row_col_options;
 /// let workbook = Workbook::new("test-worksheet.xlsx");
 /// let mut worksheet = workbook.add_worksheet(None)?;
 /// worksheet.write_string(0, 0, "Hello, excel", None)?;
-/// workbook.close()
+/// workbook.close()?;
+/// Ok(())
 /// # }
 /// ```
 /// Please read [original libxlsxwriter document](https://libxlsxwriter.github.io/worksheet_8h.html) for description missing functions.
 /// Most of this document is based on libxlsxwriter document.
+///
+/// The rows and columns are zero indexed. The last cell of a worksheet is at row [MAX_ROW](constant.MAX_ROW.html), 1,048,575,
+/// and column [MAX_COL](constant.MAX_COL.html), 16,383, which is cell XFD1048576 in Excel.
+/// The `write_*` functions return an error for the cells after them, for which
+/// [XlsxError.is_row_or_column_out_of_bounds()](struct.XlsxError.html#method.is_row_or_column_out_of_bounds) is `true`.
 pub struct Worksheet<'a> {
     pub(crate) _workbook: &'a Workbook,
     pub(crate) worksheet: *mut libxlsxwriter_sys::lxw_worksheet,
@@ -266,7 +1040,8 @@ impl<'a> Worksheet<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_comment(0, 0, "This is some comment text")?;
     /// worksheet.write_comment(1, 0, "This cell also has a comment")?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     pub fn write_comment(
@@ -275,12 +1050,13 @@ impl<'a> Worksheet<'a> {
         col: WorksheetCol,
         text: &str,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_comment(
                 self.worksheet,
                 row,
                 col,
-                CString::new(text).unwrap().as_c_str().as_ptr(),
+                CString::new(text)?.as_c_str().as_ptr(),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
@@ -290,20 +1066,47 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function is the same as [Worksheet.write_comment()](struct.Worksheet.html#method.write_comment) except that it takes options to set the author, the visibility and the size of the comment:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_comment_opt-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_number(0, 0, 42.0, None)?;
+    /// worksheet.write_comment_opt(
+    ///     0,
+    ///     0,
+    ///     "Checked by the reviewer",
+    ///     &CommentOptions {
+    ///         visible: Some(true),
+    ///         author: Some("Reviewer".to_string()),
+    ///         width: 200,
+    ///         height: 100,
+    ///         color: Some(FormatColor::Cyan),
+    ///         ..CommentOptions::default()
+    ///     },
+    /// )?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// A comment is independent of the value of the cell, so a cell can have both a value and a comment.
     pub fn write_comment_opt(
         &mut self,
         row: WorksheetRow,
         col: WorksheetCol,
         text: &str,
-        options: &mut CommentOptions,
+        options: &CommentOptions,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
+        let mut options = options.to_c_struct()?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_comment_opt(
                 self.worksheet,
                 row,
                 col,
-                CString::new(text).unwrap().as_c_str().as_ptr(),
-                options,
+                CString::new(text)?.as_c_str().as_ptr(),
+                &mut options.comment_options,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
@@ -313,32 +1116,143 @@ impl<'a> Worksheet<'a> {
         }
     }
 
-    /// This function writes numeric types to the cell specified by row and column:
+    /// This function is used to make all cell comments visible when a worksheet is opened:
     /// ```rust
     /// # use xlsxwriter::*;
     /// # fn main() -> Result<(), XlsxError> {
-    /// # let workbook = Workbook::new("test-worksheet_write_number-1.xlsx");
+    /// # let workbook = Workbook::new("test-worksheet_show_comments-1.xlsx");
     /// # let mut worksheet = workbook.add_worksheet(None)?;
-    /// worksheet.write_number(0, 0, 123456.0, None)?;
-    /// worksheet.write_number(1, 0, 2.3451, None)?;
-    /// # workbook.close()
+    /// worksheet.write_comment(0, 0, "This comment is visible")?;
+    /// worksheet.show_comments();
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
-    /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_number-1.png)
-    ///
-    /// The native data type for all numbers in Excel is a IEEE-754 64-bit double-precision floating point, which is also the default type used by worksheet_write_number.
     ///
-    /// The format parameter is used to apply formatting to the cell. This parameter can be `None` to indicate no formatting or it can be a Format object.
-    ///
-    /// ```rust
-    /// # use xlsxwriter::*;
+    /// ### Note
+    /// libxlsxwriter doesn't support the Excel page setup option to print comments "At end of sheet" or "As displayed on sheet".
+    /// Visible comments are shown on screen but they aren't printed unless that option is changed in Excel.
+    pub fn show_comments(&mut self) {
+        unsafe {
+            libxlsxwriter_sys::worksheet_show_comments(self.worksheet);
+        }
+    }
+
+    /// This function is used to set the default author of all cell comments in a worksheet:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_comments_author-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_comments_author("Ruby")?;
+    /// worksheet.write_comment(0, 0, "This comment is written by Ruby")?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// An individual comment author can be set with [CommentOptions](struct.CommentOptions.html).
+    /// This author overrides the default author of the workbook set with [Workbook.set_default_comment_author()](struct.Workbook.html#method.set_default_comment_author).
+    pub fn set_comments_author(&mut self, author: &str) -> Result<(), XlsxError> {
+        let author_vec = convert_str(author)?;
+        unsafe {
+            libxlsxwriter_sys::worksheet_set_comments_author(
+                self.worksheet,
+                author_vec.as_ptr() as *const c_char,
+            );
+        }
+        self._workbook.const_str.borrow_mut().push(author_vec);
+        Ok(())
+    }
+
+    /// This function writes any value implementing [CellWrite](trait.CellWrite.html) with the matching `write_*` function.
+    /// `Option` values write a blank cell for `None`, or nothing at all when there is no format:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write(0, 0, "Name", None)?;
+    /// worksheet.write(0, 1, &123.0, None)?;
+    /// worksheet.write(0, 2, &Some(true), None)?;
+    /// worksheet.write(0, 3, &None::<f64>, None)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write<T: CellWrite + ?Sized>(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        value: &T,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        value.write_cell(self, row, col, format)
+    }
+
+    /// This function writes the values in adjacent cells of a row, starting at `first_col`, with [Worksheet.write()](struct.Worksheet.html#method.write):
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_row-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_row(0, 0, &[Some(1.0), None, Some(3.0)], None)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_row<T: CellWrite>(
+        &mut self,
+        row: WorksheetRow,
+        first_col: WorksheetCol,
+        values: &[T],
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        for (i, value) in values.iter().enumerate() {
+            self.write(row, first_col + i as WorksheetCol, value, format)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a [RowWriter](struct.RowWriter.html) which writes consecutive rows from `start_row`, for example to convert a large CSV file in
+    /// the constant memory mode of [WorkbookOptions](struct.WorkbookOptions.html).
+    ///
+    /// In the constant memory mode the rows before the last row written to the worksheet are already flushed to disk,
+    /// and any value written to them is lost, so `start_row` must be after the rows already written.
+    pub fn row_writer<'b>(&'b mut self, start_row: WorksheetRow) -> RowWriter<'a, 'b> {
+        RowWriter {
+            worksheet: self,
+            row: start_row,
+        }
+    }
+
+    /// This function writes numeric types to the cell specified by row and column:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_number-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_number(0, 0, 123456.0, None)?;
+    /// worksheet.write_number(1, 0, 2.3451, None)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_number-1.png)
+    ///
+    /// The native data type for all numbers in Excel is a IEEE-754 64-bit double-precision floating point, which is also the default type used by worksheet_write_number.
+    ///
+    /// The format parameter is used to apply formatting to the cell. This parameter can be `None` to indicate no formatting or it can be a Format object.
+    ///
+    /// ```rust
+    /// # use xlsxwriter::*;
     /// # fn main() -> Result<(), XlsxError> {
     /// # let workbook = Workbook::new("test-worksheet_write_number-2.xlsx");
     /// let format = workbook.add_format()
     ///     .set_num_format("$#,##0.00");
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_number(0, 0, 1234.567, Some(&format))?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_number-2.png)
@@ -352,6 +1266,7 @@ impl<'a> Worksheet<'a> {
         number: f64,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_number(
                 self.worksheet,
@@ -361,6 +1276,7 @@ impl<'a> Worksheet<'a> {
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_cell(row, col, CellValue::Number(number), format);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -368,6 +1284,34 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function writes a fraction as a percentage, with a number format showing `decimals` decimal places:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_percent-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_percent(0, 0, 0.25, 0, None)?; // 25%
+    /// worksheet.write_percent(1, 0, 0.1234, 1, None)?; // 12.3%
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Excel stores percentages as fractions, so `fraction` is the value divided by 100: 0.25 is displayed as "25%", and 25.0 as "2500%".
+    /// The stored value is `fraction` itself, which is what formulas referring to the cell use.
+    ///
+    /// The percent number format replaces the number format of `format`, and the other properties of `format` are kept.
+    pub fn write_percent(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        fraction: f64,
+        decimals: u8,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        let percent = self._workbook.percent_format(decimals, format);
+        self.write_number(row, col, fraction, Some(&percent))
+    }
+
     /// This function writes a string to the cell specified by row and column:
     /// ```rust
     /// # use xlsxwriter::*;
@@ -375,7 +1319,8 @@ impl<'a> Worksheet<'a> {
     /// # let workbook = Workbook::new("test-worksheet_write_string-1.xlsx");
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_string(0, 0, "This phrase is English!", None)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_string-1.png)
@@ -390,7 +1335,8 @@ impl<'a> Worksheet<'a> {
     ///     .set_bold();
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_string(0, 0, "This phrase is Bold!", Some(&format))?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_string-2.png)
@@ -402,7 +1348,8 @@ impl<'a> Worksheet<'a> {
     /// # let workbook = Workbook::new("test-worksheet_write_string-3.xlsx");
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_string(0, 0, "こんにちは、世界！", None)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_string-3.png)
@@ -413,15 +1360,17 @@ impl<'a> Worksheet<'a> {
         text: &str,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_string(
                 self.worksheet,
                 row,
                 col,
-                CString::new(text).unwrap().as_c_str().as_ptr(),
+                CString::new(text)?.as_c_str().as_ptr(),
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_cell(row, col, CellValue::String(text.to_string()), format);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -429,6 +1378,43 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function writes a string with several lines separated by `\n` to the cell specified by row and column,
+    /// with a format which wraps the text so the lines are displayed on separate lines:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_multiline-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_multiline(0, 0, "221B Baker Street\nLondon NW1 6XE", None)?;
+    /// worksheet.set_row(0, 30.0, None)?; // two lines of the default font
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Without the text wrap property of the format Excel displays the string on a single line. `format` is used as is if it already wraps the text,
+    /// otherwise it isn't modified and a format combining it with the text wrap property is created with [Format.merge()](struct.Format.html#method.merge),
+    /// once per workbook and reused by the next calls with the same format.
+    ///
+    /// The height of the row may need adjusting to display all the lines: Excel doesn't always resize a row to its wrapped text when opening a file,
+    /// and never resizes a row whose height was set. Set the height with [Worksheet.set_row()](struct.Worksheet.html#method.set_row), about 15 points per line of the default font.
+    pub fn write_multiline(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        text: &str,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match format {
+            Some(format) if unsafe { (*format.format).text_wrap } != 0 => {
+                self.write_string(row, col, text, Some(format))
+            }
+            _ => {
+                let wrapped = self._workbook.wrapped_format(format);
+                self.write_string(row, col, text, Some(&wrapped))
+            }
+        }
+    }
+
     /// This function writes a formula or function to the cell specified by row and column:
     /// ```rust
     /// # use xlsxwriter::*;
@@ -441,7 +1427,8 @@ impl<'a> Worksheet<'a> {
     /// worksheet.write_formula(3, 0, "=IF(A3>1,\"Yes\", \"No\")", None)?;
     /// worksheet.write_formula(4, 0, "=AVERAGE(1, 2, 3, 4)", None)?;
     /// worksheet.write_formula(5, 0, "=DATEVALUE(\"1-Jan-2013\")", None)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_formula-1.png)
@@ -460,10 +1447,14 @@ impl<'a> Worksheet<'a> {
     /// worksheet.write_formula(0, 0, "=SUM(1, 2, 3)", None)?;
     /// // NO. Error on load.
     /// worksheet.write_formula(1, 0, "=SUM(1; 2; 3)", None)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// See also [Working with Formulas](https://libxlsxwriter.github.io/working_with_formulas.html).
+    ///
+    /// Formulas aren't checked by default. The `validate_formulas` option of [WorkbookOptions](struct.WorkbookOptions.html) enables a best-effort check
+    /// of the formulas, which returns an error for obviously broken formulas such as `=SUM(A1:A5`.
     pub fn write_formula(
         &mut self,
         row: WorksheetRow,
@@ -471,15 +1462,18 @@ impl<'a> Worksheet<'a> {
         formula: &str,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
+        self.check_formula(formula)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_formula(
                 self.worksheet,
                 row,
                 col,
-                CString::new(formula).unwrap().as_c_str().as_ptr(),
+                CString::new(formula)?.as_c_str().as_ptr(),
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_cell(row, col, CellValue::Formula(formula.to_string()), format);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -497,7 +1491,8 @@ impl<'a> Worksheet<'a> {
     /// # let workbook = Workbook::new("test-worksheet_write_array_formula-1.xlsx");
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_array_formula(4, 0, 6, 0, "{=TREND(C5:C7,B5:B7)}", None)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// If the array formula returns a single value then the first_ and last_ parameters should be the same:
@@ -507,9 +1502,13 @@ impl<'a> Worksheet<'a> {
     /// # let workbook = Workbook::new("test-worksheet_write_array_formula-2.xlsx");
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_array_formula(1, 0, 1, 0, "{=SUM(B1:C1*B2:C2)}", None)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// ### Note
+    /// The version of libxlsxwriter used by this crate doesn't support the dynamic array formulas of Excel 365, so there is no `write_dynamic_array_formula()` function: functions such as `FILTER()` or `UNIQUE()` have to be written as a classic array formula over a fixed range.
     pub fn write_array_formula(
         &mut self,
         first_row: WorksheetRow,
@@ -519,6 +1518,9 @@ impl<'a> Worksheet<'a> {
         formula: &str,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        check_cell(first_row, first_col)?;
+        check_cell(last_row, last_col)?;
+        self.check_formula(formula)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_array_formula(
                 self.worksheet,
@@ -526,10 +1528,18 @@ impl<'a> Worksheet<'a> {
                 first_col,
                 last_row,
                 last_col,
-                CString::new(formula).unwrap().as_c_str().as_ptr(),
+                CString::new(formula)?.as_c_str().as_ptr(),
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_range(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    CellValue::Other,
+                    format,
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -547,7 +1557,8 @@ impl<'a> Worksheet<'a> {
     /// let datetime_format = workbook.add_format()
     ///     .set_num_format("mmm d yyyy hh:mm AM/PM");
     /// worksheet.write_datetime(1, 0, &datetime, Some(&datetime_format))?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_datetime-1.png)
@@ -555,6 +1566,9 @@ impl<'a> Worksheet<'a> {
     /// The `format` parameter should be used to apply formatting to the cell using a [Format](struct.Format.html) object as shown above. Without a date format the datetime will appear as a number only.
     ///
     /// See [Working with Dates and Times](https://libxlsxwriter.github.io/working_with_dates.html) for more information about handling dates and times in libxlsxwriter.
+    ///
+    /// The datetime is stored as a serial number of the 1900 date system, where 1 is January 1, 1900. See the note on the date system of
+    /// [WorkbookOptions](struct.WorkbookOptions.html).
     pub fn write_datetime(
         &mut self,
         row: WorksheetRow,
@@ -562,6 +1576,7 @@ impl<'a> Worksheet<'a> {
         datetime: &DateTime,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
         unsafe {
             let mut xls_datetime: libxlsxwriter_sys::lxw_datetime = datetime.into();
             let result = libxlsxwriter_sys::worksheet_write_datetime(
@@ -572,6 +1587,7 @@ impl<'a> Worksheet<'a> {
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_cell(row, col, CellValue::DateTime(datetime.clone()), format);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -579,6 +1595,86 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function writes a date to the cell specified by row and column. Unlike [Worksheet.write_datetime()](struct.Worksheet.html#method.write_datetime),
+    /// it applies a `yyyy-mm-dd` date format when `format` is `None`, so the date isn't displayed as a serial number:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_date-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_date(0, 0, &DateTime::date(2024, 3, 15), None)?; // 2024-03-15
+    /// let long_date = workbook.add_format().set_num_format("d mmmm yyyy");
+    /// worksheet.write_date(1, 0, &DateTime::date(2024, 3, 15), Some(&long_date))?; // 15 March 2024
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// When a format is given it is used as is, so it should contain a date number format.
+    /// The time of `date` is written too: it isn't displayed by the default format but it is kept in the cell value.
+    pub fn write_date(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        date: &DateTime,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match format {
+            Some(format) => self.write_datetime(row, col, date, Some(format)),
+            None => {
+                let date_format = self._workbook.default_date_format();
+                self.write_datetime(row, col, date, Some(&date_format))
+            }
+        }
+    }
+
+    /// This function writes a text to a cell after converting it like a spreadsheet import does, which is handy to write the fields of a CSV file:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_auto-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_auto(0, 0, "1250.5", None)?; // number
+    /// worksheet.write_auto(0, 1, "2024-03-15", None)?; // date
+    /// worksheet.write_auto(0, 2, "2024-03-15T08:30:00", None)?; // date and time
+    /// worksheet.write_auto(0, 3, "TRUE", None)?; // boolean
+    /// worksheet.write_auto(0, 4, "North", None)?; // string
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The text is converted with the following rules, after removing the leading and trailing whitespace:
+    /// * `TRUE` and `FALSE`, in any case, are written as booleans.
+    /// * Decimal numbers such as `42`, `-3.5` or `1e6` are written as numbers. Leading zeros are lost like in Excel, so `"00123"` is written as `123`.
+    ///   Numbers too large for a `f64`, such as `1e999`, are written as strings.
+    /// * ISO 8601 dates `YYYY-MM-DD` from the year 1900 are written as dates with the `yyyy-mm-dd` format of [Worksheet.write_date()](struct.Worksheet.html#method.write_date),
+    ///   and dates followed by a time `HH:MM` or `HH:MM:SS`, separated by `T` or a space, with a `yyyy-mm-dd hh:mm:ss` format. Time zones aren't supported.
+    /// * Any other text is written unchanged as a string.
+    ///
+    /// When `format` is given it is used for every type, so it should contain a date number format for dates.
+    /// To disable the conversion for a column, such as a column of IDs or of codes which look like numbers, write its values with
+    /// [Worksheet.write_string()](struct.Worksheet.html#method.write_string) instead.
+    pub fn write_auto(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        text: &str,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match infer_auto_value(text) {
+            AutoValue::Number(number) => self.write_number(row, col, number, format),
+            AutoValue::Boolean(value) => self.write_boolean(row, col, value, format),
+            AutoValue::Date(date) => self.write_date(row, col, &date, format),
+            AutoValue::DateTime(datetime) => match format {
+                Some(format) => self.write_datetime(row, col, &datetime, Some(format)),
+                None => {
+                    let datetime_format = self._workbook.default_datetime_format();
+                    self.write_datetime(row, col, &datetime, Some(&datetime_format))
+                }
+            },
+            AutoValue::String => self.write_string(row, col, text, format),
+        }
+    }
+
     /// This function is used to write a URL/hyperlink to a worksheet cell specified by row and column.
     /// The format parameter is used to apply formatting to the cell. This parameter can be `None` to indicate no formatting or it can be a [Format](struct.Format.html) object. The typical worksheet format for a hyperlink is a blue underline:
     /// ```rust
@@ -589,7 +1685,8 @@ impl<'a> Worksheet<'a> {
     /// let url_format = workbook.add_format()
     ///     .set_underline(FormatUnderline::Single).set_font_color(FormatColor::Blue);
     /// worksheet.write_url(0, 0, "http://libxlsxwriter.github.io", Some(&url_format))?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     ///
@@ -605,7 +1702,8 @@ impl<'a> Worksheet<'a> {
     /// worksheet.write_url(1, 0, "http://www.python.org/", Some(&url_format))?;
     /// worksheet.write_url(2, 0, "https://www.python.org/", Some(&url_format))?;
     /// worksheet.write_url(3, 0, "mailto:foo@example.com", Some(&url_format))?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     ///
@@ -619,7 +1717,8 @@ impl<'a> Worksheet<'a> {
     /// #   .set_underline(FormatUnderline::Single).set_font_color(FormatColor::Blue);
     /// worksheet.write_url(0, 0, "http://libxlsxwriter.github.io", Some(&url_format))?;
     /// worksheet.write_string(0, 0, "Read the documentation.", Some(&url_format))?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     ///
@@ -642,9 +1741,17 @@ impl<'a> Worksheet<'a> {
     /// worksheet.write_url(6, 0, "external:..\\foo.xlsx", Some(&url_format))?;
     /// worksheet.write_url(7, 0, "external:..\\foo.xlsx#Sheet2!A1", Some(&url_format))?;
     /// worksheet.write_url(8, 0, "external:\\\\NET\\share\\foo.xlsx", Some(&url_format))?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// A URL without a scheme such as `"reports/2020.html"` is stored as it is and resolved by Excel against the
+    /// `hyperlink_base` of [Workbook.set_properties()](struct.Workbook.html#method.set_properties),
+    /// or against the location of the file when there is no hyperlink base.
+    ///
+    /// Excel limits URLs to 2079 characters, not counting the `internal:` and `external:` prefixes. Longer URLs return an error
+    /// instead of being truncated. Excel also limits a worksheet to 65,530 hyperlinks: the following hyperlinks return an error and aren't written.
     pub fn write_url(
         &mut self,
         row: WorksheetRow,
@@ -652,15 +1759,26 @@ impl<'a> Worksheet<'a> {
         url: &str,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
+        let link = url
+            .strip_prefix("internal:")
+            .or_else(|| url.strip_prefix("external:"))
+            .unwrap_or(url);
+        if link.chars().count() > MAX_URL_LENGTH {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_MAX_URL_LENGTH_EXCEEDED,
+            ));
+        }
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_url(
                 self.worksheet,
                 row,
                 col,
-                CString::new(url).unwrap().as_c_str().as_ptr(),
+                CString::new(url)?.as_c_str().as_ptr(),
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_cell(row, col, CellValue::Other, format);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -676,7 +1794,8 @@ impl<'a> Worksheet<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_boolean(0, 0, true, None)?;
     /// worksheet.write_boolean(1, 0, false, None)?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     pub fn write_boolean(
@@ -686,6 +1805,7 @@ impl<'a> Worksheet<'a> {
         value: bool,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_boolean(
                 self.worksheet,
@@ -695,6 +1815,7 @@ impl<'a> Worksheet<'a> {
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_cell(row, col, CellValue::Boolean(value), format);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -711,7 +1832,8 @@ impl<'a> Worksheet<'a> {
     /// # let mut url_format = workbook.add_format()
     /// #   .set_underline(FormatUnderline::Single).set_font_color(FormatColor::Blue);
     /// worksheet.write_blank(1, 1, Some(&url_format));
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// This function is used to add formatting to a cell which doesn't contain a string or number value.
@@ -725,6 +1847,7 @@ impl<'a> Worksheet<'a> {
         col: WorksheetCol,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_blank(
                 self.worksheet,
@@ -733,6 +1856,7 @@ impl<'a> Worksheet<'a> {
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_cell(row, col, CellValue::Blank, format);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -749,7 +1873,8 @@ impl<'a> Worksheet<'a> {
     /// # let mut url_format = workbook.add_format()
     /// #   .set_underline(FormatUnderline::Single).set_font_color(FormatColor::Blue);
     /// worksheet.write_formula_num(1, 1, "=1 + 2", None, 3.0);
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// Libxlsxwriter doesn't calculate the value of a formula and instead stores the value 0 as the formula result.
@@ -773,16 +1898,24 @@ impl<'a> Worksheet<'a> {
         format: Option<&Format>,
         number: f64,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
+        self.check_formula(formula)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_formula_num(
                 self.worksheet,
                 row,
                 col,
-                CString::new(formula).unwrap().as_c_str().as_ptr(),
+                CString::new(formula)?.as_c_str().as_ptr(),
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
                 number,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_cell(
+                    row,
+                    col,
+                    CellValue::FormulaNum(formula.to_string(), number),
+                    format,
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -799,7 +1932,8 @@ impl<'a> Worksheet<'a> {
     /// # let mut url_format = workbook.add_format()
     /// #   .set_underline(FormatUnderline::Single).set_font_color(FormatColor::Blue);
     /// worksheet.write_formula_str(1, 1, "=\"A\" & \"B\"", None, "AB");
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// The worksheet_write_formula_str() function is similar to the worksheet_write_formula_num() function except it
@@ -808,6 +1942,9 @@ impl<'a> Worksheet<'a> {
     ///
     /// One place where the worksheet_write_formula_str() function may be required is to specify an empty result which
     /// will force a recalculation of the formula when loaded in LibreOffice.
+    ///
+    /// libxlsxwriter has no cached result type for boolean formulas. Formulas returning a boolean can be written with a
+    /// `"TRUE"` or `"FALSE"` string result, which is what applications that don't recalculate formulas display.
     #[allow(clippy::too_many_arguments)]
     pub fn write_formula_str(
         &mut self,
@@ -817,16 +1954,20 @@ impl<'a> Worksheet<'a> {
         format: Option<&Format>,
         result: &str,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
+        self.check_formula(formula)?;
+        let cell_value = CellValue::FormulaStr(formula.to_string(), result.to_string());
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_formula_str(
                 self.worksheet,
                 row,
                 col,
-                CString::new(formula).unwrap().as_c_str().as_ptr(),
+                CString::new(formula)?.as_c_str().as_ptr(),
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
-                CString::new(result).unwrap().as_c_str().as_ptr(),
+                CString::new(result)?.as_c_str().as_ptr(),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_cell(row, col, cell_value, format);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -854,7 +1995,8 @@ impl<'a> Worksheet<'a> {
     ///     ],
     ///     None
     /// )?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_richtext-1.png)
@@ -874,6 +2016,17 @@ impl<'a> Worksheet<'a> {
     ///
     /// ### Note
     ///  Excel doesn't allow the use of two consecutive formats in a rich string or an empty string fragment. For either of these conditions a warning is raised and the input to `worksheet.write_rich_string()` is ignored.
+    ///
+    /// An empty fragment list or a fragment with an empty string returns an error without writing the cell.
+    ///
+    /// A rich string is limited to 32,767 characters like any other string cell, but libxlsxwriter checks the length of the XML of the rich string:
+    /// the text of the fragments, with `&`, `<` and `>` escaped, and the markup of the font of each fragment, which is about 100 characters
+    /// for a fragment with the default font. So the text of a rich string is limited to somewhat less than 32,767 characters, and a longer
+    /// string returns an error for which [XlsxError.is_rich_string_too_long()](struct.XlsxError.html#method.is_rich_string_too_long) is `true`,
+    /// without writing the cell.
+    ///
+    /// libxlsxwriter supports at most 254 fragments. A rich string with more fragments returns an error for which
+    /// [XlsxError.is_too_many_fragments()](struct.XlsxError.html#method.is_too_many_fragments) is `true`, without writing the cell.
     pub fn write_rich_string(
         &mut self,
         row: WorksheetRow,
@@ -881,16 +2034,13 @@ impl<'a> Worksheet<'a> {
         text: &[(&str, Option<&Format>)],
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        check_cell(row, col)?;
+        check_rich_string(text)?;
+
         let mut c_str: Vec<Vec<u8>> = text
             .iter()
-            .map(|x| {
-                CString::new(x.0)
-                    .unwrap()
-                    .as_c_str()
-                    .to_bytes_with_nul()
-                    .to_vec()
-            })
-            .collect();
+            .map(|x| convert_str(x.0))
+            .collect::<Result<_, _>>()?;
 
         let mut rich_text: Vec<_> = text
             .iter()
@@ -914,28 +2064,46 @@ impl<'a> Worksheet<'a> {
                 rich_text_ptr.as_mut_ptr(),
                 format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
             );
-            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
-                Ok(())
-            } else {
-                Err(XlsxError::new(result))
+            match result {
+                libxlsxwriter_sys::lxw_error_LXW_NO_ERROR => {
+                    self.record_cell(row, col, CellValue::Other, format);
+                    Ok(())
+                }
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_MAX_STRING_LENGTH_EXCEEDED => {
+                    Err(XlsxError::new(error::RICH_STRING_TOO_LONG_ERROR_CODE))
+                }
+                _ => Err(XlsxError::new(result)),
             }
         }
     }
 
+    /// This function is used to change the default properties of a row. The height is in points, the Excel default is 15 points.
+    /// Use [Worksheet.set_row_pixels()](struct.Worksheet.html#method.set_row_pixels) to set the height in pixels.
+    ///
+    /// The format is applied to the cells of the row which don't have a format.
+    /// A format passed when writing a cell takes precedence over the row format, which takes precedence over the column format.
+    ///
+    /// The height takes precedence over the default height of [Worksheet.set_default_row()](struct.Worksheet.html#method.set_default_row), in any call order.
+    ///
+    /// Each call replaces all the properties of the row: calling it with `None` to change the height removes the format set before,
+    /// and the options of [Worksheet.set_row_opt()](struct.Worksheet.html#method.set_row_opt) are reset.
+    /// Use [Worksheet.set_row_height_only()](struct.Worksheet.html#method.set_row_height_only) to keep them.
     pub fn set_row(
         &mut self,
         row: WorksheetRow,
         height: f64,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        let format = format.map(|x| x.format).unwrap_or(std::ptr::null_mut());
         unsafe {
-            let result = libxlsxwriter_sys::worksheet_set_row(
-                self.worksheet,
-                row,
-                height,
-                format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
-            );
+            let result = libxlsxwriter_sys::worksheet_set_row(self.worksheet, row, height, format);
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                let options = RowColOptions {
+                    hidden: 0,
+                    level: 0,
+                    collapsed: 0,
+                };
+                self.record_row(row, format, options);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -943,6 +2111,24 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function is the same as [Worksheet.set_row()](struct.Worksheet.html#method.set_row) with additional options to hide, group or collapse the row:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_row_opt-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut options = RowColOptions {
+    ///     hidden: 1,
+    ///     level: 1,
+    ///     collapsed: 0,
+    /// };
+    /// worksheet.set_row_opt(1, 15.0, None, &mut options)?;
+    /// worksheet.set_row_opt(2, 15.0, None, &mut options)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The `level` is the outline level of the row, from 0 to 7. Adjacent rows with the same level are grouped.
     pub fn set_row_opt(
         &mut self,
         row: WorksheetRow,
@@ -950,15 +2136,17 @@ impl<'a> Worksheet<'a> {
         format: Option<&Format>,
         options: &mut RowColOptions,
     ) -> Result<(), XlsxError> {
+        let format = format.map(|x| x.format).unwrap_or(std::ptr::null_mut());
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_row_opt(
                 self.worksheet,
                 row,
                 height,
-                format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
+                format,
                 options,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_row(row, format, *options);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -966,6 +2154,149 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function changes the height of a row in points and keeps the format and the options set before with
+    /// [Worksheet.set_row()](struct.Worksheet.html#method.set_row) or [Worksheet.set_row_opt()](struct.Worksheet.html#method.set_row_opt):
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_row_height_only-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let header = workbook.add_format().set_bold();
+    /// worksheet.set_row(0, 15.0, Some(&header))?;
+    /// // The row is still bold, while set_row(0, 30.0, None) would remove its format.
+    /// worksheet.set_row_height_only(0, 30.0)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// A row without format or options set before is only given the height, like with `set_row(row, height, None)`.
+    pub fn set_row_height_only(&mut self, row: WorksheetRow, height: f64) -> Result<(), XlsxError> {
+        let settings = self
+            ._workbook
+            .row_settings
+            .borrow()
+            .get(&(self.worksheet, row))
+            .copied();
+        let (format, mut options) = match settings {
+            Some(settings) => settings,
+            None => return self.set_row(row, height, None),
+        };
+        unsafe {
+            let result = libxlsxwriter_sys::worksheet_set_row_opt(
+                self.worksheet,
+                row,
+                height,
+                format,
+                &mut options,
+            );
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// This function is the same as [Worksheet.set_row()](struct.Worksheet.html#method.set_row) except that the height is in pixels:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_row_pixels-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// // Same as worksheet.set_row(0, 30.0, None)
+    /// worksheet.set_row_pixels(0, 40, None)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Excel uses 96 pixels per inch and 72 points per inch, so the height in points is `pixels * 0.75`.
+    pub fn set_row_pixels(
+        &mut self,
+        row: WorksheetRow,
+        pixels: u32,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.set_row(row, row_pixels_to_points(pixels), format)
+    }
+
+    /// This function is the same as [Worksheet.set_row_opt()](struct.Worksheet.html#method.set_row_opt) except that the height is in pixels.
+    /// See [Worksheet.set_row_pixels()](struct.Worksheet.html#method.set_row_pixels) for the conversion.
+    pub fn set_row_opt_pixels(
+        &mut self,
+        row: WorksheetRow,
+        pixels: u32,
+        format: Option<&Format>,
+        options: &mut RowColOptions,
+    ) -> Result<(), XlsxError> {
+        self.set_row_opt(row, row_pixels_to_points(pixels), format, options)
+    }
+
+    /// This function is the same as [Worksheet.set_row()](struct.Worksheet.html#method.set_row) except that the height is in EMUs (English Metric Units),
+    /// the unit of the positions and sizes of images and charts in the xlsx file:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_row_emu-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// // Same as worksheet.set_row(0, 72.0, None), one inch
+    /// worksheet.set_row_emu(0, 914_400, None)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// There are 914,400 EMUs per inch and 72 points per inch, so a point is 12,700 EMUs.
+    pub fn set_row_emu(
+        &mut self,
+        row: WorksheetRow,
+        height_emu: u32,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.set_row(row, row_emu_to_points(height_emu), format)
+    }
+
+    /// This function is the same as [Worksheet.set_column()](struct.Worksheet.html#method.set_column) except that the width is in EMUs (English Metric Units):
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_column_emu-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// // Same as worksheet.set_column(0, 0, 13.0, None), one inch or 96 pixels
+    /// worksheet.set_column_emu(0, 0, 914_400, None)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// There are 914,400 EMUs per inch and Excel uses 96 pixels per inch, so a pixel is 9,525 EMUs. The width in pixels is then converted
+    /// to characters of the default font like Excel does: a column of `n` characters is `n * 7 + 5` pixels wide, or `n * 12` pixels under one character.
+    ///
+    /// Excel rounds the widths of the columns to whole pixels, so the width is only exact for multiples of 9,525 EMUs.
+    pub fn set_column_emu(
+        &mut self,
+        first_col: WorksheetCol,
+        last_col: WorksheetCol,
+        width_emu: u32,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.set_column(first_col, last_col, column_emu_to_width(width_emu), format)
+    }
+
+    /// This function is used to change the default properties of one or more adjacent columns, such as the width:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_column-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let bold = workbook.add_format().set_bold();
+    /// worksheet.set_column(0, 0, 20.0, Some(&bold))?;
+    /// worksheet.set_column(1, 3, 12.5, None)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The width is in characters of the default font, the Excel default is 8.43.
+    ///
+    /// The format is applied to the cells of the columns which don't have a format.
+    /// A format passed when writing a cell, or set with [Worksheet.set_row()](struct.Worksheet.html#method.set_row), takes precedence over the column format.
     pub fn set_column(
         &mut self,
         first_col: WorksheetCol,
@@ -989,6 +2320,54 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function sets the width of a column from sample texts, so that the longest of them fits in the column:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_column_auto-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let labels = ["Region", "Customer name", "Total"];
+    /// for (col, label) in labels.iter().enumerate() {
+    ///     worksheet.write_string(0, col as WorksheetCol, label, None)?;
+    /// }
+    /// worksheet.set_column_auto(1, &labels, 11.0)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The width is an estimate based on the number of characters of the longest line of the samples and on the font size,
+    /// with one character of padding. It is computed by this crate and doesn't need the texts to be written to the column.
+    /// Proportional fonts can make texts with many wide characters, such as "W", slightly longer than estimated.
+    ///
+    /// The width is limited to 255 characters, the maximum width of a column in Excel. The column format isn't changed.
+    pub fn set_column_auto(
+        &mut self,
+        col: WorksheetCol,
+        sample_texts: &[&str],
+        font_size: f64,
+    ) -> Result<(), XlsxError> {
+        let width = estimate_column_width(sample_texts, font_size);
+        self.set_column(col, col, width, None)
+    }
+
+    /// This function is the same as [Worksheet.set_column()](struct.Worksheet.html#method.set_column) with additional options to hide, group or collapse the columns.
+    ///
+    /// The width is preserved when a column is hidden, so the column gets its original width back when it is unhidden in Excel:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_column_opt-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut options = RowColOptions {
+    ///     hidden: 1,
+    ///     level: 0,
+    ///     collapsed: 0,
+    /// };
+    /// worksheet.set_column_opt(3, 3, 25.0, None, &mut options)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn set_column_opt(
         &mut self,
         first_col: WorksheetCol,
@@ -1021,7 +2400,8 @@ impl<'a> Worksheet<'a> {
     /// # let workbook = Workbook::new("test-worksheet_insert_image-1.xlsx");
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.insert_image(2, 1, "../images/simple1.png")?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-insert_image-1.png)
@@ -1032,18 +2412,29 @@ impl<'a> Worksheet<'a> {
     /// The scaling of a image may be affected if is crosses a row that has its default height changed due to a font that is larger than the default font size or that has text wrapping turned on. To avoid this you should explicitly set the height of the row using Worksheet.set_row() if it crosses an inserted image.
     ///
     /// BMP images are only supported for backward compatibility. In general it is best to avoid BMP images since they aren't compressed. If used, BMP images must be 24 bit, true color, bitmaps.
+    ///
+    /// SVG images aren't supported by libxlsxwriter and return an error. Excel itself stores a raster fallback with every SVG image,
+    /// so convert the SVG image to PNG and insert the PNG image instead.
+    ///
+    /// Identical images are stored only once in the file, even if they are inserted into several worksheets
+    /// or from both files and buffers. libxlsxwriter compares the MD5 hashes of the image data, so a logo inserted into every worksheet doesn't increase the file size.
     pub fn insert_image(
         &mut self,
         row: WorksheetRow,
         col: WorksheetCol,
         filename: &str,
     ) -> Result<(), XlsxError> {
+        if is_svg_file(filename) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
         unsafe {
             let result = libxlsxwriter_sys::worksheet_insert_image(
                 self.worksheet,
                 row,
                 col,
-                CString::new(filename).unwrap().as_c_str().as_ptr(),
+                CString::new(filename)?.as_c_str().as_ptr(),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
@@ -1067,9 +2458,11 @@ impl<'a> Worksheet<'a> {
     ///         y_offset: 30,
     ///         x_scale: 0.5,
     ///         y_scale: 0.5,
+    ///         ..ImageOptions::default()
     ///     }
     /// )?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-insert_image_opt-1.png)
@@ -1083,13 +2476,26 @@ impl<'a> Worksheet<'a> {
         filename: &str,
         opt: &ImageOptions,
     ) -> Result<(), XlsxError> {
+        if is_svg_file(filename) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        if opt.jpeg_quality.is_some() {
+            let data = std::fs::read(filename).map_err(|_| {
+                XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION)
+            })?;
+            if let Some(data) = recompressed_jpeg(&data, opt)? {
+                return self.insert_image_buffer_opt(row, col, &data, &uncompressed_options(opt));
+            }
+        }
         let mut opt_struct = opt.into();
         unsafe {
             let result = libxlsxwriter_sys::worksheet_insert_image_opt(
                 self.worksheet,
                 row,
                 col,
-                CString::new(filename).unwrap().as_c_str().as_ptr(),
+                CString::new(filename)?.as_c_str().as_ptr(),
                 &mut opt_struct,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
@@ -1108,16 +2514,25 @@ impl<'a> Worksheet<'a> {
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// let data = include_bytes!("../../images/simple1.png");
     /// worksheet.insert_image_buffer(0, 0, &data[..])?;
-    /// # workbook.close()
+    /// # workbook.close()?;
+    /// # Ok(())
     /// # }
     /// ```
     /// See Worksheet.insert_image() for details about the supported image formats, and other image features.
+    ///
+    /// libxlsxwriter copies the image data when this function is called, so the buffer doesn't need to outlive the call.
+    /// Inserting the same buffer several times stores a single copy of the image in the file.
     pub fn insert_image_buffer(
         &mut self,
         row: WorksheetRow,
         col: WorksheetCol,
         buffer: &[u8],
     ) -> Result<(), XlsxError> {
+        if is_svg(buffer) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
         unsafe {
             let result = libxlsxwriter_sys::worksheet_insert_image_buffer(
                 self.worksheet,
@@ -1134,6 +2549,28 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function is like Worksheet.insert_image_buffer() function except that it takes an optional `ImageOptions` struct to scale and position the image:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_insert_image_buffer_opt-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let data = include_bytes!("../../images/simple1.png").to_vec();
+    /// worksheet.insert_image_buffer_opt(
+    ///     2, 1,
+    ///     &data,
+    ///     &ImageOptions{
+    ///         x_offset: 30,
+    ///         y_offset: 30,
+    ///         x_scale: 0.5,
+    ///         y_scale: 0.5,
+    ///         ..ImageOptions::default()
+    ///     }
+    /// )?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn insert_image_buffer_opt(
         &mut self,
         row: WorksheetRow,
@@ -1141,6 +2578,14 @@ impl<'a> Worksheet<'a> {
         buffer: &[u8],
         opt: &ImageOptions,
     ) -> Result<(), XlsxError> {
+        if is_svg(buffer) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        if let Some(data) = recompressed_jpeg(buffer, opt)? {
+            return self.insert_image_buffer_opt(row, col, &data, &uncompressed_options(opt));
+        }
         let mut opt_struct = opt.into();
         unsafe {
             let result = libxlsxwriter_sys::worksheet_insert_image_buffer_opt(
@@ -1159,15 +2604,34 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Insert a chart into the worksheet with its top left corner in the cell specified by row and column.
+    /// The chart is 480x288 pixels unless its size was changed with [Chart.set_size()](struct.Chart.html#method.set_size).
     pub fn insert_chart(
         &mut self,
         row: WorksheetRow,
         column: WorksheetCol,
         chart: &Chart,
     ) -> Result<(), XlsxError> {
+        let (width, height) = chart.default_size();
         unsafe {
-            let result =
-                libxlsxwriter_sys::worksheet_insert_chart(self.worksheet, row, column, chart.chart);
+            let result = if (width, height) == DEFAULT_CHART_SIZE {
+                libxlsxwriter_sys::worksheet_insert_chart(self.worksheet, row, column, chart.chart)
+            } else {
+                let mut options = libxlsxwriter_sys::lxw_chart_options {
+                    x_offset: 0,
+                    y_offset: 0,
+                    x_scale: f64::from(width) / f64::from(DEFAULT_CHART_SIZE.0),
+                    y_scale: f64::from(height) / f64::from(DEFAULT_CHART_SIZE.1),
+                    object_position: 0,
+                };
+                libxlsxwriter_sys::worksheet_insert_chart_opt(
+                    self.worksheet,
+                    row,
+                    column,
+                    chart.chart,
+                    &mut options,
+                )
+            };
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
             } else {
@@ -1176,24 +2640,432 @@ impl<'a> Worksheet<'a> {
         }
     }
 
-    pub fn merge_range(
+    /// Insert several charts into the worksheet in a grid, for example to build a dashboard:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-insert_charts_grid-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// # }
+    /// let mut charts = Vec::new();
+    /// for chart_type in [ChartType::Column, ChartType::Line, ChartType::Area, ChartType::Bar].iter() {
+    ///     let mut chart = workbook.add_chart(*chart_type);
+    ///     chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    ///     charts.push(chart);
+    /// }
+    /// let charts: Vec<&Chart> = charts.iter().collect();
+    /// // Two charts per row, with their top left corners 16 rows and 9 columns apart.
+    /// worksheet.insert_charts_grid(1, 2, &charts, 2, 16, 9)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The charts are inserted from left to right with [Worksheet.insert_chart()](struct.Worksheet.html#method.insert_chart), starting a new row
+    /// of the grid after `cols` charts. The top left corner of the first chart is in the cell specified by `start_row` and `start_col`.
+    ///
+    /// The spacing is given in cells: `row_step` is the number of rows and `col_step` the number of columns between the top left corners of
+    /// neighbouring charts. With the default row height of 20 pixels and column width of 64 pixels, a 480x288 pixel chart covers 7.5 columns
+    /// and 14.4 rows, so steps of 8 columns and 15 rows keep the charts apart. Larger charts and changed row or column sizes need other steps:
+    /// the charts overlap when the steps are too small.
+    ///
+    /// `cols` must be at least 1, and the error `LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE` is returned before inserting any chart when a chart
+    /// would start outside of the worksheet.
+    pub fn insert_charts_grid(
+        &mut self,
+        start_row: WorksheetRow,
+        start_col: WorksheetCol,
+        charts: &[&Chart],
+        cols: usize,
+        row_step: u32,
+        col_step: u16,
+    ) -> Result<(), XlsxError> {
+        if cols == 0 {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        let mut anchors = Vec::with_capacity(charts.len());
+        for index in 0..charts.len() {
+            let row = u64::from(start_row) + (index / cols) as u64 * u64::from(row_step);
+            let col = u64::from(start_col) + (index % cols) as u64 * u64::from(col_step);
+            if row > u64::from(MAX_ROW) || col > u64::from(MAX_COL) {
+                return Err(XlsxError::new(
+                    libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE,
+                ));
+            }
+            anchors.push((row as WorksheetRow, col as WorksheetCol));
+        }
+        for (chart, (row, col)) in charts.iter().zip(anchors) {
+            self.insert_chart(row, col, chart)?;
+        }
+        Ok(())
+    }
+
+    /// This function allows cells to be merged together so that they act as a single area:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_merge_range-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let center = workbook.add_format().set_align(FormatAlignment::Center);
+    /// worksheet.merge_range(1, 1, 1, 3, "Invoice", Some(&center))?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The string is written to the top-left cell of the range and the other cells are written as blank cells with the same format.
+    /// The other cells of the range must not be written afterwards, otherwise Excel reports the file as corrupt.
+    ///
+    /// Excel doesn't allow a single cell to be merged, so an error is returned if the range is a single cell.
+    ///
+    /// Like Excel, which warns that merging cells only keeps the upper-left value, the merge replaces the data already written to the other cells
+    /// of the range by blank cells and their data is lost. The `strict_merge_ranges` option of [WorkbookOptions](struct.WorkbookOptions.html)
+    /// returns an error instead:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let options = WorkbookOptions {
+    ///     strict_merge_ranges: true,
+    ///     ..WorkbookOptions::default()
+    /// };
+    /// let workbook = Workbook::new_with_options("test-worksheet_merge_range-2.xlsx", &options);
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 1, "Total", None)?;
+    /// assert!(worksheet.merge_range(0, 0, 0, 3, "Report", None).is_err()); // B1 would be lost
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The range can contain rows or columns hidden with [Worksheet.set_row_opt()](struct.Worksheet.html#method.set_row_opt) or
+    /// [Worksheet.set_column_opt()](struct.Worksheet.html#method.set_column_opt), in any call order: the merged cell is displayed over the visible rows and columns of the range.
+    pub fn merge_range(
+        &mut self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+        string: &str,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        if first_row == last_row && first_col == last_col {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        self.check_merge_overwrite(first_row, first_col, last_row, last_col)?;
+        unsafe {
+            let result = libxlsxwriter_sys::worksheet_merge_range(
+                self.worksheet,
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+                CString::new(string)?.as_c_str().as_ptr(),
+                format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
+            );
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self.record_range(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    CellValue::String(string.to_string()),
+                    format,
+                );
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// This function merges a range of cells and writes a title in it, with a bold format centered horizontally and vertically:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_merge_title-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.merge_title(0, 0, 0, 5, "Sales report")?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// It is the same as [Worksheet.merge_range()](struct.Worksheet.html#method.merge_range) with a format created by the workbook the first time this function is called
+    /// and shared by all the titles. Use `merge_range()` with your own format for other fonts, borders or colors.
+    pub fn merge_title(
+        &mut self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+        text: &str,
+    ) -> Result<(), XlsxError> {
+        let format = self._workbook.default_title_format();
+        self.merge_range(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            text,
+            Some(&format),
+        )
+    }
+
+    /// This function merges a range of cells and writes a rich string in it, with the text wrap property added to `format`,
+    /// so that a title with several lines and styles fits in a merged header:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_merge_range_rich_wrapped-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let bold = workbook.add_format().set_bold();
+    /// let center = workbook
+    ///     .add_format()
+    ///     .set_align(FormatAlignment::Center)
+    ///     .set_align(FormatAlignment::VerticalCenter);
+    /// worksheet.merge_range_rich_wrapped(
+    ///     0, 0, 1, 3, // A1:D2
+    ///     &[("Quarterly report\n", Some(&bold)), ("Sales by region", None)],
+    ///     Some(&center),
+    /// )?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The lines are separated by `\n` in the fragments. `format` isn't modified: a new format combining it with the text wrap property is created
+    /// with [Format.merge()](struct.Format.html#method.merge). The fragments are checked like in [Worksheet.write_rich_string()](struct.Worksheet.html#method.write_rich_string)
+    /// before the range is merged, except for the length of the XML of the rich string, which only libxlsxwriter checks:
+    /// the range is left merged with an empty text when this length is exceeded.
+    ///
+    /// Excel doesn't adjust the height of the rows of a merged range to its text, so the rows keep their height and the lines which don't fit are cut.
+    /// Set the height of the rows with [Worksheet.set_row()](struct.Worksheet.html#method.set_row) when the range doesn't have enough rows for the text.
+    pub fn merge_range_rich_wrapped(
+        &mut self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+        text: &[(&str, Option<&Format>)],
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        check_rich_string(text)?;
+        let wrap = self._workbook.add_format().set_text_wrap();
+        let wrapped = match format {
+            Some(format) => format.merge(&wrap),
+            None => wrap,
+        };
+        self.merge_range(first_row, first_col, last_row, last_col, "", Some(&wrapped))?;
+        self.write_rich_string(first_row, first_col, text, Some(&wrapped))
+    }
+
+    /// Returns the number of cells written to the worksheet, for example to start a new worksheet before reaching the limits of Excel:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_written_cell_count-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_row(0, 0, &["Name", "Score"], None)?;
+    /// worksheet.write_number(1, 1, 92.0, None)?;
+    /// assert_eq!(worksheet.written_cell_count(), 3);
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// A worksheet has at most 1,048,576 rows and 16,384 columns, so at most 17,179,869,184 cells.
+    ///
+    /// Each successful write of a cell is counted, so a cell written twice is counted twice. An array formula and a range merged with
+    /// [Worksheet.merge_range()](struct.Worksheet.html#method.merge_range) count all the cells of their range.
+    /// The count is also kept in the constant memory mode of [WorkbookOptions](struct.WorkbookOptions.html), where the written cells aren't kept.
+    pub fn written_cell_count(&self) -> u64 {
+        self._workbook
+            .written_cells
+            .borrow()
+            .get(&self.worksheet)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the format a cell was written with, or `None` if the cell wasn't written or was written without a format:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_get_cell_format-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let bold = workbook.add_format().set_bold();
+    /// worksheet.write_number(0, 0, 1.0, Some(&bold))?;
+    /// worksheet.write_number(1, 0, 2.0, None)?;
+    /// assert!(worksheet.get_cell_format(0, 0).is_some());
+    /// assert!(worksheet.get_cell_format(1, 0).is_none());
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The returned format is the format shared by all the cells written with it, so it should be combined with another format with
+    /// [Format.merge()](struct.Format.html#method.merge) rather than modified.
+    ///
+    /// Formats set with [Worksheet.set_row()](struct.Worksheet.html#method.set_row) or [Worksheet.set_column()](struct.Worksheet.html#method.set_column) aren't returned,
+    /// and no format is returned in the constant memory mode of [WorkbookOptions](struct.WorkbookOptions.html) because the written cells are not kept.
+    pub fn get_cell_format(&self, row: WorksheetRow, col: WorksheetCol) -> Option<Format<'a>> {
+        let format = self
+            ._workbook
+            .cells
+            .borrow()
+            .get(&self.worksheet)
+            .and_then(|cells| cells.get(&(row, col)))
+            .map(|record| record.format)
+            .or_else(|| self.range_at(row, col).map(|(_, format)| format))?;
+        if format.is_null() {
+            None
+        } else {
+            Some(Format {
+                _workbook: self._workbook,
+                format,
+            })
+        }
+    }
+
+    /// This function applies a format to every cell of a range without overwriting the values of the cells.
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_format_range-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_number(1, 1, 123.0, None)?;
+    /// worksheet.write_string(2, 2, "Hello", None)?;
+    /// let border = workbook.add_format().set_border(FormatBorder::Thin);
+    /// worksheet.format_range(&CellRange::new(0, 0, 4, 4), &border)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Cells which were written are written again with their value and the new format, and the other cells are written as blank cells with the new format.
+    /// The new format replaces the previous format of the cells, it isn't merged with it.
+    ///
+    /// Cells written as URLs, rich strings, array formulas or merged ranges can't be written again and are left unchanged.
+    /// In the constant memory mode of [WorkbookOptions](struct.WorkbookOptions.html) the written cells are not kept, so their values can't be written again
+    /// and `LXW_ERROR_FEATURE_NOT_SUPPORTED` is returned without writing any cell.
+    pub fn format_range(&mut self, range: &CellRange, format: &Format) -> Result<(), XlsxError> {
+        if self._workbook.constant_memory {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_FEATURE_NOT_SUPPORTED,
+            ));
+        }
+        for (row, col) in range.cells() {
+            if self.range_at(row, col).is_some() {
+                continue;
+            }
+            let value = self
+                ._workbook
+                .cells
+                .borrow()
+                .get(&self.worksheet)
+                .and_then(|cells| cells.get(&(row, col)))
+                .map(|record| record.value.clone());
+            match value {
+                None | Some(CellValue::Blank) => self.write_blank(row, col, Some(format))?,
+                Some(CellValue::Number(number)) => {
+                    self.write_number(row, col, number, Some(format))?
+                }
+                Some(CellValue::String(text)) => {
+                    self.write_string(row, col, &text, Some(format))?
+                }
+                Some(CellValue::Formula(formula)) => {
+                    self.write_formula(row, col, &formula, Some(format))?
+                }
+                Some(CellValue::FormulaNum(formula, number)) => {
+                    self.write_formula_num(row, col, &formula, Some(format), number)?
+                }
+                Some(CellValue::FormulaStr(formula, result)) => {
+                    self.write_formula_str(row, col, &formula, Some(format), &result)?
+                }
+                Some(CellValue::DateTime(datetime)) => {
+                    self.write_datetime(row, col, &datetime, Some(format))?
+                }
+                Some(CellValue::Boolean(value)) => {
+                    self.write_boolean(row, col, value, Some(format))?
+                }
+                Some(CellValue::Other) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// This function adds an autofilter to a range of the worksheet, with a drop down list in each cell of its first row:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-autofilter-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Region", None)?;
+    /// worksheet.write_string(0, 1, "Sales", None)?;
+    /// worksheet.autofilter(0, 0, 50, 1)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Note
+    /// The version of libxlsxwriter used by this crate can't write the filter rules of the columns, so there are no `filter_column()`
+    /// or `filter_column2()` functions: the file is opened with all the rows of the range shown, and the rules are chosen in Excel.
+    pub fn autofilter(
+        &mut self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+    ) -> Result<(), XlsxError> {
+        unsafe {
+            let result = libxlsxwriter_sys::worksheet_autofilter(
+                self.worksheet,
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+            );
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// This function is like [Worksheet.autofilter()](struct.Worksheet.html#method.autofilter) except that the range is given as an A1 style reference:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-autofilter_at-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Region", None)?;
+    /// worksheet.write_string(0, 1, "Month", None)?;
+    /// worksheet.write_string(0, 2, "Sales", None)?;
+    /// worksheet.autofilter_at("A1:C10")?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The range is made of two cell references separated by a colon, which can use absolute notation such as `"$A$1:$C$10"`
+    /// and be given in any order. A single cell, a missing or invalid reference and a cell outside of the worksheet return an error.
+    pub fn autofilter_at(&mut self, range: &str) -> Result<(), XlsxError> {
+        let (first_row, first_col, last_row, last_col) = parse_range_ref(range)?;
+        self.autofilter(first_row, first_col, last_row, last_col)
+    }
+
+    pub fn data_validation_cell(
         &mut self,
-        first_row: WorksheetRow,
-        first_col: WorksheetCol,
-        last_row: WorksheetRow,
-        last_col: WorksheetCol,
-        string: &str,
-        format: Option<&Format>,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        validation: &DataValidation,
     ) -> Result<(), XlsxError> {
         unsafe {
-            let result = libxlsxwriter_sys::worksheet_merge_range(
+            let result = libxlsxwriter_sys::worksheet_data_validation_cell(
                 self.worksheet,
-                first_row,
-                first_col,
-                last_row,
-                last_col,
-                CString::new(string).unwrap().as_c_str().as_ptr(),
-                format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
+                row,
+                col,
+                &mut validation.to_c_struct()?.data_validation,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
@@ -1203,20 +3075,49 @@ impl<'a> Worksheet<'a> {
         }
     }
 
-    pub fn autofilter(
+    /// This function is like [Worksheet.data_validation_cell()](struct.Worksheet.html#method.data_validation_cell) except that the cell is given as an A1 style reference:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-data_validation_cell_at-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut validation = DataValidation::new(
+    ///     DataValidationType::List,
+    ///     DataValidationCriteria::Between,
+    ///     DataValidationErrorType::Stop,
+    /// );
+    /// validation.value_list = Some(vec!["open".to_string(), "closed".to_string()]);
+    /// worksheet.data_validation_cell_at("B2", &validation)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The reference can use absolute notation such as `"$B$2"`. Invalid references return an error.
+    pub fn data_validation_cell_at(
+        &mut self,
+        cell: &str,
+        validation: &DataValidation,
+    ) -> Result<(), XlsxError> {
+        let (row, col) = parse_cell_ref(cell)?;
+        self.data_validation_cell(row, col, validation)
+    }
+
+    pub fn data_validation_range(
         &mut self,
         first_row: WorksheetRow,
         first_col: WorksheetCol,
         last_row: WorksheetRow,
         last_col: WorksheetCol,
+        validation: &DataValidation,
     ) -> Result<(), XlsxError> {
         unsafe {
-            let result = libxlsxwriter_sys::worksheet_autofilter(
+            let result = libxlsxwriter_sys::worksheet_data_validation_range(
                 self.worksheet,
                 first_row,
                 first_col,
                 last_row,
                 last_col,
+                &mut validation.to_c_struct()?.data_validation,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
@@ -1226,18 +3127,20 @@ impl<'a> Worksheet<'a> {
         }
     }
 
-    pub fn data_validation_cell(
+    /// This function is used to add a conditional format to a cell in a worksheet.
+    /// See [Worksheet.conditional_format_range()](struct.Worksheet.html#method.conditional_format_range) for details.
+    pub fn conditional_format_cell(
         &mut self,
         row: WorksheetRow,
         col: WorksheetCol,
-        validation: &DataValidation,
+        conditional_format: &ConditionalFormat,
     ) -> Result<(), XlsxError> {
         unsafe {
-            let result = libxlsxwriter_sys::worksheet_data_validation_cell(
+            let result = libxlsxwriter_sys::worksheet_conditional_format_cell(
                 self.worksheet,
                 row,
                 col,
-                &mut validation.to_c_struct().data_validation,
+                &mut conditional_format.to_c_struct()?.conditional_format,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
@@ -1247,24 +3150,91 @@ impl<'a> Worksheet<'a> {
         }
     }
 
-    pub fn data_validation_range(
+    /// This function is used to add a conditional format to a range of cells in a worksheet.
+    /// Conditional formatting is a feature of Excel which allows you to apply a format to a cell or a range of cells based on user defined rules.
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_conditional_format_range-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..10 {
+    /// #     worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    /// #     worksheet.write_number(i, 1, (i * 10).into(), None)?;
+    /// #     worksheet.write_number(i, 2, (i * 10).into(), None)?;
+    /// # }
+    /// let format = workbook.add_format()
+    ///     .set_font_color(FormatColor::Red);
+    /// worksheet.conditional_format_range(
+    ///     0, 0, 9, 0,
+    ///     &ConditionalFormat::cell(ConditionalFormatCriteria::GreaterThanOrEqualTo, 50.0, &format),
+    /// )?;
+    /// worksheet.conditional_format_range(0, 1, 9, 1, &ConditionalFormat::three_color_scale())?;
+    /// let mut data_bar = ConditionalFormat::data_bar();
+    /// data_bar.bar_color = Some(FormatColor::Green);
+    /// worksheet.conditional_format_range(0, 2, 9, 2, &data_bar)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The conditional formats of a worksheet are given increasing priorities in the order they are added: the first one has the priority 1,
+    /// which is the highest. When several rules apply to the same cell, Excel evaluates them from the highest priority to the lowest,
+    /// and a format set by a rule of higher priority wins over the format set by a rule of lower priority for the same property, such as the font color.
+    /// The formats of the lower priority rules still apply to the other properties, unless the `stop_if_true` field of a matching rule is set.
+    ///
+    /// ### Note
+    /// libxlsxwriter assigns the priorities itself and has no option to set them, so the priorities can only be changed by adding the rules in another order.
+    pub fn conditional_format_range(
         &mut self,
         first_row: WorksheetRow,
         first_col: WorksheetCol,
         last_row: WorksheetRow,
         last_col: WorksheetCol,
-        validation: &DataValidation,
+        conditional_format: &ConditionalFormat,
     ) -> Result<(), XlsxError> {
         unsafe {
-            let result = libxlsxwriter_sys::worksheet_data_validation_range(
+            let result = libxlsxwriter_sys::worksheet_conditional_format_range(
                 self.worksheet,
                 first_row,
                 first_col,
                 last_row,
                 last_col,
-                &mut validation.to_c_struct().data_validation,
+                &mut conditional_format.to_c_struct()?.conditional_format,
+            );
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// This function hides the Excel warnings of a type, the small green triangles in the corner of the cells, for the cells of `range`.
+    /// `range` is an A1 style range such as `"C3:F8"`, or several ranges separated by spaces.
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_ignore_errors-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "0123", None)?;
+    /// worksheet.write_formula(1, 0, "=1/0", None)?;
+    /// worksheet.ignore_errors(IgnoreError::NumberStoredAsText, "A1")?;
+    /// worksheet.ignore_errors(IgnoreError::EvalError, "A2")?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Each type of warning can only be ignored once per worksheet.
+    pub fn ignore_errors(&mut self, error_type: IgnoreError, range: &str) -> Result<(), XlsxError> {
+        let range_vec = convert_str(range)?;
+        unsafe {
+            let result = libxlsxwriter_sys::worksheet_ignore_errors(
+                self.worksheet,
+                error_type.value(),
+                range_vec.as_ptr() as *const c_char,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.const_str.borrow_mut().push(range_vec);
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -1272,6 +3242,60 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function hides the Excel warnings of a type for the used cells of a column, for example the "number stored as text" warnings of a column of IDs:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_ignore_errors_column-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "ID", None)?;
+    /// worksheet.write_string(1, 0, "00042", None)?;
+    /// worksheet.write_string(2, 0, "00043", None)?;
+    /// worksheet.ignore_errors_column(IgnoreError::NumberStoredAsText, 0)?; // "A1:A3"
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The range covers the column from its first to its last written cell, so this function should be called once the column is written.
+    /// When no cell of the column was written, or in the constant memory mode of [WorkbookOptions](struct.WorkbookOptions.html) where the written cells are not kept,
+    /// the range covers all the 1,048,576 rows of the column. See [Worksheet.ignore_errors()](struct.Worksheet.html#method.ignore_errors).
+    pub fn ignore_errors_column(
+        &mut self,
+        error_type: IgnoreError,
+        col: WorksheetCol,
+    ) -> Result<(), XlsxError> {
+        let used_rows = self
+            ._workbook
+            .cells
+            .borrow()
+            .get(&self.worksheet)
+            .and_then(|cells| {
+                let mut rows = cells.keys().filter(|x| x.1 == col).map(|x| x.0);
+                let first_row = rows.next()?;
+                Some((first_row, rows.next_back().unwrap_or(first_row)))
+            });
+        let used_rows = self
+            ._workbook
+            .ranges
+            .borrow()
+            .get(&self.worksheet)
+            .into_iter()
+            .flatten()
+            .filter(|(range, _)| (range.first_col..=range.last_col).contains(&col))
+            .fold(used_rows, |rows, (range, _)| match rows {
+                Some((first_row, last_row)) => {
+                    Some((first_row.min(range.first_row), last_row.max(range.last_row)))
+                }
+                None => Some((range.first_row, range.last_row)),
+            });
+        let (first_row, last_row) = used_rows.unwrap_or((0, 1_048_575));
+        let name = col_name(col);
+        self.ignore_errors(
+            error_type,
+            &format!("{}{}:{}{}", name, first_row + 1, name, last_row + 1),
+        )
+    }
+
     pub fn activate(&mut self) {
         unsafe {
             libxlsxwriter_sys::worksheet_activate(self.worksheet);
@@ -1296,18 +3320,118 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function can be used to divide a worksheet into horizontal or vertical regions known as panes and to "freeze" these panes so that the splitter bars are not visible.
+    ///
+    /// The parameters `row` and `col` are used to specify the location of the split. The split is on the top or left of the cell.
+    /// For example, to freeze the first row of a worksheet, which usually contains the headers:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_freeze_panes-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.freeze_panes(1, 0);
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// A worksheet has a single pane definition, so this function and [Worksheet.split_panes()](struct.Worksheet.html#method.split_panes)
+    /// replace each other: the last one called is used.
     pub fn freeze_panes(&mut self, row: WorksheetRow, col: WorksheetCol) {
         unsafe {
             libxlsxwriter_sys::worksheet_freeze_panes(self.worksheet, row, col);
         }
     }
 
+    /// This function is the same as [Worksheet.freeze_panes()](struct.Worksheet.html#method.freeze_panes) except that the cell displayed at the top left of the
+    /// scrolling pane is set by `top_row` and `left_col`:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_freeze_panes_opt-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// // Freeze the first row and scroll the data to row 20.
+    /// worksheet.freeze_panes_opt(1, 0, 20, 0);
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn freeze_panes_opt(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        top_row: WorksheetRow,
+        left_col: WorksheetCol,
+    ) {
+        unsafe {
+            libxlsxwriter_sys::worksheet_freeze_panes_opt(
+                self.worksheet,
+                row,
+                col,
+                top_row,
+                left_col,
+                0,
+            );
+        }
+    }
+
+    /// This function can be used to divide a worksheet into horizontal or vertical regions known as panes.
+    /// This function is different from [Worksheet.freeze_panes()](struct.Worksheet.html#method.freeze_panes) in that the splits between the panes will be visible to the user and each pane will have its own scroll bars.
+    ///
+    /// The parameters `vertical` and `horizontal` are used to specify the vertical and horizontal position of the split.
+    /// The units for vertical and horizontal are the same as those used by Excel to specify row height and column width.
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_split_panes-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.split_panes(15.0, 8.43);
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Like Worksheet.freeze_panes(), the last pane function called is used.
     pub fn split_panes(&mut self, vertical: f64, horizontal: f64) {
         unsafe {
             libxlsxwriter_sys::worksheet_split_panes(self.worksheet, vertical, horizontal);
         }
     }
 
+    /// This function freezes the first row of the worksheet, which usually contains the headers, and selects the cell A2 below it:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_freeze_header-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Name", None)?;
+    /// worksheet.write_string(0, 1, "Value", None)?;
+    /// worksheet.freeze_header();
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This is the same as calling `worksheet.freeze_panes(1, 0)` and `worksheet.set_selection(1, 0, 1, 0)`,
+    /// so the notes of [Worksheet.freeze_panes()](struct.Worksheet.html#method.freeze_panes) and
+    /// [Worksheet.set_selection()](struct.Worksheet.html#method.set_selection) also apply: a selection set before this function is kept instead of A2.
+    pub fn freeze_header(&mut self) {
+        self.freeze_panes(1, 0);
+        self.set_selection(1, 0, 1, 0);
+    }
+
+    /// This function can be used to specify which cell or range of cells is selected in a worksheet. The active cell is the first cell of the range:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_selection-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_selection(0, 0, 1, 1);
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Note
+    /// libxlsxwriter only writes a single selection range per worksheet: the selection can only be set once and the following calls are ignored.
+    /// Selections of several discontiguous ranges, such as `A1:B2` and `D4:E5`, aren't supported, so there is no `set_selections()` function.
     pub fn set_selection(
         &mut self,
         first_row: WorksheetRow,
@@ -1344,17 +3468,69 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function sets the paper size of the printed pages of the worksheet. The default is the default paper of the printer:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_paper-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_paper(PaperType::A4);
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Custom page dimensions aren't supported, see [PaperType](enum.PaperType.html).
     pub fn set_paper(&mut self, paper: PaperType) {
         unsafe {
             libxlsxwriter_sys::worksheet_set_paper(self.worksheet, paper.value());
         }
     }
 
+    /// This function is used to set the margins of the worksheet when it is printed. The units are in inches.
+    /// A negative value keeps the Excel default margin: 0.7 inch for the left and right margins, 0.75 inch for the top and bottom margins.
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_margins-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_margins(0.5, 0.5, 1.0, 1.0);
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// These margins don't include the header and footer margins, which are set with [Worksheet.set_header_opt()](struct.Worksheet.html#method.set_header_opt)
+    /// and [Worksheet.set_footer_opt()](struct.Worksheet.html#method.set_footer_opt).
+    pub fn set_margins(&mut self, left: f64, right: f64, top: f64, bottom: f64) {
+        unsafe {
+            libxlsxwriter_sys::worksheet_set_margins(self.worksheet, left, right, top, bottom);
+        }
+    }
+
+    /// This function is used to set the header for a worksheet when it is printed.
+    ///
+    /// The header string uses Excel's control characters to position the text, for example `&L` for left, `&C` for center,
+    /// `&R` for right, `&P` for the page number and `&N` for the total number of pages:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_header-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_header("&LQuarterly report&RPage &P of &N")?;
+    /// worksheet.set_footer("&CConfidential")?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Note
+    /// libxlsxwriter writes a single header and footer which are used on every printed page.
+    /// Excel's "Different first page" and "Different odd and even pages" options aren't supported, so there are no
+    /// first page or even page variants of this function.
     pub fn set_header(&mut self, header: &str) -> Result<(), XlsxError> {
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_header(
                 self.worksheet,
-                CString::new(header).unwrap().as_c_str().as_ptr(),
+                CString::new(header)?.as_c_str().as_ptr(),
             );
 
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
@@ -1365,11 +3541,13 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function is used to set the footer for a worksheet when it is printed.
+    /// See [Worksheet.set_header()](struct.Worksheet.html#method.set_header) for the control characters and limitations.
     pub fn set_footer(&mut self, footer: &str) -> Result<(), XlsxError> {
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_footer(
                 self.worksheet,
-                CString::new(footer).unwrap().as_c_str().as_ptr(),
+                CString::new(footer)?.as_c_str().as_ptr(),
             );
 
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
@@ -1380,6 +3558,22 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function is the same as [Worksheet.set_header()](struct.Worksheet.html#method.set_header) except that it takes additional options, such as the header margin:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_header_opt-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_margins(-1.0, -1.0, 0.5, -1.0);
+    /// worksheet.set_header_opt("&CQuarterly report", &HeaderFooterOptions { margin: 0.2 })?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Excel has two margin systems. The header margin is the distance between the top edge of the page and the header,
+    /// while the top margin set with [Worksheet.set_margins()](struct.Worksheet.html#method.set_margins) is the distance between the top edge of the page and the data.
+    /// The header margin must be smaller than the top margin, otherwise the header overlaps the data.
+    /// The same applies to the footer margin and the bottom margin.
     pub fn set_header_opt(
         &mut self,
         header: &str,
@@ -1388,7 +3582,7 @@ impl<'a> Worksheet<'a> {
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_header_opt(
                 self.worksheet,
-                CString::new(header).unwrap().as_c_str().as_ptr(),
+                CString::new(header)?.as_c_str().as_ptr(),
                 &mut options.into(),
             );
 
@@ -1400,6 +3594,8 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function is the same as [Worksheet.set_footer()](struct.Worksheet.html#method.set_footer) except that it takes additional options, such as the footer margin.
+    /// See [Worksheet.set_header_opt()](struct.Worksheet.html#method.set_header_opt) for the difference between the footer margin and the bottom margin.
     pub fn set_footer_opt(
         &mut self,
         footer: &str,
@@ -1408,7 +3604,7 @@ impl<'a> Worksheet<'a> {
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_footer_opt(
                 self.worksheet,
-                CString::new(footer).unwrap().as_c_str().as_ptr(),
+                CString::new(footer)?.as_c_str().as_ptr(),
                 &mut options.into(),
             );
 
@@ -1466,6 +3662,13 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function displays the worksheet from right to left, with the A1 cell in the top right corner, as some Excel versions do for Arabic, Hebrew and other right to left languages.
+    pub fn right_to_left(&mut self) {
+        unsafe {
+            libxlsxwriter_sys::worksheet_right_to_left(self.worksheet);
+        }
+    }
+
     pub fn gridlines(&mut self, option: GridLines) {
         unsafe {
             libxlsxwriter_sys::worksheet_gridlines(self.worksheet, option.value());
@@ -1484,12 +3687,33 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function prints the row and column headings, "A", "B", ... and "1", "2", ..., on the printed pages of the worksheet. They aren't printed by default.
     pub fn print_row_col_headers(&mut self) {
         unsafe {
             libxlsxwriter_sys::worksheet_print_row_col_headers(self.worksheet);
         }
     }
 
+    /// This function prints the gridlines and the row and column headings of the worksheet, to check the print layout of a report:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_print_debug_layout-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Draft", None)?;
+    /// worksheet.print_debug_layout();
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// On the printed pages the cells can then be matched with the worksheet, which helps to verify the page breaks, the print area and the fit to pages settings.
+    /// This is the same as calling [Worksheet.gridlines()](struct.Worksheet.html#method.gridlines) with `GridLines::ShowAllGridLines`
+    /// and [Worksheet.print_row_col_headers()](struct.Worksheet.html#method.print_row_col_headers).
+    pub fn print_debug_layout(&mut self) {
+        self.gridlines(GridLines::ShowAllGridLines);
+        self.print_row_col_headers();
+    }
+
     pub fn repeat_rows(
         &mut self,
         first_row: WorksheetRow,
@@ -1551,6 +3775,40 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function applies a common print configuration to the worksheet:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_print_preset-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.print_preset(PrintPreset::OnePageWideLandscape);
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// It is the same as calling [Worksheet.set_landscape()](struct.Worksheet.html#method.set_landscape) or [Worksheet.set_portrait()](struct.Worksheet.html#method.set_portrait),
+    /// [Worksheet.fit_to_pages()](struct.Worksheet.html#method.fit_to_pages) and [Worksheet.set_margins()](struct.Worksheet.html#method.set_margins)
+    /// as described by [PrintPreset](enum.PrintPreset.html). These functions can be called afterwards to change a single setting.
+    pub fn print_preset(&mut self, preset: PrintPreset) {
+        match preset {
+            PrintPreset::OnePageWideLandscape => {
+                self.set_landscape();
+                self.fit_to_pages(1, 0);
+                self.set_margins(0.25, 0.25, 0.75, 0.75);
+            }
+            PrintPreset::OnePageWidePortrait => {
+                self.set_portrait();
+                self.fit_to_pages(1, 0);
+                self.set_margins(-1.0, -1.0, -1.0, -1.0);
+            }
+            PrintPreset::OnePagePortrait => {
+                self.set_portrait();
+                self.fit_to_pages(1, 1);
+                self.set_margins(-1.0, -1.0, -1.0, -1.0);
+            }
+        }
+    }
+
     pub fn set_start_page(&mut self, start_page: u16) {
         unsafe {
             libxlsxwriter_sys::worksheet_set_start_page(self.worksheet, start_page);
@@ -1581,16 +3839,53 @@ impl<'a> Worksheet<'a> {
         }
     }
 
-    pub fn protect(&mut self, password: &str, protection: &Protection) {
+    /// This function protects the elements of a worksheet from modification, with an optional password:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_protect-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let unlocked = workbook.add_format().set_unlocked();
+    /// worksheet.write_string(0, 0, "Input:", None)?;
+    /// worksheet.write_blank(0, 1, Some(&unlocked))?;
+    /// worksheet.protect(
+    ///     Some("password"),
+    ///     &Protection {
+    ///         no_select_unlocked_cells: false,
+    ///         format_cells: true,
+    ///         ..Protection::new()
+    ///     },
+    /// )?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The protection only applies to the cells whose format has the locked property, which is the default.
+    /// Use [Format.set_unlocked()](struct.Format.html#method.set_unlocked) for the cells which should stay editable,
+    /// and [Format.set_hidden()](struct.Format.html#method.set_hidden) to hide formulas.
+    ///
+    /// The password isn't encrypted, Excel only stores a weak hash of it. It only prevents accidental modifications.
+    pub fn protect(
+        &mut self,
+        password: Option<&str>,
+        protection: &Protection,
+    ) -> Result<(), XlsxError> {
+        let password_vec = password.map(convert_str).transpose()?;
         unsafe {
             libxlsxwriter_sys::worksheet_protect(
                 self.worksheet,
-                CString::new(password).unwrap().as_c_str().as_ptr(),
+                password_vec
+                    .as_ref()
+                    .map(|x| x.as_ptr() as *const c_char)
+                    .unwrap_or(std::ptr::null()),
                 &mut protection.into(),
             );
         }
+        Ok(())
     }
 
+    /// Sets the outline options of the grouped rows and columns of the worksheet.
+    /// [Worksheet.set_outline_settings()](struct.Worksheet.html#method.set_outline_settings) sets the same options with named setters.
     pub fn outline_settings(
         &mut self,
         visible: bool,
@@ -1609,6 +3904,44 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Sets the outline options of the grouped rows and columns of the worksheet, for example to place the summary rows above the groups:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-set_outline_settings-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_outline_settings(&OutlineSettings::new().set_symbols_below(false));
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// See [OutlineSettings](struct.OutlineSettings.html) for the default settings.
+    pub fn set_outline_settings(&mut self, settings: &OutlineSettings) {
+        self.outline_settings(
+            settings.visible,
+            settings.symbols_below,
+            settings.symbols_right,
+            settings.auto_style,
+        )
+    }
+
+    /// This function is used to set the default height in points of the rows of the worksheet, and optionally to hide the rows without data:
+    /// ```rust
+    /// # use xlsxwriter::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_default_row-1.xlsx");
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_default_row(24.0, false);
+    /// // The header row keeps its own height.
+    /// worksheet.set_row(0, 40.0, None)?;
+    /// # workbook.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// The heights set with [Worksheet.set_row()](struct.Worksheet.html#method.set_row) are stored with their rows,
+    /// so they always take precedence over the default height whether `set_row()` is called before or after this function.
+    ///
+    /// Hiding the unused rows is an Excel optimization for worksheets with many rows: the rows with data, or with a height or a format set, stay visible.
     pub fn set_default_row(&mut self, height: f64, hide_unused_rows: bool) {
         unsafe {
             libxlsxwriter_sys::worksheet_set_default_row(
@@ -1623,7 +3956,7 @@ impl<'a> Worksheet<'a> {
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_vba_name(
                 self.worksheet,
-                CString::new(name).unwrap().as_c_str().as_ptr(),
+                CString::new(name)?.as_c_str().as_ptr(),
             );
 
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
@@ -1633,4 +3966,164 @@ impl<'a> Worksheet<'a> {
             }
         }
     }
+
+    /// Returns an error for an obviously broken formula when the `validate_formulas` option of the workbook is set.
+    fn check_formula(&self, formula: &str) -> Result<(), XlsxError> {
+        if self._workbook.validate_formulas && !is_plausible_formula(formula) {
+            return Err(XlsxError::new(error::INVALID_FORMULA_ERROR_CODE));
+        }
+        Ok(())
+    }
+
+    /// Returns an error when the `strict_merge_ranges` option of the workbook is set and a cell of the range other than its top-left cell contains data.
+    fn check_merge_overwrite(
+        &self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+    ) -> Result<(), XlsxError> {
+        if !self._workbook.strict_merge_ranges {
+            return Ok(());
+        }
+        let (first_row, last_row) = (first_row.min(last_row), first_row.max(last_row));
+        let (first_col, last_col) = (first_col.min(last_col), first_col.max(last_col));
+        let overwritten = self
+            ._workbook
+            .cells
+            .borrow()
+            .get(&self.worksheet)
+            .is_some_and(|cells| {
+                cells
+                    .range((first_row, first_col)..=(last_row, last_col))
+                    .any(|(&(row, col), record)| {
+                        (first_col..=last_col).contains(&col)
+                            && (row, col) != (first_row, first_col)
+                            && !matches!(record.value, CellValue::Blank)
+                    })
+            });
+        // The cells of a merged range or an array formula other than its top-left cell aren't kept.
+        let overlapped = self
+            ._workbook
+            .ranges
+            .borrow()
+            .get(&self.worksheet)
+            .is_some_and(|ranges| {
+                ranges.iter().any(|(range, _)| {
+                    let rows = range.first_row.max(first_row)..=range.last_row.min(last_row);
+                    let cols = range.first_col.max(first_col)..=range.last_col.min(last_col);
+                    !rows.is_empty()
+                        && !cols.is_empty()
+                        && (rows, cols) != (first_row..=first_row, first_col..=first_col)
+                })
+            });
+        if overwritten || overlapped {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Records the format and the options of a row for `set_row_height_only()`.
+    fn record_row(
+        &self,
+        row: WorksheetRow,
+        format: *mut libxlsxwriter_sys::lxw_format,
+        options: RowColOptions,
+    ) {
+        self._workbook
+            .row_settings
+            .borrow_mut()
+            .insert((self.worksheet, row), (format, options));
+    }
+
+    fn record_cell(
+        &self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        value: CellValue,
+        format: Option<&Format>,
+    ) {
+        *self
+            ._workbook
+            .written_cells
+            .borrow_mut()
+            .entry(self.worksheet)
+            .or_default() += 1;
+        if self._workbook.constant_memory {
+            return;
+        }
+        let record = CellRecord {
+            value,
+            format: format.map(|x| x.format).unwrap_or(std::ptr::null_mut()),
+        };
+        self._workbook
+            .cells
+            .borrow_mut()
+            .entry(self.worksheet)
+            .or_default()
+            .insert((row, col), record);
+    }
+
+    /// Records a merged range or an array formula as a single range, and `value` as the value of its top-left cell.
+    /// All the cells of the range are counted as written.
+    fn record_range(
+        &self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+        value: CellValue,
+        format: Option<&Format>,
+    ) {
+        let range = CellRange::new(
+            first_row.min(last_row),
+            first_col.min(last_col),
+            first_row.max(last_row),
+            first_col.max(last_col),
+        );
+        *self
+            ._workbook
+            .written_cells
+            .borrow_mut()
+            .entry(self.worksheet)
+            .or_default() += u64::from(range.last_row - range.first_row + 1)
+            * u64::from(range.last_col - range.first_col + 1);
+        if self._workbook.constant_memory {
+            return;
+        }
+        let format = format.map(|x| x.format).unwrap_or(std::ptr::null_mut());
+        self._workbook
+            .cells
+            .borrow_mut()
+            .entry(self.worksheet)
+            .or_default()
+            .insert(
+                (range.first_row, range.first_col),
+                CellRecord { value, format },
+            );
+        self._workbook
+            .ranges
+            .borrow_mut()
+            .entry(self.worksheet)
+            .or_default()
+            .push((range, format));
+    }
+
+    /// Returns the merged range or the array formula containing the cell `(row, col)` and its format, if any.
+    fn range_at(
+        &self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+    ) -> Option<(CellRange, *mut libxlsxwriter_sys::lxw_format)> {
+        self._workbook
+            .ranges
+            .borrow()
+            .get(&self.worksheet)?
+            .iter()
+            .rev()
+            .find(|(range, _)| range.contains(row, col))
+            .copied()
+    }
 }
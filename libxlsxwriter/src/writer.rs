@@ -0,0 +1,164 @@
+use super::{CellWrite, Format, Workbook, Worksheet, WorksheetCol, WorksheetRow, XlsxError};
+use std::cell::Cell;
+use std::path::Path;
+
+/// Font size of the default Excel font, used to fit the columns to the headers.
+const DEFAULT_FONT_SIZE: f64 = 11.0;
+
+/// Simple interface to write tabular data to a workbook without handling worksheets and formats:
+/// ```rust
+/// use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// let mut writer = ExcelWriter::new("test-excel_writer-1.xlsx");
+/// writer.sheet("Sales")?;
+/// writer.header_row(&["Region", "Total"])?;
+/// writer.write(1, 0, "North")?;
+/// writer.write(1, 1, &1250.0)?;
+/// writer.write(2, 0, "South")?;
+/// writer.write(2, 1, &980.0)?;
+/// writer.finish()
+/// # }
+/// ```
+/// The values are written with [Worksheet.write()](struct.Worksheet.html#method.write), so any [CellWrite](trait.CellWrite.html) value is supported.
+/// The header row is written in bold and its columns are wide enough for the headers.
+///
+/// `ExcelWriter` only covers simple reports. Use [Workbook](struct.Workbook.html) and [Worksheet](struct.Worksheet.html)
+/// directly for formats, charts, images and the other features of libxlsxwriter: both interfaces write the same files.
+pub struct ExcelWriter {
+    workbook: Workbook,
+    worksheet: Cell<Option<*mut libxlsxwriter_sys::lxw_worksheet>>,
+    header_format: Cell<*mut libxlsxwriter_sys::lxw_format>,
+}
+
+impl ExcelWriter {
+    /// Creates a writer for a new workbook which is written to `path` by [ExcelWriter.finish()](struct.ExcelWriter.html#method.finish).
+    ///
+    /// Like for [Workbook.new()](struct.Workbook.html#method.new), a `path` which isn't valid UTF-8 or contains a NUL byte is reported
+    /// by the error returned by the first write and by `finish()`.
+    pub fn new<P: AsRef<Path>>(path: P) -> ExcelWriter {
+        ExcelWriter {
+            workbook: Workbook::new(path),
+            worksheet: Cell::new(None),
+            header_format: Cell::new(std::ptr::null_mut()),
+        }
+    }
+
+    /// Selects the worksheet named `name` for the following writes, and adds it to the workbook if it doesn't exist yet.
+    ///
+    /// The values written before any worksheet is selected go to a worksheet with the default name "Sheet1".
+    pub fn sheet(&mut self, name: &str) -> Result<(), XlsxError> {
+        let worksheet = match self.workbook.get_worksheet(name) {
+            Some(worksheet) => worksheet.worksheet,
+            None => self.workbook.add_worksheet(Some(name))?.worksheet,
+        };
+        self.worksheet.set(Some(worksheet));
+        Ok(())
+    }
+
+    /// Writes a value to the cell specified by `row` and `col` of the selected worksheet.
+    pub fn write<T: CellWrite + ?Sized>(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        value: &T,
+    ) -> Result<(), XlsxError> {
+        let mut worksheet = self.worksheet()?;
+        worksheet.write(row, col, value, None)
+    }
+
+    /// Writes the headers in bold to the first row of the selected worksheet, and sets the width of their columns to fit them.
+    pub fn header_row(&mut self, headers: &[&str]) -> Result<(), XlsxError> {
+        let mut worksheet = self.worksheet()?;
+        let header_format = self.header_format();
+        for (col, header) in headers.iter().enumerate() {
+            let col = col as WorksheetCol;
+            worksheet.write_string(0, col, header, Some(&header_format))?;
+            worksheet.set_column_auto(col, &[header], DEFAULT_FONT_SIZE)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the workbook of the writer, to use the lower-level API for the features not covered by `ExcelWriter`.
+    pub fn workbook(&self) -> &Workbook {
+        &self.workbook
+    }
+
+    /// Writes the workbook to its file. See [Workbook.close()](struct.Workbook.html#method.close).
+    pub fn finish(self) -> Result<(), XlsxError> {
+        self.workbook.close().map(|_| ())
+    }
+
+    /// Returns the selected worksheet, which is added with the default name when no worksheet is selected.
+    fn worksheet(&self) -> Result<Worksheet<'_>, XlsxError> {
+        let worksheet = match self.worksheet.get() {
+            Some(worksheet) => worksheet,
+            None => self.workbook.add_worksheet(None)?.worksheet,
+        };
+        self.worksheet.set(Some(worksheet));
+        Ok(Worksheet {
+            _workbook: &self.workbook,
+            worksheet,
+        })
+    }
+
+    /// Returns the bold format of the headers. The format is created once and reused.
+    fn header_format(&self) -> Format<'_> {
+        if self.header_format.get().is_null() {
+            self.header_format
+                .set(self.workbook.add_format().set_bold().format);
+        }
+        Format {
+            _workbook: &self.workbook,
+            format: self.header_format.get(),
+        }
+    }
+}
+
+/// Writer of consecutive rows of a worksheet, created by [Worksheet.row_writer()](struct.Worksheet.html#method.row_writer).
+///
+/// Each call to [RowWriter.write_row()](struct.RowWriter.html#method.write_row) writes a row and moves to the next one, so the rows are always written
+/// in increasing order as required by the constant memory mode of [WorkbookOptions](struct.WorkbookOptions.html). This is suited to the conversion of
+/// large files such as CSV files, which are read and written one row at a time:
+/// ```rust
+/// use xlsxwriter::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// let options = WorkbookOptions {
+///     constant_memory: true,
+///     ..WorkbookOptions::default()
+/// };
+/// let workbook = Workbook::new_with_options("test-row_writer-1.xlsx", &options);
+/// let mut worksheet = workbook.add_worksheet(None)?;
+/// let mut rows = worksheet.row_writer(0);
+/// rows.write_row(&[&"Name", &"Score"])?;
+/// for (name, score) in [("Alice", 92.0), ("Bob", 85.5)].iter() {
+///     rows.write_row(&[name, score])?;
+/// }
+/// assert_eq!(rows.next_row(), 3);
+/// # workbook.close()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RowWriter<'a, 'b> {
+    pub(crate) worksheet: &'b mut Worksheet<'a>,
+    pub(crate) row: WorksheetRow,
+}
+
+impl<'a, 'b> RowWriter<'a, 'b> {
+    /// Writes the values to the next row, starting at the first column, with [Worksheet.write()](struct.Worksheet.html#method.write).
+    ///
+    /// The row is counted as written even when an error is returned, so the following rows aren't written over it.
+    pub fn write_row(&mut self, values: &[&dyn CellWrite]) -> Result<(), XlsxError> {
+        let row = self.row;
+        self.row += 1;
+        for (col, value) in values.iter().enumerate() {
+            self.worksheet
+                .write(row, col as WorksheetCol, *value, None)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the row written by the next call to [RowWriter.write_row()](struct.RowWriter.html#method.write_row).
+    pub fn next_row(&self) -> WorksheetRow {
+        self.row
+    }
+}